@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Pat, PatType, ReturnType};
+
+use crate::types;
+
+/// Conversion of the arguments of a callable into typed arguments.
+pub struct Args {
+    pub names: Vec<Ident>,
+    pub conversions: Vec<TokenStream>,
+    pub signatures: Vec<TokenStream>,
+}
+
+/// Converts each argument with `FromValue`. `label` is a `&str` expression
+/// naming the callable in the `TypeError` thrown on mismatch, `argument`
+/// gives the `Value` expression for an index.
+pub fn convert<'a>(
+    inputs: impl Iterator<Item = &'a PatType>,
+    label: &TokenStream,
+    argument: impl Fn(usize) -> TokenStream,
+) -> Args {
+    let mut args = Args {
+        names: vec![],
+        conversions: vec![],
+        signatures: vec![],
+    };
+
+    for (i, arg) in inputs.enumerate() {
+        let display_name = match &*arg.pat {
+            Pat::Ident(v) => v.ident.to_string().trim_start_matches('_').to_string(),
+            _ => format!("arg{i}"),
+        };
+        let ident = format_ident!("__gdv8_arg{}", i);
+        let ty = &arg.ty;
+        let ts_type = types::ts_type(ty);
+        let value = argument(i);
+
+        args.conversions.push(quote! {
+            let #ident = <#ty as ::gdv8::FromValue>::from_value(#value).map_err(|e| {
+                ::gdv8::Error::TypeError(::std::format!(
+                    "{}(): argument {} `{}`: {}",
+                    #label,
+                    #i,
+                    #display_name,
+                    e
+                ))
+            })?;
+        });
+        args.signatures
+            .push(quote!(.param(#display_name, #ts_type)));
+        args.names.push(ident);
+    }
+
+    args
+}
+
+/// Turns the result of `call` into `Result<Value, Error>` and describes its
+/// type for the `.d.ts` generator.
+pub fn convert_return(output: &ReturnType, call: TokenStream) -> (TokenStream, TokenStream) {
+    match output {
+        ReturnType::Default => (
+            quote! {
+                #call;
+                ::std::result::Result::Ok(::gdv8::Value::Undefined)
+            },
+            quote!(::gdv8::TsType::Void),
+        ),
+        ReturnType::Type(_, ty) if types::is_result(ty) => (
+            quote! {
+                #call
+                    .map(::gdv8::IntoValue::into_value)
+                    .map_err(::std::convert::Into::into)
+            },
+            types::ts_type(ty),
+        ),
+        ReturnType::Type(_, ty) => (
+            quote! {
+                ::std::result::Result::Ok(::gdv8::IntoValue::into_value(#call))
+            },
+            types::ts_type(ty),
+        ),
+    }
+}
@@ -0,0 +1,163 @@
+use syn::{Attribute, LitStr, Path};
+
+/// `#[gdv8(...)]` options on a struct or enum.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub rename_all: Option<RenameRule>,
+    pub tag: Option<String>,
+}
+
+/// `#[gdv8(...)]` options on a field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<String>,
+    pub default: Option<Option<Path>>,
+    pub skip: bool,
+}
+
+/// `#[gdv8(...)]` options on an enum variant.
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl RenameRule {
+    fn parse(lit: &LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "camelCase" => RenameRule::Camel,
+            "PascalCase" => RenameRule::Pascal,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            _ => return Err(syn::Error::new_spanned(lit, "unknown rename rule")),
+        })
+    }
+
+    /// Applies the rule to a Rust identifier, either snake_case (fields) or
+    /// PascalCase (variants).
+    pub fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+
+        match self {
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+            RenameRule::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| match i {
+                    0 => w.to_string(),
+                    _ => capitalize(w),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Lowercase words of a snake_case or PascalCase identifier.
+fn split_words(ident: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+
+    for part in ident
+        .trim_start_matches("r#")
+        .split('_')
+        .filter(|p| !p.is_empty())
+    {
+        let mut word = String::new();
+        for c in part.chars() {
+            if c.is_uppercase() && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.extend(c.to_lowercase());
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub fn container(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut result = ContainerAttrs::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("gdv8")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                result.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+            } else if meta.path.is_ident("tag") {
+                result.tag = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else {
+                return Err(meta.error("unsupported gdv8 container attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+pub fn field(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut result = FieldAttrs::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("gdv8")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                result.default = match meta.input.peek(syn::Token![=]) {
+                    true => Some(Some(meta.value()?.parse::<LitStr>()?.parse()?)),
+                    false => Some(None),
+                };
+            } else if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else {
+                return Err(meta.error("unsupported gdv8 field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+pub fn variant(attrs: &[Attribute]) -> syn::Result<VariantAttrs> {
+    let mut result = VariantAttrs::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("gdv8")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else {
+                return Err(meta.error("unsupported gdv8 variant attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,218 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::Parser, FnArg, ImplItem, ImplItemFn, ItemImpl, LitStr, Type};
+
+use crate::args::{self, Args};
+
+enum Kind {
+    Constructor,
+    Method,
+    Getter(Option<String>),
+    Setter(Option<String>),
+}
+
+struct MethodAttrs {
+    kind: Kind,
+    name: Option<String>,
+    skip: bool,
+}
+
+/// Reads and removes the `#[gdv8(...)]` attributes of a method.
+fn take_method_attrs(method: &mut ImplItemFn) -> syn::Result<MethodAttrs> {
+    let mut result = MethodAttrs {
+        kind: Kind::Method,
+        name: None,
+        skip: false,
+    };
+
+    for attr in method.attrs.iter().filter(|a| a.path().is_ident("gdv8")) {
+        attr.parse_nested_meta(|meta| {
+            let value = |meta: &syn::meta::ParseNestedMeta| -> syn::Result<Option<String>> {
+                match meta.input.peek(syn::Token![=]) {
+                    true => Ok(Some(meta.value()?.parse::<LitStr>()?.value())),
+                    false => Ok(None),
+                }
+            };
+
+            if meta.path.is_ident("constructor") {
+                result.kind = Kind::Constructor;
+            } else if meta.path.is_ident("getter") {
+                result.kind = Kind::Getter(value(&meta)?);
+            } else if meta.path.is_ident("setter") {
+                result.kind = Kind::Setter(value(&meta)?);
+            } else if meta.path.is_ident("name") {
+                result.name = value(&meta)?;
+            } else if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else {
+                return Err(meta.error("unsupported gdv8 method attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    method.attrs.retain(|a| !a.path().is_ident("gdv8"));
+    Ok(result)
+}
+
+fn class_name(self_ty: &Type) -> syn::Result<String> {
+    match self_ty {
+        Type::Path(v) => Ok(v.path.segments.last().unwrap().ident.to_string()),
+        _ => Err(syn::Error::new_spanned(
+            self_ty,
+            "gdv8::class needs a named type",
+        )),
+    }
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let mut item_impl: ItemImpl = syn::parse2(item)?;
+
+    if let Some((_, path, _)) = &item_impl.trait_ {
+        return Err(syn::Error::new_spanned(
+            path,
+            "gdv8::class goes on an inherent impl block",
+        ));
+    }
+
+    let mut js_name = class_name(&item_impl.self_ty)?;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            js_name = meta.value()?.parse::<LitStr>()?.value();
+            Ok(())
+        } else {
+            Err(meta.error("unsupported gdv8::class attribute"))
+        }
+    });
+    parser.parse2(attr)?;
+
+    let mut registrations = vec![];
+
+    for item in item_impl.items.iter_mut() {
+        let method = match item {
+            ImplItem::Fn(v) => v,
+            _ => continue,
+        };
+
+        let attrs = take_method_attrs(method)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let ident = &method.sig.ident;
+        let rust_name = ident.to_string();
+        let name = attrs.name.clone().unwrap_or_else(|| rust_name.clone());
+
+        let mut receiver = None;
+        let mut inputs = vec![];
+        for arg in &method.sig.inputs {
+            match arg {
+                FnArg::Receiver(v) if v.reference.is_none() => {
+                    return Err(syn::Error::new_spanned(
+                        v,
+                        "methods exposed to JS take `&self` or `&mut self`",
+                    ))
+                }
+                FnArg::Receiver(v) => receiver = Some(v),
+                FnArg::Typed(v) => inputs.push(v),
+            }
+        }
+
+        let label = format!("{js_name}.{name}");
+        let Args {
+            names, conversions, ..
+        } = args::convert(inputs.into_iter(), &quote!(#label), |_| {
+            quote!(args.next().unwrap_or(::gdv8::Value::Undefined))
+        });
+
+        let registration = match (attrs.kind, receiver) {
+            (Kind::Constructor, Some(receiver)) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "constructors can't take `self`",
+                ))
+            }
+            (Kind::Constructor, None) => {
+                let construct = match crate::types::is_result(match &method.sig.output {
+                    syn::ReturnType::Type(_, ty) => ty,
+                    syn::ReturnType::Default => {
+                        return Err(syn::Error::new_spanned(
+                            &method.sig,
+                            "constructors return `Self`",
+                        ))
+                    }
+                }) {
+                    true => quote!(Self::#ident(#(#names),*).map_err(::std::convert::Into::into)),
+                    false => quote!(::std::result::Result::Ok(Self::#ident(#(#names),*))),
+                };
+                quote! {
+                    .constructor(|args: ::std::vec::Vec<::gdv8::Value>| -> ::std::result::Result<Self, ::gdv8::Error> {
+                        #[allow(unused_mut, unused_variables)]
+                        let mut args = args.into_iter();
+                        #(#conversions)*
+                        #construct
+                    })
+                }
+            }
+            (kind, Some(_)) => {
+                let (call, _) = args::convert_return(
+                    &method.sig.output,
+                    quote!(Self::#ident(this, #(#names),*)),
+                );
+                let (register, name) = match kind {
+                    Kind::Getter(v) => (quote!(getter), v.unwrap_or(name)),
+                    Kind::Setter(v) => (
+                        quote!(setter),
+                        v.unwrap_or_else(|| match attrs.name {
+                            Some(name) => name,
+                            None => rust_name.trim_start_matches("set_").to_string(),
+                        }),
+                    ),
+                    _ => (quote!(method), name),
+                };
+                quote! {
+                    .#register(#name, |this: &mut Self, args: ::std::vec::Vec<::gdv8::Value>| -> ::std::result::Result<::gdv8::Value, ::gdv8::Error> {
+                        #[allow(unused_mut, unused_variables)]
+                        let mut args = args.into_iter();
+                        #(#conversions)*
+                        #call
+                    })
+                }
+            }
+            (Kind::Method, None) => {
+                let (call, _) =
+                    args::convert_return(&method.sig.output, quote!(Self::#ident(#(#names),*)));
+                quote! {
+                    .static_method(#name, |args: ::std::vec::Vec<::gdv8::Value>| -> ::std::result::Result<::gdv8::Value, ::gdv8::Error> {
+                        #[allow(unused_mut, unused_variables)]
+                        let mut args = args.into_iter();
+                        #(#conversions)*
+                        #call
+                    })
+                }
+            }
+            (_, None) => {
+                return Err(syn::Error::new_spanned(
+                    &method.sig,
+                    "getters and setters take `&self` or `&mut self`",
+                ))
+            }
+        };
+
+        registrations.push(registration);
+    }
+
+    let self_ty = &item_impl.self_ty;
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+
+    Ok(quote! {
+        #item_impl
+
+        impl #impl_generics ::gdv8::NativeClass for #self_ty #where_clause {
+            fn describe() -> ::gdv8::ClassDescriptor<Self> {
+                ::gdv8::ClassDescriptor::new(#js_name)
+                    #(#registrations)*
+            }
+        }
+    })
+}
@@ -0,0 +1,463 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, Data, DataEnum, DeriveInput, Field, Fields, Generics, Ident, Path, Variant,
+};
+
+use crate::attrs::{self, ContainerAttrs, FieldAttrs, RenameRule};
+
+struct NamedField<'a> {
+    ident: &'a Ident,
+    field: &'a Field,
+    key: String,
+    attrs: FieldAttrs,
+}
+
+fn named_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
+    rename_all: Option<RenameRule>,
+) -> syn::Result<Vec<NamedField<'a>>> {
+    fields
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named fields have identifiers");
+            let attrs = attrs::field(&field.attrs)?;
+            let key = match (&attrs.rename, rename_all) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(rule)) => rule.apply(&ident.to_string()),
+                (None, None) => ident.to_string().trim_start_matches("r#").to_string(),
+            };
+            Ok(NamedField {
+                ident,
+                field,
+                key,
+                attrs,
+            })
+        })
+        .collect()
+}
+
+fn variant_name(variant: &Variant, rename_all: Option<RenameRule>) -> syn::Result<String> {
+    let attrs = attrs::variant(&variant.attrs)?;
+    Ok(match (attrs.rename, rename_all) {
+        (Some(rename), _) => rename,
+        (None, Some(rule)) => rule.apply(&variant.ident.to_string()),
+        (None, None) => variant.ident.to_string(),
+    })
+}
+
+fn with_bound(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn invalid(message: TokenStream) -> TokenStream {
+    quote! {
+        ::gdv8::Error::InvalidValue(::gdv8::ValueError::new(#message))
+    }
+}
+
+/// `Value::Object` holding the given `(key, value expression)` pairs.
+fn build_object(entries: &[(String, TokenStream)], tag: Option<(&str, &str)>) -> TokenStream {
+    let tag = tag.map(|(tag, name)| {
+        quote! {
+            object.insert(#tag, ::gdv8::Value::String(::std::string::String::from(#name)));
+        }
+    });
+    let inserts = entries.iter().map(|(key, value)| {
+        quote! {
+            object.insert(#key, ::gdv8::IntoValue::into_value(#value));
+        }
+    });
+
+    quote! {{
+        let mut object = ::gdv8::Object::new();
+        #tag
+        #(#inserts)*
+        ::gdv8::Value::Object(object)
+    }}
+}
+
+/// Struct expression reading every field out of a local `object`.
+fn read_object(constructor: TokenStream, fields: &[NamedField]) -> TokenStream {
+    let reads = fields.iter().map(|f| {
+        let ident = f.ident;
+        let key = &f.key;
+        let ty = &f.field.ty;
+
+        let default = match &f.attrs.default {
+            Some(Some(path)) => quote!(#path()),
+            _ => quote!(::std::default::Default::default()),
+        };
+        let convert = quote! {
+            <#ty as ::gdv8::FromValue>::from_value(v).map_err(|e| e.at_key(#key))?
+        };
+
+        if f.attrs.skip {
+            return quote!(#ident: #default);
+        }
+
+        match f.attrs.default {
+            Some(_) => quote! {
+                #ident: match object.remove(#key) {
+                    ::std::option::Option::None
+                    | ::std::option::Option::Some(::gdv8::Value::Undefined) => #default,
+                    ::std::option::Option::Some(v) => #convert,
+                }
+            },
+            None => quote! {
+                #ident: {
+                    let v = object.remove(#key).unwrap_or(::gdv8::Value::Undefined);
+                    #convert
+                }
+            },
+        }
+    });
+
+    quote!(#constructor { #(#reads),* })
+}
+
+fn expect_object(value: TokenStream) -> TokenStream {
+    quote! {
+        let mut object = match #value {
+            ::gdv8::Value::Object(v) => v,
+            other => return ::std::result::Result::Err(::gdv8::Error::expected("object", &other)),
+        };
+    }
+}
+
+pub fn expand_into_value(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container = attrs::container(&input.attrs)?;
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, parse_quote!(::gdv8::IntoValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let fields = named_fields(fields.named.iter(), container.rename_all)?;
+                let entries: Vec<_> = fields
+                    .iter()
+                    .filter(|f| !f.attrs.skip)
+                    .map(|f| {
+                        let ident = f.ident;
+                        (f.key.clone(), quote!(self.#ident))
+                    })
+                    .collect();
+                build_object(&entries, None)
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                quote!(::gdv8::IntoValue::into_value(self.0))
+            }
+            Fields::Unnamed(fields) => {
+                let items = (0..fields.unnamed.len()).map(syn::Index::from);
+                quote! {
+                    ::gdv8::Value::Array(::gdv8::Array::new(::std::vec![
+                        #(::gdv8::IntoValue::into_value(self.#items)),*
+                    ]))
+                }
+            }
+            Fields::Unit => quote!(::gdv8::Value::Object(::gdv8::Object::new())),
+        },
+        Data::Enum(data) => enum_into_value(data, &container, &name.to_string())?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "IntoValue can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::gdv8::IntoValue for #name #ty_generics #where_clause {
+            fn into_value(self) -> ::gdv8::Value {
+                #body
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#name #ty_generics> for ::gdv8::Value #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                ::gdv8::IntoValue::into_value(value)
+            }
+        }
+    })
+}
+
+fn enum_into_value(
+    data: &DataEnum,
+    container: &ContainerAttrs,
+    enum_name: &str,
+) -> syn::Result<TokenStream> {
+    let mut arms = vec![];
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let name = variant_name(variant, container.rename_all)?;
+        let tag = container.tag.as_deref();
+
+        let arm = match (&variant.fields, tag) {
+            (Fields::Unit, None) => quote! {
+                Self::#ident => ::gdv8::Value::String(::std::string::String::from(#name))
+            },
+            (Fields::Unit, Some(tag)) => {
+                let object = build_object(&[], Some((tag, &name)));
+                quote!(Self::#ident => #object)
+            }
+            (Fields::Named(fields), tag) => {
+                let fields = named_fields(fields.named.iter(), None)?;
+                let bindings = fields.iter().map(|f| f.ident);
+                let entries: Vec<_> = fields
+                    .iter()
+                    .filter(|f| !f.attrs.skip)
+                    .map(|f| {
+                        let ident = f.ident;
+                        (f.key.clone(), quote!(#ident))
+                    })
+                    .collect();
+
+                let value = match tag {
+                    Some(tag) => build_object(&entries, Some((tag, &name))),
+                    None => {
+                        let inner = build_object(&entries, None);
+                        build_object(&[(name.clone(), inner)], None)
+                    }
+                };
+                quote! {
+                    #[allow(unused_variables)]
+                    Self::#ident { #(#bindings),* } => #value
+                }
+            }
+            (Fields::Unnamed(fields), None) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|i| format_ident!("__field{}", i))
+                    .collect();
+                let inner = match bindings.len() {
+                    1 => {
+                        let binding = &bindings[0];
+                        quote!(#binding)
+                    }
+                    _ => quote! {
+                        ::gdv8::Value::Array(::gdv8::Array::new(::std::vec![
+                            #(::gdv8::IntoValue::into_value(#bindings)),*
+                        ]))
+                    },
+                };
+                let value = build_object(&[(name.clone(), inner)], None);
+                quote!(Self::#ident(#(#bindings),*) => #value)
+            }
+            // like serde, a tag can only be added to an object and dropping it
+            // would make the value unreadable for `FromValue`
+            (Fields::Unnamed(fields), Some(tag)) if fields.unnamed.len() == 1 => {
+                let variant = format!("{enum_name}::{ident}");
+                quote! {
+                    Self::#ident(inner) => match ::gdv8::IntoValue::into_value(inner) {
+                        ::gdv8::Value::Object(mut object) => {
+                            object.insert(#tag, ::gdv8::Value::String(::std::string::String::from(#name)));
+                            ::gdv8::Value::Object(object)
+                        }
+                        value => ::std::panic!(
+                            "{} holds {} instead of an object, which can't carry the tag `{}`",
+                            #variant,
+                            value.type_name(),
+                            #tag,
+                        ),
+                    }
+                }
+            }
+            (Fields::Unnamed(_), Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "tuple variants can't be used with an internal tag",
+                ))
+            }
+        };
+        arms.push(arm);
+    }
+
+    Ok(quote! {
+        match self {
+            #(#arms,)*
+        }
+    })
+}
+
+pub fn expand_from_value(input: DeriveInput) -> syn::Result<TokenStream> {
+    let container = attrs::container(&input.attrs)?;
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, parse_quote!(::gdv8::FromValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let fields = named_fields(fields.named.iter(), container.rename_all)?;
+                let expect = expect_object(quote!(value));
+                let read = read_object(quote!(Self), &fields);
+                quote! {
+                    #expect
+                    ::std::result::Result::Ok(#read)
+                }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                ::gdv8::FromValue::from_value(value).map(Self)
+            },
+            Fields::Unnamed(fields) => {
+                let read = read_tuple(quote!(Self), fields.unnamed.len());
+                quote! {
+                    #read
+                }
+            }
+            Fields::Unit => quote! {
+                let _ = value;
+                ::std::result::Result::Ok(Self)
+            },
+        },
+        Data::Enum(data) => enum_from_value(data, &container, &name.to_string())?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "FromValue can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::gdv8::FromValue for #name #ty_generics #where_clause {
+            fn from_value(value: ::gdv8::Value) -> ::std::result::Result<Self, ::gdv8::Error> {
+                #body
+            }
+        }
+    })
+}
+
+/// Reads a tuple struct or variant from a `Value::Array` in `value`.
+fn read_tuple(constructor: TokenStream, len: usize) -> TokenStream {
+    let reads = (0..len).map(|i| {
+        quote! {
+            ::gdv8::FromValue::from_value(items.next().unwrap_or(::gdv8::Value::Undefined))
+                .map_err(|e| e.at_index(#i))?
+        }
+    });
+
+    quote! {
+        let mut items = <::std::vec::Vec<::gdv8::Value> as ::gdv8::FromValue>::from_value(value)?
+            .into_iter();
+        ::std::result::Result::Ok(#constructor(#(#reads),*))
+    }
+}
+
+fn enum_from_value(
+    data: &DataEnum,
+    container: &ContainerAttrs,
+    enum_name: &str,
+) -> syn::Result<TokenStream> {
+    let unknown = invalid(quote! {
+        ::std::format!("unknown variant `{}` of {}", name, #enum_name)
+    });
+
+    match &container.tag {
+        Some(tag) => {
+            let mut arms = vec![];
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let name = variant_name(variant, container.rename_all)?;
+                let arm = match &variant.fields {
+                    Fields::Unit => quote!(#name => ::std::result::Result::Ok(Self::#ident)),
+                    Fields::Named(fields) => {
+                        let fields = named_fields(fields.named.iter(), None)?;
+                        let read = read_object(quote!(Self::#ident), &fields);
+                        quote!(#name => ::std::result::Result::Ok(#read))
+                    }
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                        #name => ::gdv8::FromValue::from_value(::gdv8::Value::Object(object))
+                            .map(Self::#ident)
+                    },
+                    Fields::Unnamed(_) => {
+                        return Err(syn::Error::new_spanned(
+                            variant,
+                            "tuple variants can't be used with an internal tag",
+                        ))
+                    }
+                };
+                arms.push(arm);
+            }
+
+            let expect = expect_object(quote!(value));
+            let missing_tag = invalid(quote!(::std::format!("missing tag `{}`", #tag)));
+            Ok(quote! {
+                #expect
+                let name = match object.remove(#tag) {
+                    ::std::option::Option::Some(::gdv8::Value::String(v)) => v,
+                    _ => return ::std::result::Result::Err(#missing_tag),
+                };
+                #[allow(unreachable_code)]
+                match name.as_str() {
+                    #(#arms,)*
+                    _ => ::std::result::Result::Err(#unknown),
+                }
+            })
+        }
+        None => {
+            let mut unit_arms = vec![];
+            let mut data_arms = vec![];
+
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let name = variant_name(variant, container.rename_all)?;
+                match &variant.fields {
+                    Fields::Unit => {
+                        unit_arms.push(quote!(#name => ::std::result::Result::Ok(Self::#ident)))
+                    }
+                    fields => {
+                        let read = match fields {
+                            Fields::Named(fields) => {
+                                let fields = named_fields(fields.named.iter(), None)?;
+                                let expect = expect_object(quote!(value));
+                                let read = read_object(quote!(Self::#ident), &fields);
+                                quote! {
+                                    #expect
+                                    ::std::result::Result::Ok(#read)
+                                }
+                            }
+                            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                                ::gdv8::FromValue::from_value(value).map(Self::#ident)
+                            },
+                            Fields::Unnamed(fields) => {
+                                read_tuple(quote!(Self::#ident), fields.unnamed.len())
+                            }
+                            Fields::Unit => unreachable!(),
+                        };
+                        data_arms.push(quote! {
+                            if let ::std::option::Option::Some(value) = object.remove(#name) {
+                                let read = || -> ::std::result::Result<Self, ::gdv8::Error> {
+                                    #read
+                                };
+                                return read().map_err(|e| e.at_key(#name));
+                            }
+                        });
+                    }
+                }
+            }
+
+            let no_variant = invalid(quote! {
+                ::std::format!("expected a variant of {}", #enum_name)
+            });
+            Ok(quote! {
+                match value {
+                    ::gdv8::Value::String(name) => match name.as_str() {
+                        #(#unit_arms,)*
+                        _ => ::std::result::Result::Err(#unknown),
+                    },
+                    #[allow(unused_mut)]
+                    ::gdv8::Value::Object(mut object) => {
+                        #(#data_arms)*
+                        ::std::result::Result::Err(#no_variant)
+                    }
+                    other => ::std::result::Result::Err(::gdv8::Error::expected("string or object", &other)),
+                }
+            })
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::Parser, FnArg, ItemFn, LitStr};
+
+use crate::args::{self, Args};
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let function: ItemFn = syn::parse2(item)?;
+
+    let mut js_name = function.sig.ident.to_string();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            js_name = meta.value()?.parse::<LitStr>()?.value();
+            Ok(())
+        } else {
+            Err(meta.error("unsupported gdv8::function attribute"))
+        }
+    });
+    parser.parse2(attr)?;
+
+    if let Some(asyncness) = &function.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "gdv8::function does not support async functions",
+        ));
+    }
+
+    let mut inputs = vec![];
+    for arg in &function.sig.inputs {
+        match arg {
+            FnArg::Typed(v) => inputs.push(v),
+            FnArg::Receiver(v) => {
+                return Err(syn::Error::new_spanned(
+                    v,
+                    "gdv8::function can't be used on methods, use gdv8::class",
+                ))
+            }
+        }
+    }
+
+    let ident = &function.sig.ident;
+    let vis = &function.vis;
+
+    let Args {
+        names: arg_names,
+        conversions: arg_conversions,
+        signatures: arg_signatures,
+    } = args::convert(
+        inputs.into_iter(),
+        &quote!(NAME),
+        |i| quote!(args.value(#i)),
+    );
+    let (call, returns) =
+        args::convert_return(&function.sig.output, quote!(super::#ident(#(#arg_names),*)));
+
+    let docs = format!("JS glue for [`{ident}`], generated by `gdv8::function`.");
+
+    Ok(quote! {
+        #function
+
+        #[doc = #docs]
+        #[allow(non_snake_case)]
+        #vis mod #ident {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Name of the function in JS.
+            pub const NAME: &str = #js_name;
+
+            pub fn callable() -> ::gdv8::Callable {
+                ::gdv8::Callable::Native(::std::boxed::Box::new(
+                    |#[allow(unused_variables)] args: &mut ::gdv8::CallbackArgs<'_, '_>| -> ::std::result::Result<::gdv8::Value, ::gdv8::Error> {
+                        #(#arg_conversions)*
+                        #call
+                    },
+                ))
+            }
+
+            pub fn signature() -> ::gdv8::Signature {
+                ::gdv8::Signature::new()
+                    #(#arg_signatures)*
+                    .returns(#returns)
+            }
+
+            /// Registers the function on the global object of `context`.
+            pub fn register(context: &::gdv8::Context) -> ::std::result::Result<(), ::gdv8::Error> {
+                context.register_callable(NAME, callable())?;
+                context.declare(NAME, signature())
+            }
+        }
+    })
+}
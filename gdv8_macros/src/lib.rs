@@ -0,0 +1,108 @@
+mod args;
+mod attrs;
+mod class;
+mod derive;
+mod function;
+mod types;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Exports a Rust function to JS.
+///
+/// Next to the function a module of the same name is generated with
+/// `callable()`, `signature()` and `register(&Context)`. Arguments are
+/// converted with `FromValue` and the result with `IntoValue`, mismatches
+/// throw a `TypeError` into the script.
+///
+/// ```ignore
+/// #[gdv8::function]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+///
+/// add::register(&context)?;
+/// ```
+///
+/// The JS name defaults to the Rust name and can be changed with
+/// `#[gdv8::function(name = "addNumbers")]`.
+#[proc_macro_attribute]
+pub fn function(attr: TokenStream, item: TokenStream) -> TokenStream {
+    function::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Exposes a Rust type to JS as a class.
+///
+/// Goes on an inherent impl block and implements `NativeClass`, register the
+/// class with `Context::register_class::<T>()`. Methods taking `&self` or
+/// `&mut self` end up on the prototype, the others become static methods.
+/// `#[gdv8(constructor)]` marks the function backing `new`,
+/// `#[gdv8(getter)]` and `#[gdv8(setter)]` turn methods into accessor
+/// properties (a `set_` prefix is dropped from setter names), `#[gdv8(skip)]`
+/// hides a method and `#[gdv8(name = "...")]` renames it.
+///
+/// ```ignore
+/// struct Counter {
+///     count: f64,
+/// }
+///
+/// #[gdv8::class]
+/// impl Counter {
+///     #[gdv8(constructor)]
+///     fn new(start: f64) -> Self {
+///         Self { count: start }
+///     }
+///
+///     fn increment(&mut self) -> f64 {
+///         self.count += 1.0;
+///         self.count
+///     }
+///
+///     #[gdv8(getter)]
+///     fn count(&self) -> f64 {
+///         self.count
+///     }
+/// }
+/// ```
+///
+/// The native value is dropped once V8 garbage collects the JS object or the
+/// `Context` it was created in is dropped.
+#[proc_macro_attribute]
+pub fn class(attr: TokenStream, item: TokenStream) -> TokenStream {
+    class::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `IntoValue` and `From<T> for Value`.
+///
+/// Structs become objects, newtypes their inner value and tuple structs
+/// arrays. Enum unit variants become strings and data variants
+/// `{ "Variant": data }`, unless `#[gdv8(tag = "type")]` asks for an internal
+/// tag. Keys follow `#[gdv8(rename_all = "camelCase")]` and
+/// `#[gdv8(rename = "...")]`, `#[gdv8(skip)]` leaves a field out.
+///
+/// With an internal tag a newtype variant has to hold something that becomes
+/// an object, converting one that holds e.g. a number panics.
+#[proc_macro_derive(IntoValue, attributes(gdv8))]
+pub fn derive_into_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand_into_value(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `FromValue`, the inverse of `IntoValue`.
+///
+/// `Option` fields may be missing, `#[gdv8(default)]` or
+/// `#[gdv8(default = "path::to::fn")]` fills in missing fields. Errors carry
+/// the path to the mismatched value, e.g. `items[2].name: expected string`.
+#[proc_macro_derive(FromValue, attributes(gdv8))]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand_from_value(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Last path segment of a type and its first generic argument, if any.
+pub fn split_path(ty: &Type) -> Option<(String, Option<&Type>)> {
+    let path = match ty {
+        Type::Path(v) => &v.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+
+    let inner = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    };
+
+    Some((segment.ident.to_string(), inner))
+}
+
+/// Whether a return type is a `Result` that should be propagated.
+pub fn is_result(ty: &Type) -> bool {
+    matches!(split_path(ty), Some((name, _)) if name == "Result")
+}
+
+/// `gdv8::TsType` expression describing a Rust type for the `.d.ts` generator.
+pub fn ts_type(ty: &Type) -> TokenStream {
+    if let Type::Tuple(tuple) = ty {
+        if tuple.elems.is_empty() {
+            return quote!(::gdv8::TsType::Void);
+        }
+    }
+
+    let (name, inner) = match split_path(ty) {
+        Some(v) => v,
+        None => return quote!(::gdv8::TsType::Any),
+    };
+
+    match (name.as_str(), inner) {
+        ("f64" | "f32" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64", _) => {
+            quote!(::gdv8::TsType::Number)
+        }
+        ("String", _) => quote!(::gdv8::TsType::String),
+        ("bool", _) => quote!(::gdv8::TsType::Boolean),
+        ("Value", _) => quote!(::gdv8::TsType::Value),
+        ("Object", _) => quote!(::gdv8::TsType::Object),
+        ("Function", _) => quote!(::gdv8::TsType::Named(::std::string::String::from(
+            "Function"
+        ))),
+        ("ArrayBuffer", _) => quote!(::gdv8::TsType::Named(::std::string::String::from(
+            "ArrayBuffer"
+        ))),
+        ("TypedArray", _) => quote!(::gdv8::TsType::Named(::std::string::String::from(
+            "ArrayBufferView"
+        ))),
+        ("Date", _) => quote!(::gdv8::TsType::Named(::std::string::String::from("Date"))),
+        ("RegExp", _) => quote!(::gdv8::TsType::Named(::std::string::String::from("RegExp"))),
+        ("JsError", _) => quote!(::gdv8::TsType::Named(::std::string::String::from("Error"))),
+        ("Array", _) => quote!(::gdv8::TsType::Array(::std::boxed::Box::new(
+            ::gdv8::TsType::Value
+        ))),
+        ("Vec", Some(inner)) => {
+            let inner = ts_type(inner);
+            quote!(::gdv8::TsType::Array(::std::boxed::Box::new(#inner)))
+        }
+        ("Option" | "Result", Some(inner)) => ts_type(inner),
+        _ => quote!(::gdv8::TsType::Any),
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    cell::OnceCell,
+    ops::{Index, IndexMut},
+};
+
+use crate::{error::Error, Value};
+
+/// A JS array. Indexing past the end reads `undefined` and assigning there
+/// fills the gap with `undefined`, like in JS.
+#[derive(Clone, Default)]
+pub struct Array {
+    pub(crate) items: Vec<Value>,
+    /// `length` as a `Value`, so `value["length"]` can hand out a reference.
+    /// Reset whenever the length changes.
+    length: OnceCell<Box<Value>>,
+}
+
+impl Array {
+    /// The highest index a JS array can have, its length is a `u32`.
+    pub const MAX_INDEX: usize = u32::MAX as usize - 1;
+
+    /// How far past the end an assignment may grow an array. JS arrays can
+    /// have holes, this one is dense and fills every element up to the index,
+    /// so a stray large index would allocate that many `undefined`s.
+    pub const MAX_GAP: usize = 1 << 16;
+
+    /// A `Value::Array` of clones of `arr`.
+    pub fn value_from(arr: &[Value]) -> Value {
+        Value::Array(Array::new(arr.to_vec()))
+    }
+
+    #[deprecated(note = "shadows `From::from`, use `Array::value_from`")]
+    pub fn from(arr: &[Value]) -> Value {
+        Self::value_from(arr)
+    }
+
+    pub fn new(initial: Vec<Value>) -> Self {
+        Array {
+            items: initial,
+            length: OnceCell::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Array::new(Vec::with_capacity(capacity))
+    }
+
+    /// The JS `length`.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// `length` as a JS number.
+    pub(crate) fn length(&self) -> &Value {
+        self.length
+            .get_or_init(|| Box::new(Value::Number(self.items.len() as f64)))
+    }
+
+    /// Assigns `index` like `array[index] = value`, filling the gap to it
+    /// with `undefined`. A `RangeError` past `MAX_INDEX` or more than
+    /// `MAX_GAP` elements past the end.
+    pub fn set(&mut self, index: usize, value: impl Into<Value>) -> Result<(), Error> {
+        *self.slot(index)? = value.into();
+        return Ok(());
+    }
+
+    fn slot(&mut self, index: usize) -> Result<&mut Value, Error> {
+        if index > Self::MAX_INDEX {
+            return Err(Error::RangeError(format!("invalid array index {index}")));
+        }
+
+        if index >= self.items.len() {
+            if index - self.items.len() > Self::MAX_GAP {
+                return Err(Error::RangeError(format!(
+                    "array index {index} is too far past the length {}",
+                    self.items.len()
+                )));
+            }
+            self.items.resize(index + 1, Value::Undefined);
+            self.length.take();
+        }
+
+        return Ok(&mut self.items[index]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.items.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Value> {
+        self.items.get_mut(index)
+    }
+
+    pub fn push(&mut self, value: impl Into<Value>) {
+        self.items.push(value.into());
+        self.length.take();
+    }
+
+    pub fn pop(&mut self) -> Option<Value> {
+        self.length.take();
+        self.items.pop()
+    }
+
+    /// Like `splice(index, 0, value)`, panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: impl Into<Value>) {
+        self.items.insert(index, value.into());
+        self.length.take();
+    }
+
+    /// Like `splice(index, 1)`, panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Value {
+        let value = self.items.remove(index);
+        self.length.take();
+        value
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.length.take();
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+        self.items.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Value> {
+        self.items.iter_mut()
+    }
+
+    pub fn as_slice(&self) -> &[Value] {
+        &self.items
+    }
+
+    pub fn into_vec(self) -> Vec<Value> {
+        self.items
+    }
+}
+
+impl Index<usize> for Array {
+    type Output = Value;
+
+    fn index(&self, index: usize) -> &Value {
+        self.items.get(index).unwrap_or(&Value::Undefined)
+    }
+}
+
+impl IndexMut<usize> for Array {
+    /// Panics where `Array::set` fails.
+    fn index_mut(&mut self, index: usize) -> &mut Value {
+        match self.slot(index) {
+            Ok(v) => v,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl std::fmt::Debug for Array {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Array").field(&self.items).finish()
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Array {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().map(Into::into).collect()
+    }
+}
+
+impl From<Array> for Vec<Value> {
+    fn from(array: Array) -> Self {
+        array.items
+    }
+}
+
+impl FromIterator<Value> for Array {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        Array::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Value> for Array {
+    fn extend<I: IntoIterator<Item = Value>>(&mut self, iter: I) {
+        self.items.extend(iter);
+        self.length.take();
+    }
+}
+
+impl IntoIterator for Array {
+    type Item = Value;
+    type IntoIter = std::vec::IntoIter<Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Array {
+    type Item = &'a Value;
+    type IntoIter = std::slice::Iter<'a, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Array {
+    type Item = &'a mut Value;
+    type IntoIter = std::slice::IterMut<'a, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
+    }
+}
@@ -0,0 +1,277 @@
+//! Values of the JS builtins that have a Godot counterpart: `Date`, `RegExp`
+//! and `Error`.
+
+use godot::{
+    builtin::{Dictionary, GString},
+    classes::{RegEx, Time},
+    obj::Gd,
+};
+
+/// The largest time value a `Date` can hold, 100 million days.
+const MAX_TIME: f64 = 8.64e15;
+
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A JS `Date`, milliseconds since the Unix epoch in UTC. `NaN` is an
+/// invalid date.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Date(f64);
+
+/// A point in time split into calendar fields, in UTC.
+struct Fields {
+    year: i64,
+    month: usize,
+    day: i64,
+    weekday: usize,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    milliseconds: i64,
+}
+
+impl Date {
+    /// TimeClip: out of range times give an invalid date, others are
+    /// truncated to whole milliseconds.
+    pub fn from_time_value(time: f64) -> Self {
+        match time.is_finite() && time.abs() <= MAX_TIME {
+            true => Self(time.trunc() + 0.0),
+            false => Self(f64::NAN),
+        }
+    }
+
+    pub fn now() -> Self {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        Self::from_time_value(since_epoch.as_millis() as f64)
+    }
+
+    /// From seconds since the epoch, what Godot's `Time` works with.
+    pub fn from_unix_time(seconds: f64) -> Self {
+        Self::from_time_value(seconds * 1000.0)
+    }
+
+    /// From a dictionary like `Time.get_datetime_dict_from_system()` gives,
+    /// read as UTC.
+    pub fn from_datetime_dict(datetime: &Dictionary) -> Self {
+        let seconds = Time::singleton().get_unix_time_from_datetime_dict(datetime.clone());
+        Self::from_unix_time(seconds as f64)
+    }
+
+    /// What `getTime()` gives.
+    pub fn time_value(self) -> f64 {
+        self.0
+    }
+
+    pub fn unix_time(self) -> f64 {
+        self.0 / 1000.0
+    }
+
+    pub fn is_valid(self) -> bool {
+        !self.0.is_nan()
+    }
+
+    /// `year`, `month`, `day`, `weekday`, `hour`, `minute` and `second` in
+    /// UTC, `None` for an invalid date.
+    pub fn to_datetime_dict(self) -> Option<Dictionary> {
+        match self.is_valid() {
+            true => Some(
+                Time::singleton().get_datetime_dict_from_unix_time(self.unix_time().floor() as i64),
+            ),
+            false => None,
+        }
+    }
+
+    fn fields(self) -> Option<Fields> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let days = (self.0 / MS_PER_DAY).floor() as i64;
+        let time = self.0.rem_euclid(MS_PER_DAY) as i64;
+        let (year, month, day) = civil_from_days(days);
+
+        return Some(Fields {
+            year,
+            month,
+            day,
+            weekday: (days + 4).rem_euclid(7) as usize,
+            hours: time / 3_600_000,
+            minutes: time / 60_000 % 60,
+            seconds: time / 1000 % 60,
+            milliseconds: time % 1000,
+        });
+    }
+
+    /// `toISOString()`, `None` where JS throws a `RangeError`.
+    pub fn to_iso_string(self) -> Option<String> {
+        let f = self.fields()?;
+        let year = match f.year {
+            0..=9999 => format!("{:04}", f.year),
+            y if y < 0 => format!("-{:06}", -y),
+            y => format!("+{y:06}"),
+        };
+
+        Some(format!(
+            "{year}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            f.month + 1,
+            f.day,
+            f.hours,
+            f.minutes,
+            f.seconds,
+            f.milliseconds
+        ))
+    }
+
+    /// `toString()`, in UTC rather than the local time zone so the result
+    /// doesn't depend on the machine.
+    pub(crate) fn to_js_string(self) -> String {
+        let f = match self.fields() {
+            Some(v) => v,
+            None => return String::from("Invalid Date"),
+        };
+        let year = match f.year {
+            y if y < 0 => format!("-{:04}", -y),
+            y => format!("{y:04}"),
+        };
+
+        format!(
+            "{} {} {:02} {year} {:02}:{:02}:{:02} GMT+0000 (Coordinated Universal Time)",
+            WEEKDAYS[f.weekday], MONTHS[f.month], f.day, f.hours, f.minutes, f.seconds
+        )
+    }
+}
+
+/// Year, zero based month and day of the proleptic Gregorian calendar for a
+/// number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, usize, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 2
+    } else {
+        shifted_month - 10
+    };
+    let year = year_of_era + era * 400 + if month <= 1 { 1 } else { 0 };
+
+    (year, month as usize, day)
+}
+
+/// A JS `RegExp`, its pattern source and flags like `gi`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegExp {
+    pub source: String,
+    pub flags: String,
+}
+
+impl RegExp {
+    pub fn new(source: impl Into<String>, flags: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            flags: flags.into(),
+        }
+    }
+
+    /// The pattern as a Godot `RegEx`, see `to_pcre_pattern`. `None` if PCRE
+    /// doesn't accept the pattern.
+    pub fn to_godot_regex(&self) -> Option<Gd<RegEx>> {
+        RegEx::create_from_string(GString::from(self.to_pcre_pattern()))
+    }
+
+    /// The reverse of `to_godot_regex`, see `from_pcre_pattern`.
+    pub fn from_godot_regex(regex: &Gd<RegEx>) -> Self {
+        Self::from_pcre_pattern(&regex.get_pattern().to_string())
+    }
+
+    /// The source with the `i`, `m` and `s` flags as inline modifiers, the
+    /// others have no PCRE counterpart and are dropped.
+    pub fn to_pcre_pattern(&self) -> String {
+        let modifiers: String = self
+            .flags
+            .chars()
+            .filter(|flag| matches!(flag, 'i' | 'm' | 's'))
+            .collect();
+        return match modifiers.is_empty() {
+            true => self.source.clone(),
+            false => format!("(?{modifiers}){}", self.source),
+        };
+    }
+
+    /// The reverse of `to_pcre_pattern`, leading `(?ims)` modifiers become
+    /// flags again. Other modifiers stay in the source.
+    pub fn from_pcre_pattern(pattern: &str) -> Self {
+        let modifiers = pattern
+            .strip_prefix("(?")
+            .and_then(|rest| rest.split_once(')'))
+            .filter(|(modifiers, _)| {
+                !modifiers.is_empty() && modifiers.chars().all(|c| matches!(c, 'i' | 'm' | 's'))
+            });
+
+        return match modifiers {
+            Some((modifiers, source)) => {
+                let flags: String = "ims".chars().filter(|c| modifiers.contains(*c)).collect();
+                Self::new(source, flags)
+            }
+            None => Self::new(pattern, ""),
+        };
+    }
+}
+
+impl std::fmt::Display for RegExp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/{}/{}", self.source, self.flags)
+    }
+}
+
+/// A JS error object, e.g. one a script rejected a promise with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsError {
+    /// `Error`, `TypeError` or a custom name.
+    pub name: String,
+    pub message: String,
+    pub stack: Option<String>,
+}
+
+impl JsError {
+    pub fn new(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            message: message.into(),
+            stack: None,
+        }
+    }
+
+    /// `{ name, message, stack }`, `stack` only when there is one.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dictionary = Dictionary::new();
+        dictionary.set("name", GString::from(self.name.as_str()));
+        dictionary.set("message", GString::from(self.message.as_str()));
+        if let Some(stack) = &self.stack {
+            dictionary.set("stack", GString::from(stack.as_str()));
+        }
+        dictionary
+    }
+}
+
+/// `Error.prototype.toString`.
+impl std::fmt::Display for JsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.name.is_empty(), self.message.is_empty()) {
+            (_, true) => write!(f, "{}", self.name),
+            (true, false) => write!(f, "{}", self.message),
+            (false, false) => write!(f, "{}: {}", self.name, self.message),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use rusty_v8 as v8;
+
+use crate::{error::Error, helper::AsValue, FromValue, Value};
+
+/// The arguments of a call into a `Callable::Native`. Nothing is converted
+/// up front, each accessor only converts what it is asked for.
+pub struct CallbackArgs<'a, 's> {
+    scope: &'a mut v8::HandleScope<'s>,
+    source: Source<'a, 's>,
+}
+
+/// What the callable was invoked by.
+enum Source<'a, 's> {
+    Call(&'a v8::FunctionCallbackArguments<'s>),
+    /// A property read, or a write with the assigned value as the only
+    /// argument.
+    Property {
+        this: v8::Local<'s, v8::Object>,
+        value: Option<v8::Local<'s, v8::Value>>,
+        undefined: v8::Local<'s, v8::Value>,
+    },
+}
+
+impl<'a, 's> CallbackArgs<'a, 's> {
+    pub(crate) fn new(
+        scope: &'a mut v8::HandleScope<'s>,
+        args: &'a v8::FunctionCallbackArguments<'s>,
+    ) -> Self {
+        Self {
+            scope,
+            source: Source::Call(args),
+        }
+    }
+
+    /// The arguments of an accessor, `value` is the one assigned to it.
+    pub(crate) fn property(
+        scope: &'a mut v8::HandleScope<'s>,
+        this: v8::Local<'s, v8::Object>,
+        value: Option<v8::Local<'s, v8::Value>>,
+    ) -> Self {
+        let undefined = v8::undefined(scope).into();
+        Self {
+            scope,
+            source: Source::Property {
+                this,
+                value,
+                undefined,
+            },
+        }
+    }
+
+    /// How many arguments were passed, `arguments.length`.
+    pub fn len(&self) -> usize {
+        match &self.source {
+            Source::Call(args) => args.length().max(0) as usize,
+            Source::Property { value, .. } => value.is_some() as usize,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Converts argument `index`, `undefined` when it wasn't passed. The
+    /// error path starts with the index.
+    pub fn get<T: FromValue>(&mut self, index: usize) -> Result<T, Error> {
+        T::from_value(self.value(index)).map_err(|e| e.at_index(index))
+    }
+
+    /// Argument `index` as a `Value`, `undefined` when it wasn't passed.
+    pub fn value(&mut self, index: usize) -> Value {
+        let value = self.raw(index);
+        value.as_value(self.scope)
+    }
+
+    /// Every argument as a `Value`, what `Callable::Closure` receives.
+    pub fn values(&mut self) -> Vec<Value> {
+        (0..self.len()).map(|i| self.value(i)).collect()
+    }
+
+    /// The receiver, the global object for a plain call.
+    pub fn this(&mut self) -> Value {
+        let this: v8::Local<v8::Value> = match &self.source {
+            Source::Call(args) => args.this().into(),
+            Source::Property { this, .. } => (*this).into(),
+        };
+        this.as_value(self.scope)
+    }
+
+    /// `new.target`, `undefined` unless called with `new`.
+    pub fn new_target(&mut self) -> Value {
+        match &self.source {
+            Source::Call(args) => args.new_target().as_value(self.scope),
+            Source::Property { .. } => Value::Undefined,
+        }
+    }
+
+    /// Argument `index` without conversion.
+    pub fn raw(&self, index: usize) -> v8::Local<'s, v8::Value> {
+        match &self.source {
+            Source::Call(args) => args.get(index.min(i32::MAX as usize) as i32),
+            Source::Property {
+                value: Some(value), ..
+            } if index == 0 => *value,
+            Source::Property { undefined, .. } => *undefined,
+        }
+    }
+
+    /// The underlying V8 arguments, `None` for a property accessor.
+    pub fn arguments(&self) -> Option<&v8::FunctionCallbackArguments<'s>> {
+        match &self.source {
+            Source::Call(args) => Some(args),
+            Source::Property { .. } => None,
+        }
+    }
+
+    /// The scope of the call, to work with `raw` values.
+    pub fn scope(&mut self) -> &mut v8::HandleScope<'s> {
+        self.scope
+    }
+}
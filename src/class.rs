@@ -0,0 +1,383 @@
+use std::{any::TypeId, cell::RefCell, ffi::c_void};
+
+use crate::{
+    context::{throw_error, CallbackScope},
+    error::Error,
+    helper::AsValue,
+    owned, Value,
+};
+use rusty_v8::{self as v8, MapFnTo};
+
+/// Instance method, getter or setter of a native class.
+pub type MethodFn<T> = fn(&mut T, Vec<Value>) -> Result<Value, Error>;
+/// Static method of a native class.
+pub type StaticFn = fn(Vec<Value>) -> Result<Value, Error>;
+pub type ConstructorFn<T> = fn(Vec<Value>) -> Result<T, Error>;
+
+/// A Rust type exposed to JS as a class, usually implemented by `#[gdv8::class]`.
+pub trait NativeClass: Sized + 'static {
+    fn describe() -> ClassDescriptor<Self>;
+}
+
+/// Everything needed to build the JS class of a `NativeClass`.
+pub struct ClassDescriptor<T> {
+    name: &'static str,
+    constructor: Option<ConstructorFn<T>>,
+    methods: Vec<(&'static str, MethodFn<T>)>,
+    accessors: Vec<(&'static str, Option<MethodFn<T>>, Option<MethodFn<T>>)>,
+    statics: Vec<(&'static str, StaticFn)>,
+}
+
+impl<T: NativeClass> ClassDescriptor<T> {
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            constructor: None,
+            methods: vec![],
+            accessors: vec![],
+            statics: vec![],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Without a constructor `new` throws, instances can't be created from JS.
+    pub fn constructor(mut self, constructor: ConstructorFn<T>) -> Self {
+        self.constructor = Some(constructor);
+        self
+    }
+
+    pub fn method(mut self, name: &'static str, method: MethodFn<T>) -> Self {
+        self.methods.push((name, method));
+        self
+    }
+
+    pub fn getter(mut self, name: &'static str, getter: MethodFn<T>) -> Self {
+        match self.accessors.iter_mut().find(|(n, _, _)| *n == name) {
+            Some(accessor) => accessor.1 = Some(getter),
+            None => self.accessors.push((name, Some(getter), None)),
+        }
+        self
+    }
+
+    pub fn setter(mut self, name: &'static str, setter: MethodFn<T>) -> Self {
+        match self.accessors.iter_mut().find(|(n, _, _)| *n == name) {
+            Some(accessor) => accessor.2 = Some(setter),
+            None => self.accessors.push((name, None, Some(setter))),
+        }
+        self
+    }
+
+    pub fn static_method(mut self, name: &'static str, method: StaticFn) -> Self {
+        self.statics.push((name, method));
+        self
+    }
+
+    /// The trampolines and function pointers the class hands to V8, which a
+    /// snapshot holding the class has to know about.
+    pub(crate) fn external_references(&self) -> Vec<v8::ExternalReference<'static>> {
+        let mut references = vec![
+            v8::ExternalReference {
+                function: construct_trampoline::<T>.map_fn_to(),
+            },
+            v8::ExternalReference {
+                function: method_trampoline::<T>.map_fn_to(),
+            },
+            v8::ExternalReference {
+                function: static_trampoline.map_fn_to(),
+            },
+        ];
+
+        let mut pointers = vec![];
+        if let Some(constructor) = self.constructor {
+            pointers.push(constructor as *const ());
+        }
+        for (_, method) in &self.methods {
+            pointers.push(*method as *const ());
+        }
+        for (_, getter, setter) in &self.accessors {
+            pointers.extend(getter.iter().chain(setter).map(|v| *v as *const ()));
+        }
+        for (_, method) in &self.statics {
+            pointers.push(*method as *const ());
+        }
+
+        for pointer in pointers {
+            references.push(v8::ExternalReference {
+                pointer: pointer as *mut c_void,
+            });
+        }
+
+        references
+    }
+
+    /// Builds the constructor function of the class in the current context.
+    pub(crate) fn build<'s>(
+        &self,
+        scope: &mut v8::HandleScope<'s>,
+    ) -> Option<v8::Local<'s, v8::Function>> {
+        let constructor = match self.constructor {
+            Some(v) => v8::External::new(scope, v as *const () as *mut c_void).into(),
+            None => v8::null(scope).into(),
+        };
+
+        let template = v8::FunctionBuilder::<v8::FunctionTemplate>::new(construct_trampoline::<T>)
+            .data(constructor)
+            .build(scope);
+        template.set_class_name(v8::String::new(scope, self.name)?);
+        template
+            .instance_template(scope)
+            .set_internal_field_count(1);
+
+        let prototype = template.prototype_template(scope);
+        for (name, method) in &self.methods {
+            let key = v8::String::new(scope, name)?;
+            let function = method_template::<T>(scope, *method);
+            prototype.set(key.into(), function.into());
+        }
+
+        for (name, method) in &self.statics {
+            let key = v8::String::new(scope, name)?;
+            let data = v8::External::new(scope, *method as *const () as *mut c_void);
+            let function = v8::FunctionBuilder::<v8::FunctionTemplate>::new(static_trampoline)
+                .data(data.into())
+                .build(scope);
+            template.set(key.into(), function.into());
+        }
+
+        let class = template.get_function(scope)?;
+
+        if !self.accessors.is_empty() {
+            let prototype_key = v8::String::new(scope, "prototype")?;
+            let prototype = class.get(scope, prototype_key.into())?.to_object(scope)?;
+
+            for (name, getter, setter) in &self.accessors {
+                let getter = getter.map(|v| method_template::<T>(scope, v).get_function(scope));
+                let setter = setter.map(|v| method_template::<T>(scope, v).get_function(scope));
+                define_accessor(scope, prototype, name, getter.flatten(), setter.flatten())?;
+            }
+        }
+
+        Some(class)
+    }
+}
+
+/// Native state of a JS instance, pointed to by its first internal field.
+/// `repr(C)` keeps the type id at the start for every `T`.
+#[repr(C)]
+struct Instance<T> {
+    type_id: TypeId,
+    value: RefCell<T>,
+}
+
+/// Defines the class of `T` on the global object of the current context.
+pub(crate) fn register<T: NativeClass>(scope: &mut v8::HandleScope<'_>) -> Result<(), Error> {
+    let descriptor = T::describe();
+
+    let class = match descriptor.build(scope) {
+        Some(v) => v,
+        None => {
+            return Err(Error::TypeError(format!(
+                "could not build class {}",
+                descriptor.name()
+            )))
+        }
+    };
+
+    let name = v8::String::new(scope, descriptor.name()).unwrap();
+    scope
+        .get_current_context()
+        .global(scope)
+        .set(scope, name.into(), class.into());
+
+    return Ok(());
+}
+
+/// `ClassDescriptor::external_references` of a class, as a plain function
+/// the runtime and snapshot builders can keep.
+pub(crate) type ClassReferences = fn() -> Vec<v8::ExternalReference<'static>>;
+/// `register` of a class, for the snapshot builder.
+pub(crate) type ClassRegister = fn(&mut v8::HandleScope) -> Result<(), Error>;
+
+pub(crate) fn class_references<T: NativeClass>() -> Vec<v8::ExternalReference<'static>> {
+    T::describe().external_references()
+}
+
+fn method_template<'s, T: NativeClass>(
+    scope: &mut v8::HandleScope<'s>,
+    method: MethodFn<T>,
+) -> v8::Local<'s, v8::FunctionTemplate> {
+    let data = v8::External::new(scope, method as *const () as *mut c_void);
+    v8::FunctionBuilder::<v8::FunctionTemplate>::new(method_trampoline::<T>)
+        .data(data.into())
+        .build(scope)
+}
+
+/// `Object.defineProperty(object, name, { get, set, configurable: true })`
+fn define_accessor(
+    scope: &mut v8::HandleScope<'_>,
+    object: v8::Local<v8::Object>,
+    name: &str,
+    getter: Option<v8::Local<v8::Function>>,
+    setter: Option<v8::Local<v8::Function>>,
+) -> Option<()> {
+    let descriptor = v8::Object::new(scope);
+
+    if let Some(getter) = getter {
+        let key = v8::String::new(scope, "get")?;
+        descriptor.set(scope, key.into(), getter.into());
+    }
+    if let Some(setter) = setter {
+        let key = v8::String::new(scope, "set")?;
+        descriptor.set(scope, key.into(), setter.into());
+    }
+
+    let key = v8::String::new(scope, "configurable")?;
+    let value = v8::Boolean::new(scope, true);
+    descriptor.set(scope, key.into(), value.into());
+
+    let global = scope.get_current_context().global(scope);
+    let object_key = v8::String::new(scope, "Object")?;
+    let define_key = v8::String::new(scope, "defineProperty")?;
+    let define_property = global
+        .get(scope, object_key.into())?
+        .to_object(scope)?
+        .get(scope, define_key.into())?;
+    let define_property = v8::Local::<v8::Function>::try_from(define_property).ok()?;
+
+    let name = v8::String::new(scope, name)?;
+    let recv = v8::undefined(scope).into();
+    define_property.call(
+        scope,
+        recv,
+        &[object.into(), name.into(), descriptor.into()],
+    )?;
+
+    Some(())
+}
+
+fn collect_args(
+    scope: &mut v8::HandleScope<'_>,
+    args: &v8::FunctionCallbackArguments,
+) -> Vec<Value> {
+    (0..args.length())
+        .map(|i| args.get(i).as_value(scope))
+        .collect()
+}
+
+/// Native state behind `this`, `None` if `this` isn't an instance of `T`.
+fn instance<'a, T: NativeClass>(
+    scope: &mut v8::HandleScope<'_>,
+    this: v8::Local<v8::Object>,
+) -> Option<&'a Instance<T>> {
+    if this.internal_field_count() < 1 {
+        return None;
+    }
+
+    let field = this.get_internal_field(scope, 0)?;
+    let pointer = v8::Local::<v8::External>::try_from(field).ok()?.value();
+    if pointer.is_null() || unsafe { *(pointer as *const TypeId) } != TypeId::of::<T>() {
+        return None;
+    }
+
+    Some(unsafe { &*(pointer as *const Instance<T>) })
+}
+
+fn construct_trampoline<T: NativeClass>(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    _retval: v8::ReturnValue,
+) {
+    if args.new_target().is_undefined() {
+        let message = format!(
+            "Class constructor {} cannot be invoked without 'new'",
+            T::describe().name()
+        );
+        return throw_error(scope, Error::TypeError(message));
+    }
+
+    let constructor = match v8::Local::<v8::External>::try_from(args.data().unwrap()) {
+        Ok(v) => unsafe { std::mem::transmute::<*mut c_void, ConstructorFn<T>>(v.value()) },
+        Err(_) => {
+            let message = format!("{} has no constructor", T::describe().name());
+            return throw_error(scope, Error::TypeError(message));
+        }
+    };
+
+    let call_args = collect_args(scope, &args);
+    let entered = CallbackScope::enter(scope);
+    let value = constructor(call_args);
+    drop(entered);
+
+    let value = match value {
+        Ok(v) => v,
+        Err(e) => return throw_error(scope, e),
+    };
+
+    // the native state lives as long as the JS object or its context
+    let this = args.this();
+    let instance = Instance {
+        type_id: TypeId::of::<T>(),
+        value: RefCell::new(value),
+    };
+    let pointer = owned::attach(scope, this, Box::new(instance));
+
+    let external = v8::External::new(scope, pointer as *mut c_void);
+    this.set_internal_field(0, external.into());
+}
+
+fn method_trampoline<T: NativeClass>(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let method = match v8::Local::<v8::External>::try_from(args.data().unwrap()) {
+        Ok(v) => unsafe { std::mem::transmute::<*mut c_void, MethodFn<T>>(v.value()) },
+        Err(_) => return,
+    };
+
+    let instance = match instance::<T>(scope, args.this()) {
+        Some(v) => v,
+        None => return throw_error(scope, Error::TypeError("Illegal invocation".to_string())),
+    };
+
+    let call_args = collect_args(scope, &args);
+
+    let entered = CallbackScope::enter(scope);
+    let result = match instance.value.try_borrow_mut() {
+        Ok(mut value) => method(&mut value, call_args),
+        Err(_) => Err(Error::TypeError(format!(
+            "{} is already in use",
+            T::describe().name()
+        ))),
+    };
+    drop(entered);
+
+    match result {
+        Ok(v) => retval.set(v.as_local(scope)),
+        Err(e) => throw_error(scope, e),
+    };
+}
+
+fn static_trampoline(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let method = match v8::Local::<v8::External>::try_from(args.data().unwrap()) {
+        Ok(v) => unsafe { std::mem::transmute::<*mut c_void, StaticFn>(v.value()) },
+        Err(_) => return,
+    };
+
+    let call_args = collect_args(scope, &args);
+    let entered = CallbackScope::enter(scope);
+    let result = method(call_args);
+    drop(entered);
+
+    match result {
+        Ok(v) => retval.set(v.as_local(scope)),
+        Err(e) => throw_error(scope, e),
+    };
+}
@@ -0,0 +1,105 @@
+use crate::{error::Error, helper};
+use godot::builtin::GString;
+use godot::classes::{file_access::ModeFlags, DirAccess, FileAccess};
+use rusty_v8 as v8;
+
+const MAGIC: &[u8; 4] = b"GDV8";
+const CACHE_DIR: &str = "user://gdv8/code_cache";
+
+/// Compiled script data produced by V8, valid only for the exact source and
+/// V8 version it was created with.
+#[derive(Clone, Debug)]
+pub struct CodeCache {
+    source_hash: u64,
+    v8_version: String,
+    data: Vec<u8>,
+}
+
+impl CodeCache {
+    /// Wraps code cache `data` V8 produced for `source`, e.g. bytes kept in
+    /// a custom store instead of `user://`.
+    pub fn new(source: &str, data: Vec<u8>) -> Self {
+        Self {
+            source_hash: helper::hash(source.as_bytes()),
+            v8_version: v8::V8::get_version().to_string(),
+            data,
+        }
+    }
+
+    /// Whether the cache can be consumed for `source` by the running V8.
+    pub fn is_valid_for(&self, source: &str) -> bool {
+        self.source_hash == helper::hash(source.as_bytes())
+            && self.v8_version == v8::V8::get_version()
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let version = self.v8_version.as_bytes();
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 12 + version.len() + self.data.len());
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&self.source_hash.to_le_bytes());
+        bytes.extend_from_slice(&(version.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(version);
+        bytes.extend_from_slice(&self.data);
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.strip_prefix(MAGIC)?;
+
+        let source_hash = u64::from_le_bytes(bytes.get(0..8)?.try_into().ok()?);
+        let version_len = u32::from_le_bytes(bytes.get(8..12)?.try_into().ok()?) as usize;
+        let v8_version = std::str::from_utf8(bytes.get(12..12 + version_len)?).ok()?;
+        let data = bytes.get(12 + version_len..)?;
+
+        Some(Self {
+            source_hash,
+            v8_version: v8_version.to_string(),
+            data: data.to_vec(),
+        })
+    }
+
+    /// Loads a cache file, `res://` and `user://` paths work.
+    pub fn load(path: &str) -> Option<Self> {
+        helper::read_file(path).and_then(|bytes| Self::from_bytes(&bytes))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        let path = GString::from(path);
+
+        DirAccess::make_dir_recursive_absolute(path.get_base_dir());
+
+        let mut file = match FileAccess::open(path.clone(), ModeFlags::WRITE) {
+            Some(v) => v,
+            None => return Err(Error::Io(format!("could not write code cache {path}"))),
+        };
+
+        file.store_buffer(godot::builtin::PackedByteArray::from(self.to_bytes().as_slice()));
+        file.close();
+
+        return Ok(());
+    }
+
+    /// Looks up the cache for a script, a `.jsc` file imported next to the
+    /// script wins over the one in `user://gdv8/code_cache`.
+    pub fn load_for(script_path: &str) -> Option<Self> {
+        Self::load(&format!("{script_path}c")).or_else(|| Self::load(&Self::path_for(script_path)))
+    }
+
+    /// Writable cache location for a script under `user://gdv8/code_cache`.
+    pub fn path_for(script_path: &str) -> String {
+        let name: String = script_path
+            .trim_start_matches("res://")
+            .trim_start_matches("user://")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '_' })
+            .collect();
+
+        format!("{CACHE_DIR}/{name}c")
+    }
+}
@@ -0,0 +1,249 @@
+//! The ECMAScript type conversions (ToPrimitive, ToNumber, ToString, ...).
+
+use crate::Value;
+
+/// The preferred type handed to `Value::to_primitive`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    /// What `+` and `==` ask for.
+    Default,
+    /// What arithmetic and relational comparison ask for.
+    Number,
+    /// What template literals and `String(value)` ask for.
+    String,
+}
+
+/// WhiteSpace and LineTerminator besides the U+2000 to U+200A range.
+const WHITESPACE: &[char] = &[
+    '\t', '\n', '\u{b}', '\u{c}', '\r', ' ', '\u{a0}', '\u{1680}', '\u{2028}', '\u{2029}',
+    '\u{202f}', '\u{205f}', '\u{3000}', '\u{feff}',
+];
+
+/// Trimmed by StringToNumber.
+fn is_js_whitespace(c: char) -> bool {
+    WHITESPACE.contains(&c) || ('\u{2000}'..='\u{200a}').contains(&c)
+}
+
+/// StringToNumber, `NaN` for anything that isn't a StringNumericLiteral.
+fn string_to_number(source: &str) -> f64 {
+    let source = source.trim_matches(is_js_whitespace);
+    if source.is_empty() {
+        return 0.0;
+    }
+
+    let radix = match source.get(..2) {
+        Some("0x" | "0X") => Some(16),
+        Some("0o" | "0O") => Some(8),
+        Some("0b" | "0B") => Some(2),
+        _ => None,
+    };
+    if let Some(radix) = radix {
+        let digits = &source[2..];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return f64::NAN;
+        }
+        // accumulated in f64 since the literal may exceed any integer type
+        return digits.chars().fold(0.0, |number, c| {
+            number * radix as f64 + c.to_digit(radix).unwrap() as f64
+        });
+    }
+
+    let (sign, unsigned) = match source.as_bytes()[0] {
+        b'-' => (-1.0, &source[1..]),
+        b'+' => (1.0, &source[1..]),
+        _ => (1.0, source),
+    };
+    if unsigned == "Infinity" {
+        return sign * f64::INFINITY;
+    }
+
+    match is_decimal_literal(unsigned) {
+        true => sign * unsigned.parse::<f64>().unwrap_or(f64::NAN),
+        false => f64::NAN,
+    }
+}
+
+/// StrUnsignedDecimalLiteral without `Infinity`: `1`, `1.`, `.5`, `1.5e-3`.
+/// Checked up front because `str::parse` also takes `inf` and `nan`.
+fn is_decimal_literal(source: &str) -> bool {
+    let bytes = source.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut i = digits(0);
+    let mut mantissa = i;
+    if bytes.get(i) == Some(&b'.') {
+        let fraction = digits(i + 1);
+        mantissa += fraction;
+        i += 1 + fraction;
+    }
+    if mantissa == 0 {
+        return false;
+    }
+
+    if let Some(b'e' | b'E') = bytes.get(i) {
+        i += 1;
+        if let Some(b'+' | b'-') = bytes.get(i) {
+            i += 1;
+        }
+        let exponent = digits(i);
+        if exponent == 0 {
+            return false;
+        }
+        i += exponent;
+    }
+
+    i == bytes.len()
+}
+
+/// Number::toString with radix 10: the shortest digits that round trip,
+/// written out between 1e-7 and 1e21 and in exponent form outside of it.
+pub(crate) fn number_to_string(number: f64) -> String {
+    if number.is_nan() {
+        return String::from("NaN");
+    }
+    if number == 0.0 {
+        return String::from("0");
+    }
+    if number.is_infinite() {
+        return String::from(if number > 0.0 {
+            "Infinity"
+        } else {
+            "-Infinity"
+        });
+    }
+
+    let sign = if number < 0.0 { "-" } else { "" };
+
+    // `{:e}` prints the shortest round trip digits, e.g. `1.2345e-7`
+    let scientific = format!("{:e}", number.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap() + 1;
+
+    let body = if k <= n && n <= 21 {
+        digits + &"0".repeat((n - k) as usize)
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        let exponent = match n - 1 {
+            e if e < 0 => format!("e{e}"),
+            e => format!("e+{e}"),
+        };
+        match k {
+            1 => digits + &exponent,
+            _ => format!("{}.{}{exponent}", &digits[..1], &digits[1..]),
+        }
+    };
+
+    format!("{sign}{body}")
+}
+
+/// Array.prototype.join with `,`, the string form of an array.
+fn join(items: &[Value]) -> String {
+    items
+        .iter()
+        .map(|v| match v {
+            Value::Null | Value::Undefined => String::new(),
+            v => v.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl Value {
+    /// ToPrimitive. A `Date` gives its time value for `Hint::Number` and its
+    /// string form otherwise, other objects have no custom `valueOf` so every
+    /// hint ends up with their string form.
+    pub fn to_primitive(&self, hint: Hint) -> Value {
+        match self {
+            Value::Date(v) if hint == Hint::Number => Value::Number(v.time_value()),
+            Value::Object(_)
+            | Value::Array(_)
+            | Value::Function(_)
+            | Value::ArrayBuffer(_)
+            | Value::TypedArray(_)
+            | Value::Date(_)
+            | Value::RegExp(_)
+            | Value::Map(_)
+            | Value::Set(_)
+            | Value::Error(_) => Value::String(self.to_string()),
+            v => v.clone(),
+        }
+    }
+
+    /// ToNumber, what unary `+` does.
+    pub fn to_number(&self) -> f64 {
+        match self {
+            Value::String(v) => string_to_number(v),
+            Value::Number(v) => *v,
+            Value::Boolean(v) => *v as u8 as f64,
+            Value::Null => 0.0,
+            Value::Undefined => f64::NAN,
+            Value::Object(_)
+            | Value::Array(_)
+            | Value::Function(_)
+            | Value::ArrayBuffer(_)
+            | Value::TypedArray(_)
+            | Value::Date(_)
+            | Value::RegExp(_)
+            | Value::Map(_)
+            | Value::Set(_)
+            | Value::Error(_) => self.to_primitive(Hint::Number).to_number(),
+        }
+    }
+
+    /// ToString, what `String(value)` does.
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        match self {
+            Value::String(v) => v.clone(),
+            Value::Number(v) => number_to_string(*v),
+            Value::Boolean(v) => v.to_string(),
+            Value::Null => String::from("null"),
+            Value::Undefined => String::from("undefined"),
+            Value::Object(_) => String::from("[object Object]"),
+            Value::Array(v) => join(&v.items),
+            Value::Function(v) => v.source().unwrap_or_default().to_string(),
+            Value::ArrayBuffer(_) => String::from("[object ArrayBuffer]"),
+            Value::TypedArray(v) => v.iter().map(number_to_string).collect::<Vec<_>>().join(","),
+            Value::Date(v) => v.to_js_string(),
+            Value::RegExp(v) => v.to_string(),
+            Value::Map(_) => String::from("[object Map]"),
+            Value::Set(_) => String::from("[object Set]"),
+            Value::Error(v) => v.to_string(),
+        }
+    }
+
+    /// ToIntegerOrInfinity, the number truncated towards zero with `NaN`
+    /// as 0.
+    pub fn to_integer(&self) -> f64 {
+        let number = self.to_number();
+        match number.is_nan() {
+            true => 0.0,
+            // `+ 0.0` turns -0 into 0
+            false => number.trunc() + 0.0,
+        }
+    }
+
+    /// ToInt32, numbers wrap modulo 2^32 like in bitwise operators.
+    pub fn to_int32(&self) -> i32 {
+        self.to_uint32() as i32
+    }
+
+    /// ToUint32, numbers wrap modulo 2^32 like in `>>>`.
+    pub fn to_uint32(&self) -> u32 {
+        let number = self.to_number();
+        match number.is_finite() {
+            true => number.trunc().rem_euclid(4294967296.0) as u32,
+            false => 0,
+        }
+    }
+}
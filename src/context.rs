@@ -1,13 +1,42 @@
 use crate::{
-    error::Error,
-    helper::{AsLocal, AsValue},
-    Callable, Runtime, Value, V8_RUNTIME,
+    class,
+    error::{Error, Exception},
+    helper::{self, AsLocal, AsValue},
+    owned, proxy, source_map, typescript, variant, Callable, CallbackArgs, CodeCache,
+    NamespaceDescriptor, NativeClass, ObjectHandle, Runtime, RuntimeBuilder, Signature, Value,
+    V8_RUNTIME,
+};
+use godot::{
+    builtin::{Dictionary, GString, Variant, VariantArray},
+    meta::{FromGodot, ToGodot},
+    prelude::{godot_api, Base, GodotClass, IRefCounted, RefCounted},
 };
-use godot::meta::{FromGodot, ToGodot};
 use rusty_v8::{self as v8};
+use std::{cell::RefCell, collections::HashMap, ffi::c_void, rc::Rc, sync::Mutex};
 
 static mut CONTEXT_COUNT: u64 = 0;
 
+/// Context scopes by id, for values like `Function` that only know the id of
+/// the context they came from.
+static CONTEXT_SCOPES: Mutex<Option<HashMap<u64, ContextScopePtr>>> = Mutex::new(None);
+
+struct ContextScopePtr {
+    scope: *mut v8::ContextScope<'static, v8::HandleScope<'static>>,
+    context: *mut v8::Local<'static, v8::Context>,
+}
+
+unsafe impl Send for ContextScopePtr {}
+
+thread_local! {
+    /// Scopes of the native callbacks running right now, innermost last. A
+    /// new scope has to be opened on top of the innermost one, not on the
+    /// root scope of a context.
+    static CALLBACK_SCOPES: RefCell<Vec<*mut v8::HandleScope<'static>>> = RefCell::new(vec![]);
+}
+
+/// Name of the private global property holding the context id.
+const CONTEXT_ID_KEY: &str = "gdv8::contextId";
+
 pub struct Context {
     id: u64,
     handle_scope_ptr: *mut v8::HandleScope<'static, ()>,
@@ -20,7 +49,14 @@ unsafe impl Send for Context {}
 impl Context {
     pub fn new() -> Self {
         let guard = V8_RUNTIME.lock().unwrap();
-        let runtime = guard.get_or_init(|| Runtime::new());
+        let runtime = guard.get_or_init(|| {
+            let builder = RuntimeBuilder::from_project_settings();
+            // V8 still works with the settings it already runs with
+            if let Err(e) = builder.initialize_v8() {
+                godot::prelude::godot_error!("{e}, the gdv8/runtime settings are ignored");
+            }
+            Runtime::new(builder)
+        });
 
         let isolate = runtime
             .get_isolate()
@@ -37,18 +73,41 @@ impl Context {
         let context_scope_ptr =
             Box::into_raw(Box::new(v8::ContextScope::new(handle_scope, *context)));
 
+        let id = unsafe {
+            CONTEXT_COUNT += 1;
+            CONTEXT_COUNT
+        };
+
+        let context_scope = unsafe { context_scope_ptr.as_mut().unwrap() };
+        helper::snapshot_builtins(context_scope);
+        source_map::install(context_scope);
+        set_context_id(context_scope, id);
+
+        CONTEXT_SCOPES
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(
+                id,
+                ContextScopePtr {
+                    scope: context_scope_ptr,
+                    context: context_ptr,
+                },
+            );
+
         return Self {
-            id: unsafe {
-                CONTEXT_COUNT += 1;
-                CONTEXT_COUNT
-            },
+            id,
             handle_scope_ptr,
             context_ptr,
             context_scope_ptr,
         };
     }
 
-    fn context_scope(
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub(crate) fn context_scope(
         &self,
     ) -> Result<&mut v8::ContextScope<'static, v8::HandleScope<'static>>, Error> {
         return match unsafe { self.context_scope_ptr.as_mut() } {
@@ -57,157 +116,389 @@ impl Context {
         };
     }
 
+    /// Runs a script named `<anonymous>`, see `run_script_named`.
     pub fn run_script(&self, source: &str) -> Result<v8::Local<'_, v8::Value>, Error> {
+        self.run_script_named(source, "<anonymous>", 0, 0)
+    }
+
+    /// Runs a script under a resource name so stack frames and errors point to
+    /// `name` instead of `<anonymous>`. TypeScript names are transpiled first.
+    /// The offsets are zero based and shift reported positions, e.g. for
+    /// scripts embedded in another file.
+    pub fn run_script_named(
+        &self,
+        source: &str,
+        name: &str,
+        line_offset: i32,
+        column_offset: i32,
+    ) -> Result<v8::Local<'_, v8::Value>, Error> {
         let scope = self.context_scope()?;
 
         let scope = &mut v8::TryCatch::new(scope);
 
+        let source = typescript::maybe_transpile(name, source)?;
+        let source = source.as_ref();
+        source_map::register(name, source, line_offset, column_offset);
+        let origin = script_origin(scope, source, name, line_offset, column_offset);
         let source = source.as_local(scope)?;
-        let name = "name".as_local(scope)?.into();
 
-        let result = v8::Script::compile(scope, source, None).and_then(|script| script.run(scope));
+        let result =
+            v8::Script::compile(scope, source, Some(&origin)).and_then(|script| script.run(scope));
 
         return match result {
             Some(v) => Ok(v.clone()),
-            None => Err(match scope.has_caught() {
-                true => Error::Exception(
-                    scope
-                        .exception()
-                        .unwrap()
-                        .to_object(scope)
-                        .unwrap()
-                        .get(scope, name)
-                        .unwrap()
-                        .to_rust_string_lossy(scope),
-                ),
-                false => Error::None,
-            }),
+            None => Err(caught_exception(scope)),
         };
     }
 
-    pub fn register_callable(&self, identifier: &str, callable: Callable) -> Result<(), Error> {
-        let function = |scope: &mut v8::HandleScope<'_>,
-                        args: v8::FunctionCallbackArguments,
-                        mut retval: v8::ReturnValue| {
-            let scope = &mut v8::TryCatch::new(scope);
-            let mut weak: Vec<Value> = vec![];
-
-            for i in 0..args.length() {
-                // godot_print!("PARSING ARG {}", i);
-                let current_arg = args.get(i).as_value(scope);
-                // godot_print!("current arg: {current_arg}");
-                // let value = current_arg.to_rust_string_lossy(scope);
-                weak.push(current_arg);
-            }
+    /// Runs a script, consuming `cache` if it is still valid for `source`.
+    /// Returns a fresh code cache whenever the given one was missing or rejected.
+    pub fn run_script_cached(
+        &self,
+        source: &str,
+        name: &str,
+        cache: Option<&CodeCache>,
+    ) -> Result<(v8::Local<'_, v8::Value>, Option<CodeCache>), Error> {
+        let scope = self.context_scope()?;
 
-            let data = args.data().unwrap().to_object(scope).unwrap();
-            let context_id_key = v8::String::new(scope, "contextId").unwrap();
-            let identifier_key = v8::String::new(scope, "identifier").unwrap();
-            let name = v8::String::new(scope, "name").unwrap();
-
-            let context_id = data
-                .get(scope, context_id_key.into())
-                .unwrap()
-                .number_value(scope)
-                .unwrap()
-                .to_be_bytes();
-            let context_id = u64::from_be_bytes(context_id);
-
-            let callback_identifier = data
-                .get(scope, identifier_key.into())
-                .unwrap()
-                .to_string(scope)
-                .unwrap()
-                .to_rust_string_lossy(scope);
-
-            let mut guard = V8_RUNTIME.lock().unwrap();
-            let runtime = guard
-                .get_mut()
-                .expect("exposed callable run without runtime");
-
-            let registry = runtime.get_registry(context_id);
-            let callback = registry
-                .get(&callback_identifier)
-                .expect("callbacks should be registered at this point");
-
-            let godot_args = godot::builtin::Array::from_iter(weak.iter().map(|v| v.to_godot()));
-
-            let result = match callback {
-                Callable::Godot(callable) => {
-                    // godot_print!("callable: {callable}, {}", callable.is_valid());
-                    let result = callable.callv(&godot::builtin::VariantArray::from(godot_args));
-                    let result = Value::from_godot(result);
-                    result
-                }
-                Callable::Closure(v) => v(weak),
-            };
-            
-            retval.set(result.as_local(scope));
-
-            let _error = match scope.has_caught() {
-                true => Error::Exception(
-                    scope
-                        .exception()
-                        .unwrap()
-                        .to_object(scope)
-                        .unwrap()
-                        .get(scope, name.into())
-                        .unwrap()
-                        .to_rust_string_lossy(scope),
+        let scope = &mut v8::TryCatch::new(scope);
+
+        let source = typescript::maybe_transpile(name, source)?;
+        let source = source.as_ref();
+        source_map::register(name, source, 0, 0);
+        let origin = script_origin(scope, source, name, 0, 0);
+        let source_string = source.as_local(scope)?;
+
+        let cache = cache.filter(|cache| cache.is_valid_for(source));
+        let (mut compile_source, options) = match cache {
+            Some(cache) => (
+                v8::script_compiler::Source::new_with_cached_data(
+                    source_string,
+                    Some(&origin),
+                    v8::script_compiler::CachedData::new(cache.data()),
                 ),
-                false => Error::None,
-            };
+                v8::script_compiler::CompileOptions::ConsumeCodeCache,
+            ),
+            None => (
+                v8::script_compiler::Source::new(source_string, Some(&origin)),
+                v8::script_compiler::CompileOptions::NoCompileOptions,
+            ),
+        };
+
+        let script = match v8::script_compiler::compile(
+            scope,
+            &mut compile_source,
+            options,
+            v8::script_compiler::NoCacheReason::NoReason,
+        ) {
+            Some(v) => v,
+            None => return Err(caught_exception(scope)),
+        };
+
+        let rejected = match compile_source.get_cached_data() {
+            Some(data) => data.rejected(),
+            None => true,
+        };
+
+        let new_cache = match rejected {
+            true => script
+                .get_unbound_script(scope)
+                .create_code_cache()
+                .map(|data| CodeCache::new(source, data.to_vec())),
+            false => None,
+        };
+
+        return match script.run(scope) {
+            Some(v) => Ok((v, new_cache)),
+            None => Err(caught_exception(scope)),
+        };
+    }
+
+    /// Runs a script file, reusing and refreshing its code cache on disk.
+    pub fn run_script_file(&self, path: &str) -> Result<v8::Local<'_, v8::Value>, Error> {
+        let source = match crate::helper::read_file(path) {
+            Some(v) => String::from_utf8_lossy(&v).into_owned(),
+            None => return Err(Error::Io(format!("could not read script {path}"))),
+        };
+
+        let cache = CodeCache::load_for(path);
+        let (value, new_cache) = self.run_script_cached(&source, path, cache.as_ref())?;
+
+        if let Some(new_cache) = new_cache {
+            // a failed write only costs a recompile on the next launch
+            let _ = new_cache.save(&CodeCache::path_for(path));
+        }
+
+        return Ok(value);
+    }
+
+    /// Registers `callable` at a dotted path like `game.entities.spawn`,
+    /// creating `game` and `game.entities` if they are missing. A plain name
+    /// ends up on the global object.
+    pub fn register_callable(&self, path: &str, callable: Callable) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let function = callable_function(scope, Some(self.id), path);
+            let (object, key) = resolve_path(scope, path, true)?.unwrap();
+            define(scope, object, key, function.into(), path)
+        })?;
+
+        // only once the path is defined, a failed define leaves nothing behind
+        self.insert_callable(path, callable)
+    }
+
+    /// Supplies the callable behind a function declared with
+    /// `SnapshotBuilder::callable`, which throws until then.
+    pub fn implement(&self, path: &str, callable: Callable) -> Result<(), Error> {
+        self.insert_callable(path, callable)
+    }
+
+    /// Registers `callable` as the method `name` of `object`, e.g. an object
+    /// a script handed over, leaving the global object alone.
+    pub fn register_callable_on(
+        &self,
+        object: &ObjectHandle,
+        name: &str,
+        callable: Callable,
+    ) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let object = object.local(scope, self.id)?;
+            let function = owned_callable_function(scope, callable);
+            let key = v8::String::new(scope, name).unwrap().into();
+            define(scope, object, key, function.into(), name)
+        })
+    }
+
+    /// Registers the callables of `namespace` on the object at its path,
+    /// created if missing. Nested namespaces become child objects.
+    pub fn register_namespace(&self, namespace: NamespaceDescriptor) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let global = scope.get_current_context().global(scope);
+            self.fill_namespace(scope, global, "", namespace)
+        })
+    }
+
+    fn fill_namespace<'s>(
+        &self,
+        scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+        parent: v8::Local<'s, v8::Object>,
+        parent_path: &str,
+        namespace: NamespaceDescriptor,
+    ) -> Result<(), Error> {
+        let mut object = parent;
+        let mut path = parent_path.to_string();
+        for segment in path_segments(&namespace.path)? {
+            path = join_path(&path, segment);
+            object = child_object(scope, object, segment, &path)?;
+        }
+
+        for (name, callable) in namespace.callables {
+            let identifier = join_path(&path, &name);
+            let function = callable_function(scope, Some(self.id), &identifier);
+            let key = v8::String::new(scope, &name).unwrap().into();
+            define(scope, object, key, function.into(), &identifier)?;
+
+            self.insert_callable(&identifier, callable)?;
+        }
+
+        for (name, signature) in namespace.signatures {
+            self.declare(&join_path(&path, &name), signature)?;
+        }
+
+        for child in namespace.namespaces {
+            self.fill_namespace(scope, object, &path, child)?;
+        }
+
+        if namespace.frozen && helper::freeze(scope, object).is_none() {
+            return Err(caught_exception(scope));
+        }
+
+        return Ok(());
+    }
+
+    /// Defines an accessor at a dotted path like `game.time`, so scripts
+    /// read a value computed on every access instead of a copy. Without a
+    /// setter assignments are ignored, like for a getter only property.
+    pub fn define_property(
+        &self,
+        path: &str,
+        getter: Callable,
+        setter: Option<Callable>,
+    ) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let (object, key) = resolve_path(scope, path, true)?.unwrap();
+            define_accessor_property(scope, object, key, getter, setter, path)
+        })
+    }
+
+    /// Defines a value at a dotted path like `game.VERSION` that scripts can
+    /// neither assign nor delete.
+    pub fn define_constant(&self, path: &str, value: Value) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let value = value.as_local(scope);
+            let (object, key) = resolve_path(scope, path, true)?.unwrap();
+            let attributes = v8::PropertyAttribute::READ_ONLY | v8::PropertyAttribute::DONT_DELETE;
+            define_with(scope, object, key, value, attributes, path)
+        })
+    }
+
+    fn insert_callable(&self, identifier: &str, callable: Callable) -> Result<(), Error> {
+        let mut guard = V8_RUNTIME.lock().unwrap();
+        let runtime = match guard.get_mut() {
+            Some(v) => v,
+            None => return Err(Error::UnitializedRuntime),
         };
 
+        runtime
+            .get_registry(self.id)
+            .insert(identifier.to_string(), Rc::new(callable));
+        return Ok(());
+    }
+
+    /// Exposes a native class as a constructor on the global object.
+    pub fn register_class<T: NativeClass>(&self) -> Result<(), Error> {
+        self.with_scope(|scope| class::register::<T>(scope))
+    }
+
+    /// Records the types of a registered callable for the `.d.ts` generator,
+    /// needed for closures whose arguments can't be inspected.
+    pub fn declare(&self, identifier: &str, signature: Signature) -> Result<(), Error> {
         let mut guard = V8_RUNTIME.lock().unwrap();
         let runtime = match guard.get_mut() {
             Some(v) => v,
             None => return Err(Error::UnitializedRuntime),
         };
 
-        match &callable {
-            Callable::Godot(callable) => callable.callv(&godot::builtin::VariantArray::from(
-                godot::builtin::Array::new(),
-            )),
-            Callable::Closure(_) => todo!(),
+        runtime.signatures.insert(identifier.to_string(), signature);
+        return Ok(());
+    }
+
+    /// Parses JSON with V8's own `JSON.parse`, the result stays in JS which
+    /// pays off for large payloads that are only handed to scripts.
+    pub fn parse_json(&self, json: &str) -> Result<v8::Local<'_, v8::Value>, Error> {
+        let scope = self.context_scope()?;
+        let scope = &mut v8::TryCatch::new(scope);
+
+        let json = json.as_local(scope)?;
+        return match v8::json::parse(scope, json) {
+            Some(v) => Ok(v),
+            None => Err(caught_exception(scope)),
         };
+    }
 
+    /// `JSON.stringify` on a JS value, exceptions from `toJSON` or cyclic
+    /// objects are returned as errors.
+    pub fn stringify_json(&self, value: v8::Local<v8::Value>) -> Result<String, Error> {
         let scope = self.context_scope()?;
-        let registry = runtime.get_registry(self.id);
-        registry.insert(identifier.to_string(), callable);
+        let scope = &mut v8::TryCatch::new(scope);
 
-        let identifier = identifier.as_local(scope)?;
-        let id = v8::Number::new(scope, f64::from_be_bytes(self.id.to_be_bytes()));
-        let callback_data = v8::Object::new(scope);
+        return match v8::json::stringify(scope, value) {
+            Some(v) => Ok(v.to_rust_string_lossy(scope)),
+            None => Err(caught_exception(scope)),
+        };
+    }
 
-        let context_id_key = v8::String::new(scope, "contextId").unwrap();
-        let identifier_key = v8::String::new(scope, "identifier").unwrap();
+    /// Converts a Godot variant, e.g. a Dictionary from a save file or a
+    /// network message, straight into a JS value.
+    pub fn variant_to_js(&self, variant: &Variant) -> Result<v8::Local<'_, v8::Value>, Error> {
+        let scope = self.context_scope()?;
+        variant::to_local(scope, variant, 0)
+    }
 
-        callback_data.set(scope, context_id_key.into(), id.into());
-        callback_data.set(scope, identifier_key.into(), identifier.into());
+    /// Converts a JS value straight into a Godot variant, objects become
+    /// Dictionaries and arrays Arrays.
+    pub fn js_to_variant(&self, value: v8::Local<v8::Value>) -> Result<Variant, Error> {
+        let scope = self.context_scope()?;
+        variant::from_local(scope, value, 0)
+    }
 
-        let function = v8::FunctionBuilder::<v8::FunctionTemplate>::new(function)
-            .data(callback_data.into())
-            .build(scope)
-            .get_function(scope)
-            .unwrap();
+    /// A JS object reading and writing `dictionary` directly, so edits from
+    /// scripts reach Godot and the other way around. Nested Dictionaries and
+    /// Arrays are shared the same way.
+    pub fn dictionary_proxy(&self, dictionary: Dictionary) -> Result<ObjectHandle, Error> {
+        self.with_scope(|scope| {
+            let proxy = proxy::dictionary_proxy(scope, dictionary)?;
+            ObjectHandle::from_local(scope, self.id, proxy)
+        })
+    }
 
-        scope
-            .get_current_context()
-            .global(scope)
-            .set(scope, identifier.into(), function.into());
+    /// Like `dictionary_proxy`, for an Array.
+    pub fn array_proxy(&self, array: VariantArray) -> Result<ObjectHandle, Error> {
+        self.with_scope(|scope| {
+            let proxy = proxy::array_proxy(scope, array)?;
+            ObjectHandle::from_local(scope, self.id, proxy)
+        })
+    }
 
-        return Ok(());
+    /// Assigns a variant at a dotted path, Dictionaries and Arrays as live
+    /// proxies and other variants as copies.
+    pub fn share_global(&self, path: &str, variant: &Variant) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let value = proxy::live(scope, variant)?;
+            set_path(scope, path, value)
+        })
     }
 
     pub fn to_rust_string_lossy(&self, value: v8::Local<rusty_v8::Value>) -> Result<String, Error> {
         let context_scope = self.context_scope()?;
         Ok(value.to_rust_string_lossy(context_scope))
     }
+
+    /// Calls the function at a dotted path like `game.spawn`, with the
+    /// object holding it as `this`.
+    pub fn call(&self, path: &str, args: &[Value]) -> Result<Value, Error> {
+        self.with_scope(|scope| {
+            let args: Vec<v8::Local<v8::Value>> = args.iter().map(|v| v.as_local(scope)).collect();
+            let result = call_path(scope, path, &args)?;
+            Ok(result.as_value(scope))
+        })
+    }
+
+    /// Reads the value at a dotted path like `game.config.speed`, `undefined`
+    /// if an object on the way is missing.
+    pub fn get_global(&self, path: &str) -> Result<Value, Error> {
+        self.with_scope(|scope| {
+            let value = get_path(scope, path)?;
+            Ok(value.as_value(scope))
+        })
+    }
+
+    /// Assigns the value at a dotted path, creating missing objects on the
+    /// way like `game.config` in `game.config.speed`.
+    pub fn set_global(&self, path: &str, value: Value) -> Result<(), Error> {
+        self.with_scope(|scope| {
+            let value = value.as_local(scope);
+            set_path(scope, path, value)
+        })
+    }
+
+    /// `delete` on a dotted path. Like in JS this is `true` unless the
+    /// property can't be deleted, missing properties count as deleted.
+    pub fn delete_global(&self, path: &str) -> Result<bool, Error> {
+        self.with_scope(|scope| delete_path(scope, path))
+    }
+
+    /// Runs `f` in a handle scope of its own, so the handles it creates are
+    /// freed once it returns, and catches what it throws. Inside a native
+    /// callback the scope nests in the callback's, see `with_scope_for`.
+    fn with_scope<T>(
+        &self,
+        f: impl FnOnce(&mut v8::TryCatch<v8::HandleScope>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        with_scope_for(self.id, |scope| {
+            let scope = &mut v8::TryCatch::new(scope);
+            f(scope)
+        })?
+    }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
+        owned::release(self.id);
+
+        if let Some(scopes) = CONTEXT_SCOPES.lock().unwrap().as_mut() {
+            scopes.remove(&self.id);
+        }
+
         unsafe {
             drop(Box::from_raw(self.context_scope_ptr));
             drop(Box::from_raw(self.context_ptr));
@@ -215,3 +506,721 @@ impl Drop for Context {
         }
     }
 }
+
+/// Runs `f` in a new scope of a live context, `InvalidContext` once it was
+/// dropped. During a native callback the scope is opened on top of the
+/// callback's own scope.
+pub(crate) fn with_scope_for<T>(
+    id: u64,
+    f: impl FnOnce(&mut v8::HandleScope<'_>) -> T,
+) -> Result<T, Error> {
+    let (root, context) = {
+        let guard = CONTEXT_SCOPES.lock().unwrap();
+        match guard.as_ref().and_then(|scopes| scopes.get(&id)) {
+            Some(v) => (v.scope, v.context),
+            None => return Err(Error::InvalidContext),
+        }
+    };
+
+    let top = match CALLBACK_SCOPES.with(|scopes| scopes.borrow().last().copied()) {
+        Some(v) => unsafe { &mut *v },
+        None => unsafe { &mut **root },
+    };
+
+    let scope = &mut v8::HandleScope::new(top);
+    let context = v8::Local::new(scope, unsafe { *context });
+    let scope = &mut v8::ContextScope::new(scope, context);
+    return Ok(f(scope));
+}
+
+/// Marks a native callback as running until it is dropped, see
+/// `with_scope_for`.
+pub(crate) struct CallbackScope(());
+
+impl CallbackScope {
+    pub(crate) fn enter(scope: &mut v8::HandleScope<'_>) -> Self {
+        let scope = scope as *mut v8::HandleScope<'_> as *mut v8::HandleScope<'static>;
+        CALLBACK_SCOPES.with(|scopes| scopes.borrow_mut().push(scope));
+        Self(())
+    }
+}
+
+impl Drop for CallbackScope {
+    fn drop(&mut self) {
+        CALLBACK_SCOPES.with(|scopes| scopes.borrow_mut().pop());
+    }
+}
+
+fn context_id_key<'s>(scope: &mut v8::HandleScope<'s>) -> v8::Local<'s, v8::Private> {
+    let name = v8::String::new(scope, CONTEXT_ID_KEY).unwrap();
+    v8::Private::for_api(scope, Some(name))
+}
+
+/// Tags the context's global object with its id, hidden from scripts.
+fn set_context_id(scope: &mut v8::HandleScope<'_>, id: u64) {
+    let key = context_id_key(scope);
+    let id = v8::Number::new(scope, f64::from_be_bytes(id.to_be_bytes()));
+    let global = scope.get_current_context().global(scope);
+    global.set_private(scope, key, id.into());
+}
+
+/// Id of the context `scope` is in, `None` for contexts not created through
+/// `Context::new`.
+pub(crate) fn current_context_id(scope: &mut v8::HandleScope<'_>) -> Option<u64> {
+    let key = context_id_key(scope);
+    let global = scope.get_current_context().global(scope);
+    let id = global.get_private(scope, key)?;
+    match id.is_number() {
+        true => Some(u64::from_be_bytes(id.number_value(scope)?.to_be_bytes())),
+        false => None,
+    }
+}
+
+/// Splits a dotted path, `game.spawn` gives `["game", "spawn"]`.
+fn path_segments(path: &str) -> Result<Vec<&str>, Error> {
+    let segments: Vec<&str> = path.split('.').collect();
+    return match segments.iter().any(|segment| segment.is_empty()) {
+        true => Err(Error::TypeError(format!("invalid path `{path}`"))),
+        false => Ok(segments),
+    };
+}
+
+/// The object holding the last segment of `path` and that segment as a key.
+/// Missing objects on the way are created if `create` is set, otherwise the
+/// lookup gives `None`.
+fn resolve_path<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    path: &str,
+    create: bool,
+) -> Result<Option<(v8::Local<'s, v8::Object>, v8::Local<'s, v8::Value>)>, Error> {
+    let segments = path_segments(path)?;
+    let (last, parents) = segments.split_last().unwrap();
+
+    let mut object = scope.get_current_context().global(scope);
+    for (i, segment) in parents.iter().enumerate() {
+        let key = v8::String::new(scope, segment).unwrap().into();
+        let value = match object.get(scope, key) {
+            Some(v) => v,
+            None => return Err(caught_exception(scope)),
+        };
+
+        object = match v8::Local::<v8::Object>::try_from(value) {
+            Ok(v) => v,
+            Err(_) if value.is_null_or_undefined() && create => {
+                let child = v8::Object::new(scope);
+                if object.set(scope, key, child.into()).is_none() {
+                    return Err(caught_exception(scope));
+                }
+                child
+            }
+            Err(_) if value.is_null_or_undefined() => return Ok(None),
+            Err(_) => {
+                return Err(Error::TypeError(format!(
+                    "{} is not an object",
+                    segments[..=i].join(".")
+                )))
+            }
+        };
+    }
+
+    let key = v8::String::new(scope, last).unwrap().into();
+    return Ok(Some((object, key)));
+}
+
+/// `base.name`, or just `name` at the top level.
+fn join_path(base: &str, name: &str) -> String {
+    match base.is_empty() {
+        true => name.to_string(),
+        false => format!("{base}.{name}"),
+    }
+}
+
+/// The object at `object[key]`, created if missing. `path` names it in
+/// errors.
+fn child_object<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    object: v8::Local<'s, v8::Object>,
+    key: &str,
+    path: &str,
+) -> Result<v8::Local<'s, v8::Object>, Error> {
+    let key = v8::String::new(scope, key).unwrap().into();
+    let value = match object.get(scope, key) {
+        Some(v) => v,
+        None => return Err(caught_exception(scope)),
+    };
+
+    return match v8::Local::<v8::Object>::try_from(value) {
+        Ok(v) => Ok(v),
+        Err(_) if value.is_null_or_undefined() => {
+            let child = v8::Object::new(scope);
+            define(scope, object, key, child.into(), path)?;
+            Ok(child)
+        }
+        Err(_) => Err(Error::TypeError(format!("{path} is not an object"))),
+    };
+}
+
+/// Defines a data property, failing on frozen objects where an assignment
+/// would be silently ignored.
+fn define<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    object: v8::Local<'s, v8::Object>,
+    key: v8::Local<'s, v8::Value>,
+    value: v8::Local<'s, v8::Value>,
+    path: &str,
+) -> Result<(), Error> {
+    define_with(scope, object, key, value, v8::PropertyAttribute::NONE, path)
+}
+
+fn define_with<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    object: v8::Local<'s, v8::Object>,
+    key: v8::Local<'s, v8::Value>,
+    value: v8::Local<'s, v8::Value>,
+    attributes: v8::PropertyAttribute,
+    path: &str,
+) -> Result<(), Error> {
+    let key = match v8::Local::<v8::Name>::try_from(key) {
+        Ok(v) => v,
+        Err(_) => return Err(Error::TypeError(format!("invalid key for {path}"))),
+    };
+
+    return match object.define_own_property(scope, key, value, attributes) {
+        Some(true) => Ok(()),
+        Some(false) => Err(Error::TypeError(format!("cannot define {path}"))),
+        None => Err(caught_exception(scope)),
+    };
+}
+
+fn get_path<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    path: &str,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    let (object, key) = match resolve_path(scope, path, false)? {
+        Some(v) => v,
+        None => return Ok(v8::undefined(scope).into()),
+    };
+
+    return match object.get(scope, key) {
+        Some(v) => Ok(v),
+        None => Err(caught_exception(scope)),
+    };
+}
+
+fn set_path<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    path: &str,
+    value: v8::Local<'s, v8::Value>,
+) -> Result<(), Error> {
+    let (object, key) = resolve_path(scope, path, true)?.unwrap();
+
+    return match object.set(scope, key, value) {
+        Some(_) => Ok(()),
+        None => Err(caught_exception(scope)),
+    };
+}
+
+fn delete_path(scope: &mut v8::TryCatch<v8::HandleScope>, path: &str) -> Result<bool, Error> {
+    let (object, key) = match resolve_path(scope, path, false)? {
+        Some(v) => v,
+        None => return Ok(true),
+    };
+
+    return match object.delete(scope, key) {
+        Some(v) => Ok(v),
+        None => Err(caught_exception(scope)),
+    };
+}
+
+fn call_path<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    path: &str,
+    args: &[v8::Local<'s, v8::Value>],
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    let not_a_function = || Error::TypeError(format!("{path} is not a function"));
+
+    let (object, key) = match resolve_path(scope, path, false)? {
+        Some(v) => v,
+        None => return Err(not_a_function()),
+    };
+    let function = match object.get(scope, key) {
+        Some(v) => v8::Local::<v8::Function>::try_from(v).map_err(|_| not_a_function())?,
+        None => return Err(caught_exception(scope)),
+    };
+
+    return match function.call(scope, object.into(), args) {
+        Some(v) => Ok(v),
+        None => Err(caught_exception(scope)),
+    };
+}
+
+/// The JS function calling the callable registered under `identifier`.
+/// Without a context id it calls the one of the context it runs in.
+fn callable_function<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    context_id: Option<u64>,
+    identifier: &str,
+) -> v8::Local<'s, v8::Function> {
+    let identifier = v8::String::new(scope, identifier).unwrap();
+    let callback_data = v8::Object::new(scope);
+
+    let identifier_key = v8::String::new(scope, "identifier").unwrap();
+    callback_data.set(scope, identifier_key.into(), identifier.into());
+
+    if let Some(context_id) = context_id {
+        let id = v8::Number::new(scope, f64::from_be_bytes(context_id.to_be_bytes()));
+        let context_id_key = v8::String::new(scope, "contextId").unwrap();
+        callback_data.set(scope, context_id_key.into(), id.into());
+    }
+
+    v8::FunctionBuilder::<v8::FunctionTemplate>::new(callable_trampoline)
+        .data(callback_data.into())
+        .build(scope)
+        .get_function(scope)
+        .unwrap()
+}
+
+/// The JS function calling `callable` instead of one from the registry.
+/// `callable` is dropped once the function is collected or its context is,
+/// for functions no path leads to, like methods on objects.
+fn owned_callable_function<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    callable: Callable,
+) -> v8::Local<'s, v8::Function> {
+    let callable = Box::new(Rc::new(callable));
+    let data = v8::External::new(scope, &*callable as *const Rc<Callable> as *mut c_void);
+
+    let function = v8::FunctionBuilder::<v8::Function>::new(owned_callable_trampoline)
+        .data(data.into())
+        .build(scope)
+        .unwrap();
+
+    owned::attach(scope, function.into(), callable);
+    function
+}
+
+pub(crate) fn owned_callable_trampoline<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    args: v8::FunctionCallbackArguments<'s>,
+    retval: v8::ReturnValue,
+) {
+    let data = v8::Local::<v8::External>::try_from(args.data().unwrap()).unwrap();
+    let callable = unsafe { (*(data.value() as *const Rc<Callable>)).clone() };
+    run_callable(scope, &args, retval, &callable);
+}
+
+/// Entry point for every registered callable, the callable itself is looked
+/// up in the runtime registry through the function's data object.
+pub(crate) fn callable_trampoline<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    args: v8::FunctionCallbackArguments<'s>,
+    retval: v8::ReturnValue,
+) {
+    let data = args.data().unwrap().to_object(scope).unwrap();
+    let context_id_key = v8::String::new(scope, "contextId").unwrap();
+    let identifier_key = v8::String::new(scope, "identifier").unwrap();
+
+    // functions declared in a snapshot have no id and belong to whichever
+    // context loaded it
+    let context_id = match data.get(scope, context_id_key.into()) {
+        Some(id) if id.is_number() => Some(u64::from_be_bytes(
+            id.number_value(scope).unwrap().to_be_bytes(),
+        )),
+        _ => current_context_id(scope),
+    };
+
+    let callback_identifier = data
+        .get(scope, identifier_key.into())
+        .unwrap()
+        .to_string(scope)
+        .unwrap()
+        .to_rust_string_lossy(scope);
+
+    // cloned out so the runtime isn't locked while the callable runs, it
+    // may call back into JS and from there into other callables
+    let callback = {
+        let mut guard = V8_RUNTIME.lock().unwrap();
+        let runtime = guard
+            .get_mut()
+            .expect("exposed callable run without runtime");
+        context_id.and_then(|id| runtime.get_registry(id).get(&callback_identifier).cloned())
+    };
+
+    match callback {
+        Some(callback) => run_callable(scope, &args, retval, &callback),
+        None => throw_error(
+            scope,
+            Error::TypeError(format!("{callback_identifier} is not implemented")),
+        ),
+    };
+}
+
+/// Defines a function at `path` calling the callable each context supplies
+/// with `Context::implement`, for functions declared in a snapshot.
+pub(crate) fn define_callable_stub(
+    scope: &mut v8::HandleScope<'_>,
+    path: &str,
+) -> Result<(), Error> {
+    let scope = &mut v8::TryCatch::new(scope);
+    let function = callable_function(scope, None, path);
+    let (object, key) = resolve_path(scope, path, true)?.unwrap();
+    define(scope, object, key, function.into(), path)
+}
+
+fn run_callable<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    args: &v8::FunctionCallbackArguments<'s>,
+    mut retval: v8::ReturnValue,
+    callable: &Callable,
+) {
+    let entered = CallbackScope::enter(scope);
+    let result = invoke(callable, &mut CallbackArgs::new(scope, args));
+    drop(entered);
+
+    match result {
+        Ok(v) => retval.set(v.as_local(scope)),
+        Err(e) => throw_error(scope, e),
+    };
+}
+
+/// Calls `callable`, `Closure`s and Godot callables with every argument
+/// converted.
+fn invoke(callable: &Callable, args: &mut CallbackArgs<'_, '_>) -> Result<Value, Error> {
+    match callable {
+        Callable::Godot(callable) => {
+            let values = args.values();
+            let godot_args = godot::builtin::Array::from_iter(values.iter().map(|v| v.to_godot()));
+            let result = callable.callv(&godot::builtin::VariantArray::from(godot_args));
+            Value::try_from_godot(result).map_err(|e| Error::TypeError(e.to_string()))
+        }
+        Callable::Closure(v) => v(args.values()),
+        Callable::Native(v) => v(args),
+    }
+}
+
+/// The callables behind a property made by `Context::define_property`,
+/// owned by the object holding the property.
+struct Accessor {
+    getter: Rc<Callable>,
+    setter: Option<Rc<Callable>>,
+}
+
+/// rusty_v8 can't hand data to an accessor, so each one finds its callables
+/// through a private property named after it on the holder.
+fn accessor_key<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    key: v8::Local<v8::Name>,
+) -> v8::Local<'s, v8::Private> {
+    let name = format!("gdv8::accessor::{}", key.to_rust_string_lossy(scope));
+    let name = v8::String::new(scope, &name).unwrap();
+    v8::Private::for_api(scope, Some(name))
+}
+
+/// Defines `key` on `object` as a native accessor, enumerable like a plain
+/// property. The callables live as long as `object`.
+fn define_accessor_property<'s>(
+    scope: &mut v8::TryCatch<'_, v8::HandleScope<'s>>,
+    object: v8::Local<'s, v8::Object>,
+    key: v8::Local<'s, v8::Value>,
+    getter: Callable,
+    setter: Option<Callable>,
+    path: &str,
+) -> Result<(), Error> {
+    let key = match v8::Local::<v8::Name>::try_from(key) {
+        Ok(v) => v,
+        Err(_) => return Err(Error::TypeError(format!("invalid key for {path}"))),
+    };
+
+    match object.set_accessor_with_setter(scope, key, accessor_getter, accessor_setter) {
+        Some(true) => {}
+        Some(false) => return Err(Error::TypeError(format!("cannot define {path}"))),
+        None => return Err(caught_exception(scope)),
+    };
+
+    // a redefined accessor leaves the old callables to the object
+    let accessor = Box::new(Accessor {
+        getter: Rc::new(getter),
+        setter: setter.map(Rc::new),
+    });
+    let pointer = owned::attach(scope, object, accessor);
+    let data = v8::External::new(scope, pointer as *mut c_void);
+    let private = accessor_key(scope, key);
+    object.set_private(scope, private, data.into());
+
+    return Ok(());
+}
+
+/// The getter and setter of the accessor `key` on `holder`.
+fn accessor(
+    scope: &mut v8::HandleScope<'_>,
+    key: v8::Local<v8::Name>,
+    holder: v8::Local<v8::Object>,
+) -> Option<(Rc<Callable>, Option<Rc<Callable>>)> {
+    let private = accessor_key(scope, key);
+    let data = holder.get_private(scope, private)?;
+    let data = v8::Local::<v8::External>::try_from(data).ok()?;
+
+    let accessor = unsafe { &*(data.value() as *const Accessor) };
+    Some((accessor.getter.clone(), accessor.setter.clone()))
+}
+
+pub(crate) fn accessor_getter<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    key: v8::Local<'s, v8::Name>,
+    args: v8::PropertyCallbackArguments<'s>,
+    mut retval: v8::ReturnValue,
+) {
+    let (getter, _) = match accessor(scope, key, args.holder()) {
+        Some(v) => v,
+        None => return,
+    };
+
+    let entered = CallbackScope::enter(scope);
+    let result = invoke(
+        &getter,
+        &mut CallbackArgs::property(scope, args.this(), None),
+    );
+    drop(entered);
+
+    match result {
+        Ok(v) => retval.set(v.as_local(scope)),
+        Err(e) => throw_error(scope, e),
+    };
+}
+
+/// Without a setter the assignment is ignored.
+pub(crate) fn accessor_setter<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    key: v8::Local<'s, v8::Name>,
+    value: v8::Local<'s, v8::Value>,
+    args: v8::PropertyCallbackArguments<'s>,
+) {
+    let setter = match accessor(scope, key, args.holder()) {
+        Some((_, Some(setter))) => setter,
+        _ => return,
+    };
+
+    let entered = CallbackScope::enter(scope);
+    let result = invoke(
+        &setter,
+        &mut CallbackArgs::property(scope, args.this(), Some(value)),
+    );
+    drop(entered);
+
+    if let Err(e) = result {
+        throw_error(scope, e);
+    }
+}
+
+/// Throws `error` into JS, `TypeError`s and `RangeError`s keep their type.
+pub(crate) fn throw_error(scope: &mut v8::HandleScope<'_>, error: Error) {
+    let message = v8::String::new(scope, &error.to_string()).unwrap();
+    let exception = match error {
+        Error::TypeError(_) | Error::InvalidValue(_) => v8::Exception::type_error(scope, message),
+        Error::RangeError(_) => v8::Exception::range_error(scope, message),
+        _ => v8::Exception::error(scope, message),
+    };
+    scope.throw_exception(exception);
+}
+
+/// Builds the origin for a script named `name`, picking up a
+/// `//# sourceMappingURL=` comment so V8 knows about the source map.
+pub(crate) fn script_origin<'s>(
+    scope: &mut v8::HandleScope<'s, ()>,
+    source: &str,
+    name: &str,
+    line_offset: i32,
+    column_offset: i32,
+) -> v8::ScriptOrigin<'s> {
+    let resource_name = v8::String::new(scope, name).unwrap().into();
+    let source_map_url = match crate::helper::source_mapping_url(source) {
+        Some(url) => v8::String::new(scope, url).unwrap().into(),
+        None => v8::undefined(scope).into(),
+    };
+
+    v8::ScriptOrigin::new(
+        scope,
+        resource_name,
+        line_offset,
+        column_offset,
+        false,
+        0,
+        source_map_url,
+        false,
+        false,
+        false,
+    )
+}
+
+/// Turns the exception caught by `scope` into an `Error`.
+pub(crate) fn caught_exception(scope: &mut v8::TryCatch<v8::HandleScope>) -> Error {
+    let exception = match scope.exception() {
+        Some(v) => v,
+        None => return Error::None,
+    };
+
+    let stack = scope
+        .stack_trace()
+        .map(|stack| stack.to_rust_string_lossy(scope));
+
+    let mut exception = match scope.message() {
+        Some(message) => Exception {
+            message: message.get(scope).to_rust_string_lossy(scope),
+            resource_name: message
+                .get_script_resource_name(scope)
+                .filter(|name| !name.is_undefined())
+                .map(|name| name.to_rust_string_lossy(scope)),
+            line: message.get_line_number(scope),
+            column: Some(message.get_start_column()),
+            stack,
+        },
+        None => Exception {
+            message: exception.to_rust_string_lossy(scope),
+            stack,
+            ..Default::default()
+        },
+    };
+
+    source_map::remap_exception(&mut exception);
+
+    return Error::Exception(exception);
+}
+
+/// A `Context` for GDScript, values cross over as Variants with objects as
+/// Dictionaries. Errors are printed to the error panel and give `null` or
+/// `false`.
+#[derive(GodotClass)]
+#[class(base=RefCounted)]
+pub struct JSContext {
+    base: Base<RefCounted>,
+    context: Context,
+}
+
+#[godot_api]
+impl IRefCounted for JSContext {
+    fn init(base: Base<RefCounted>) -> Self {
+        Self {
+            base,
+            context: Context::new(),
+        }
+    }
+}
+
+#[godot_api]
+impl JSContext {
+    /// Runs a script under the resource name `name`, see
+    /// `Context::run_script_named`.
+    #[func]
+    fn run_script(&self, source: GString, name: GString) -> Variant {
+        let result = self
+            .context
+            .run_script_named(&source.to_string(), &name.to_string(), 0, 0)
+            .and_then(|value| {
+                self.context
+                    .with_scope(|scope| variant::from_local(scope, value, 0))
+            });
+        report_or_nil(result)
+    }
+
+    /// Named so it doesn't shadow `Object.call`.
+    #[func]
+    fn call_function(&self, path: GString, args: VariantArray) -> Variant {
+        let result = self.context.with_scope(|scope| {
+            let args = args
+                .iter_shared()
+                .map(|v| variant::to_local(scope, &v, 0))
+                .collect::<Result<Vec<_>, Error>>()?;
+            let value = call_path(scope, &path.to_string(), &args)?;
+            variant::from_local(scope, value, 0)
+        });
+        report_or_nil(result)
+    }
+
+    #[func]
+    fn get_global(&self, path: GString) -> Variant {
+        let result = self.context.with_scope(|scope| {
+            let value = get_path(scope, &path.to_string())?;
+            variant::from_local(scope, value, 0)
+        });
+        report_or_nil(result)
+    }
+
+    #[func]
+    fn set_global(&self, path: GString, value: Variant) -> bool {
+        let result = self.context.with_scope(|scope| {
+            let value = variant::to_local(scope, &value, 0)?;
+            set_path(scope, &path.to_string(), value)
+        });
+        report(result).is_some()
+    }
+
+    /// Exposes `callable` at a dotted path like `game.spawn`.
+    #[func]
+    fn register_callable(&self, path: GString, callable: godot::builtin::Callable) -> bool {
+        let result = self
+            .context
+            .register_callable(&path.to_string(), Callable::Godot(callable));
+        report(result).is_some()
+    }
+
+    /// `setter` may be a null `Callable` for a read only property.
+    #[func]
+    fn define_property(
+        &self,
+        path: GString,
+        getter: godot::builtin::Callable,
+        setter: godot::builtin::Callable,
+    ) -> bool {
+        let setter = match setter.is_valid() {
+            true => Some(Callable::Godot(setter)),
+            false => None,
+        };
+        let result =
+            self.context
+                .define_property(&path.to_string(), Callable::Godot(getter), setter);
+        report(result).is_some()
+    }
+
+    #[func]
+    fn define_constant(&self, path: GString, value: Variant) -> bool {
+        let result = Value::try_from_godot(value)
+            .map_err(|e| Error::TypeError(e.to_string()))
+            .and_then(|value| self.context.define_constant(&path.to_string(), value));
+        report(result).is_some()
+    }
+
+    /// Like `set_global`, but Dictionaries and Arrays stay shared with the
+    /// script instead of being copied.
+    #[func]
+    fn share_global(&self, path: GString, value: Variant) -> bool {
+        let result = self.context.share_global(&path.to_string(), &value);
+        report(result).is_some()
+    }
+
+    #[func]
+    fn delete_global(&self, path: GString) -> bool {
+        let result = self
+            .context
+            .with_scope(|scope| delete_path(scope, &path.to_string()));
+        report(result).unwrap_or(false)
+    }
+}
+
+/// Prints an error to Godot's error panel.
+fn report<T>(result: Result<T, Error>) -> Option<T> {
+    return match result {
+        Ok(v) => Some(v),
+        Err(Error::Exception(e)) => {
+            e.report();
+            None
+        }
+        Err(e) => {
+            godot::prelude::godot_error!("{e}");
+            None
+        }
+    };
+}
+
+fn report_or_nil(result: Result<Variant, Error>) -> Variant {
+    report(result).unwrap_or_else(Variant::nil)
+}
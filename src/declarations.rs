@@ -0,0 +1,419 @@
+use std::collections::BTreeMap;
+
+use crate::{error::Error, helper, Callable, V8_RUNTIME};
+use godot::{
+    builtin::{Dictionary, GString, StringName, VariantType},
+    classes::{ClassDb, RefCounted},
+    prelude::*,
+};
+
+/// TypeScript type of a value crossing the JS/Rust boundary.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TsType {
+    Any,
+    Void,
+    Undefined,
+    Boolean,
+    Number,
+    String,
+    /// Any `gdv8::Value`, declared once as the `Value` type alias.
+    Value,
+    Object,
+    Array(Box<TsType>),
+    Named(String),
+}
+
+impl TsType {
+    /// Maps a Godot property or argument type to its JS counterpart.
+    pub fn from_godot(variant_type: VariantType, class_name: &str) -> Self {
+        match variant_type {
+            VariantType::NIL => TsType::Any,
+            VariantType::BOOL => TsType::Boolean,
+            VariantType::INT | VariantType::FLOAT => TsType::Number,
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => {
+                TsType::String
+            }
+            VariantType::DICTIONARY => TsType::Object,
+            VariantType::ARRAY => TsType::Array(Box::new(TsType::Any)),
+            VariantType::PACKED_STRING_ARRAY => TsType::Array(Box::new(TsType::String)),
+            VariantType::PACKED_BYTE_ARRAY
+            | VariantType::PACKED_INT32_ARRAY
+            | VariantType::PACKED_INT64_ARRAY
+            | VariantType::PACKED_FLOAT32_ARRAY
+            | VariantType::PACKED_FLOAT64_ARRAY => TsType::Array(Box::new(TsType::Number)),
+            VariantType::OBJECT => match class_name.is_empty() {
+                true => TsType::Named("Godot.Object".to_string()),
+                false => TsType::Named(format!("Godot.{class_name}")),
+            },
+            _ => TsType::Any,
+        }
+    }
+
+    fn from_property_info(info: &Dictionary) -> Self {
+        let variant_type = info
+            .get("type")
+            .and_then(|v| v.try_to::<i64>().ok())
+            .map(|v| VariantType::from_ord(v as i32))
+            .unwrap_or(VariantType::NIL);
+        let class_name = info
+            .get("class_name")
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+
+        Self::from_godot(variant_type, &class_name)
+    }
+}
+
+impl std::fmt::Display for TsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TsType::Any => write!(f, "any"),
+            TsType::Void => write!(f, "void"),
+            TsType::Undefined => write!(f, "undefined"),
+            TsType::Boolean => write!(f, "boolean"),
+            TsType::Number => write!(f, "number"),
+            TsType::String => write!(f, "string"),
+            TsType::Value => write!(f, "Value"),
+            TsType::Object => write!(f, "{{ [key: string]: any }}"),
+            TsType::Array(inner) => write!(f, "{inner}[]"),
+            TsType::Named(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Parameter and return types of a registered callable.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Signature {
+    params: Vec<(String, TsType)>,
+    rest: Option<(String, TsType)>,
+    returns: TsType,
+}
+
+impl Default for Signature {
+    /// Closures receive every argument as a `Value` and return one.
+    fn default() -> Self {
+        Self {
+            params: vec![],
+            rest: Some(("args".to_string(), TsType::Value)),
+            returns: TsType::Value,
+        }
+    }
+}
+
+impl Signature {
+    pub fn new() -> Self {
+        Self {
+            params: vec![],
+            rest: None,
+            returns: TsType::Void,
+        }
+    }
+
+    pub fn param(mut self, name: &str, ty: TsType) -> Self {
+        self.params.push((name.to_string(), ty));
+        self
+    }
+
+    pub fn rest(mut self, name: &str, ty: TsType) -> Self {
+        self.rest = Some((name.to_string(), ty));
+        self
+    }
+
+    pub fn returns(mut self, ty: TsType) -> Self {
+        self.returns = ty;
+        self
+    }
+
+    /// Reads the signature of a Godot method from its `get_method_list` entry.
+    fn from_method_info(info: &Dictionary) -> Self {
+        let mut signature = Self::new();
+
+        let args = info
+            .get("args")
+            .and_then(|v| v.try_to::<VariantArray>().ok())
+            .unwrap_or_default();
+        let args = args
+            .iter_shared()
+            .filter_map(|v| v.try_to::<Dictionary>().ok());
+        for (i, arg) in args.enumerate() {
+            let name = arg
+                .get("name")
+                .map(|v| v.to_string())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| format!("arg{i}"));
+            signature = signature.param(&name, TsType::from_property_info(&arg));
+        }
+
+        let returns = info
+            .get("return")
+            .and_then(|v| v.try_to::<Dictionary>().ok())
+            .map(|v| TsType::from_property_info(&v))
+            .unwrap_or(TsType::Any);
+
+        signature.returns(match returns {
+            TsType::Any => TsType::Void,
+            v => v,
+        })
+    }
+
+    /// Looks the method behind a Godot callable up on its object.
+    fn from_callable(callable: &godot::builtin::Callable) -> Option<Self> {
+        let object = callable.object()?;
+        let method = callable.method_name()?.to_string();
+
+        object
+            .get_method_list()
+            .iter_shared()
+            .find(|info| info.get("name").map(|v| v.to_string()) == Some(method.clone()))
+            .map(|info| Self::from_method_info(&info))
+    }
+
+    fn render(&self, name: &str) -> String {
+        let mut params: Vec<String> = self
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{}: {ty}", escape_ident(name)))
+            .collect();
+
+        if let Some((name, ty)) = &self.rest {
+            params.push(format!("...{}: {ty}[]", escape_ident(name)));
+        }
+
+        format!("{name}({}): {}", params.join(", "), self.returns)
+    }
+}
+
+/// Collects declarations and renders them as a `.d.ts` file.
+#[derive(Clone, Debug, Default)]
+pub struct Declarations {
+    functions: BTreeMap<String, Signature>,
+    godot_classes: Vec<String>,
+}
+
+impl Declarations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declarations for every callable registered on any context so far.
+    pub fn from_runtime() -> Self {
+        let mut declarations = Self::new();
+
+        let guard = V8_RUNTIME.lock().unwrap();
+        let runtime = match guard.get() {
+            Some(v) => v,
+            None => return declarations,
+        };
+
+        for registry in runtime.registry.values() {
+            for (identifier, callable) in registry.iter() {
+                let signature = runtime
+                    .signatures
+                    .get(identifier)
+                    .cloned()
+                    .or_else(|| match callable.as_ref() {
+                        Callable::Godot(callable) => Signature::from_callable(callable),
+                        Callable::Closure(_) | Callable::Native(_) => None,
+                    })
+                    .unwrap_or_default();
+
+                declarations.functions.insert(identifier.clone(), signature);
+            }
+        }
+
+        declarations
+    }
+
+    pub fn function(mut self, identifier: &str, signature: Signature) -> Self {
+        self.functions.insert(identifier.to_string(), signature);
+        self
+    }
+
+    /// Declares a Godot class and, through `extends`, its ancestors.
+    pub fn godot_class(mut self, class_name: &str) -> Self {
+        let class_db = ClassDb::singleton();
+        let mut class_name = StringName::from(class_name);
+
+        while !class_name.is_empty() && class_db.class_exists(class_name.clone()) {
+            let name = class_name.to_string();
+            if self.godot_classes.contains(&name) {
+                break;
+            }
+            self.godot_classes.push(name);
+            class_name = class_db.get_parent_class(class_name);
+        }
+
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::from("// Generated by gdv8, do not edit.\n\n");
+
+        out += concat!(
+            "type Value = string | number | boolean | null | undefined | Function\n",
+            "    | ArrayBuffer | ArrayBufferView | Date | RegExp | Error\n",
+            "    | Map<Value, Value> | Set<Value> | Value[] | { [key: string]: Value };\n\n",
+        );
+
+        let mut namespaces: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (identifier, signature) in &self.functions {
+            // function declarations can't be quoted, a renamed one wouldn't
+            // exist at runtime
+            if identifier.split('.').any(is_reserved) {
+                godot::prelude::godot_warn!(
+                    "{identifier} is not declared, its name is a reserved word in TypeScript"
+                );
+                continue;
+            }
+
+            match identifier.rsplit_once('.') {
+                Some((namespace, name)) => namespaces
+                    .entry(namespace)
+                    .or_default()
+                    .push(signature.render(name)),
+                None => out += &format!("declare function {};\n", signature.render(identifier)),
+            }
+        }
+
+        for (namespace, functions) in namespaces {
+            out += &format!("\ndeclare namespace {namespace} {{\n");
+            for function in functions {
+                out += &format!("    function {function};\n");
+            }
+            out += "}\n";
+        }
+
+        if !self.godot_classes.is_empty() {
+            out += "\ndeclare namespace Godot {\n";
+            for class_name in &self.godot_classes {
+                out += &render_godot_class(class_name);
+            }
+            out += "}\n";
+        }
+
+        out
+    }
+
+    /// Writes the declarations to `path`, `res://` paths work.
+    pub fn write(&self, path: &str) -> Result<(), Error> {
+        helper::write_file(path, self.render().as_bytes())
+    }
+}
+
+fn render_godot_class(class_name: &str) -> String {
+    let class_db = ClassDb::singleton();
+    let name = StringName::from(class_name);
+
+    let parent = class_db.get_parent_class(name.clone());
+    let mut out = match parent.is_empty() {
+        true => format!("    class {class_name} {{\n"),
+        false => format!("    class {class_name} extends {parent} {{\n"),
+    };
+
+    let properties = class_db
+        .class_get_property_list_ex(name.clone())
+        .no_inheritance(true)
+        .done();
+    for property in properties.iter_shared() {
+        let property_name = property
+            .get("name")
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        // groups and categories show up as properties without a type
+        if property_name.is_empty() || property_name.contains('/') {
+            continue;
+        }
+        out += &format!(
+            "        {}: {};\n",
+            member_name(&property_name),
+            TsType::from_property_info(&property)
+        );
+    }
+
+    let methods = class_db
+        .class_get_method_list_ex(name)
+        .no_inheritance(true)
+        .done();
+    for method in methods.iter_shared() {
+        let method_name = method
+            .get("name")
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        if method_name.is_empty() || method_name.starts_with('_') {
+            continue;
+        }
+        out += &format!(
+            "        {};\n",
+            Signature::from_method_info(&method).render(&member_name(&method_name))
+        );
+    }
+
+    out += "    }\n";
+    out
+}
+
+fn is_reserved(name: &str) -> bool {
+    const RESERVED: &str = concat!(
+        "break case catch class const continue debugger default delete do else enum export ",
+        "extends false finally for function if import in instanceof new null return super ",
+        "switch this throw true try typeof var void while with implements interface let ",
+        "package private protected public static yield",
+    );
+
+    RESERVED.split(' ').any(|word| word == name)
+}
+
+/// Parameter names only matter by position, a reserved one gets renamed.
+fn escape_ident(name: &str) -> String {
+    match is_reserved(name) {
+        true => format!("{name}_"),
+        false => name.to_string(),
+    }
+}
+
+/// Class members keep their runtime name, reserved ones quoted.
+fn member_name(name: &str) -> String {
+    match is_reserved(name) {
+        true => format!("\"{name}\""),
+        false => name.to_string(),
+    }
+}
+
+/// Editor tool that writes the `.d.ts` for the current runtime, e.g. from an
+/// `EditorScript` after the game's callables have been registered.
+#[derive(GodotClass)]
+#[class(tool, init, base=RefCounted)]
+pub struct JSDeclarations {
+    base: Base<RefCounted>,
+    godot_classes: PackedStringArray,
+}
+
+#[godot_api]
+impl JSDeclarations {
+    /// Also declare `class_name` and its ancestors in the `Godot` namespace.
+    #[func]
+    fn add_godot_class(&mut self, class_name: GString) {
+        self.godot_classes.push(class_name);
+    }
+
+    #[func]
+    fn generate(&self) -> GString {
+        GString::from(self.declarations().render())
+    }
+
+    #[func]
+    fn write(&self, path: GString) -> bool {
+        self.declarations().write(&path.to_string()).is_ok()
+    }
+}
+
+impl JSDeclarations {
+    fn declarations(&self) -> Declarations {
+        self.godot_classes
+            .as_slice()
+            .iter()
+            .fold(Declarations::from_runtime(), |declarations, class_name| {
+                declarations.godot_class(&class_name.to_string())
+            })
+    }
+}
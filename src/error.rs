@@ -1,11 +1,22 @@
 #[derive(Clone, Debug)]
 pub enum Error {
-    Exception(String),
+    Exception(Exception),
     None,
     ScopePointerAllocationFailed,
     ContextAllocationFailed,
     UnitializedRuntime,
     InvalidContext,
+    RuntimeAlreadyInitialized,
+    /// V8 already runs with other flags or platform settings, e.g. the ones a
+    /// `SnapshotBuilder` initialized it with.
+    V8AlreadyInitialized,
+    SnapshotCreationFailed,
+    Io(String),
+    Transpile(String),
+    TypeError(String),
+    RangeError(String),
+    Json(String),
+    InvalidValue(ValueError),
 }
 
 impl std::fmt::Display for Error {
@@ -14,13 +25,136 @@ impl std::fmt::Display for Error {
             f,
             "{}",
             match self {
-                Error::Exception(v) => &v,
+                Error::Exception(v) => return write!(f, "{v}"),
                 Error::None => "None",
                 Error::ScopePointerAllocationFailed => "ScopePointerAllocationFailed",
                 Error::ContextAllocationFailed => "ContextAllocationFailed",
                 Error::UnitializedRuntime => "UnitializedRuntime",
                 Error::InvalidContext => "InvalidContext",
+                Error::RuntimeAlreadyInitialized => "RuntimeAlreadyInitialized",
+                Error::V8AlreadyInitialized => "V8AlreadyInitialized",
+                Error::SnapshotCreationFailed => "SnapshotCreationFailed",
+                Error::Io(v) => &v,
+                Error::Transpile(v) => &v,
+                Error::TypeError(v) => &v,
+                Error::RangeError(v) => &v,
+                Error::Json(v) => &v,
+                Error::InvalidValue(v) => return write!(f, "{v}"),
             }
         )
     }
 }
+
+impl std::error::Error for Error {}
+
+impl Error {
+    /// Conversion error for a value that doesn't have the `expected` type.
+    pub fn expected(expected: &str, found: &crate::Value) -> Self {
+        Error::InvalidValue(ValueError::new(format!(
+            "expected {expected}, got {}",
+            found.type_name()
+        )))
+    }
+
+    /// Prefixes the path of a conversion error with an object key.
+    pub fn at_key(self, key: &str) -> Self {
+        match self {
+            Error::InvalidValue(v) => Error::InvalidValue(v.at(PathSegment::Key(key.to_string()))),
+            v => v,
+        }
+    }
+
+    /// Prefixes the path of a conversion error with an array index.
+    pub fn at_index(self, index: usize) -> Self {
+        match self {
+            Error::InvalidValue(v) => Error::InvalidValue(v.at(PathSegment::Index(index))),
+            v => v,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A `Value` that couldn't be converted, with the path to the offending part.
+#[derive(Clone, Debug)]
+pub struct ValueError {
+    pub path: Vec<PathSegment>,
+    pub message: String,
+}
+
+impl ValueError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: vec![],
+            message: message.into(),
+        }
+    }
+
+    fn at(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+impl std::fmt::Display for ValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}", self.message);
+        }
+
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => write!(f, "{key}")?,
+                PathSegment::Key(key) => write!(f, ".{key}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// A JS exception together with the position it was thrown from.
+#[derive(Clone, Debug, Default)]
+pub struct Exception {
+    pub message: String,
+    pub resource_name: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub stack: Option<String>,
+}
+
+impl Exception {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Prints the exception to Godot's error panel.
+    pub fn report(&self) {
+        match &self.stack {
+            Some(stack) => godot::prelude::godot_error!("{self}\n{stack}"),
+            None => godot::prelude::godot_error!("{self}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Exception {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.resource_name, self.line) {
+            (Some(name), Some(line)) => write!(
+                f,
+                "{name}:{line}:{}: {}",
+                self.column.unwrap_or(0) + 1,
+                self.message
+            ),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::{cell::OnceCell, rc::Rc};
+
+use rusty_v8 as v8;
+
+use crate::{
+    context::{self, caught_exception},
+    error::Error,
+    helper::AsValue,
+    Value,
+};
+
+/// A JS function kept alive from Rust, e.g. an event handler a script passed
+/// to a host callback. Clones share the same function.
+///
+/// The handle belongs to the thread of its `Context`, so `Function`, and with
+/// it `Value`, is neither `Send` nor `Sync`.
+#[derive(Clone)]
+pub struct Function(Rc<Inner>);
+
+struct Inner {
+    context_id: u64,
+    function: v8::Global<v8::Function>,
+    /// Read on first use, most functions never need it.
+    source: OnceCell<String>,
+}
+
+impl Function {
+    pub(crate) fn new(
+        scope: &mut v8::HandleScope<'_>,
+        context_id: u64,
+        function: v8::Local<'_, v8::Function>,
+    ) -> Self {
+        Self(Rc::new(Inner {
+            context_id,
+            function: v8::Global::new(scope, function),
+            source: OnceCell::new(),
+        }))
+    }
+
+    pub(crate) fn as_local<'s>(
+        &self,
+        scope: &mut v8::HandleScope<'s>,
+    ) -> v8::Local<'s, v8::Function> {
+        v8::Local::new(scope, &self.0.function)
+    }
+
+    /// Calls the function in the context it was created in, like
+    /// `function.call(this, ...args)`. A thrown exception becomes an `Err`.
+    pub fn call(&self, this: &Value, args: &[Value]) -> Result<Value, Error> {
+        context::with_scope_for(self.0.context_id, |scope| {
+            let scope = &mut v8::TryCatch::new(scope);
+
+            let function = self.as_local(scope);
+            let this = this.as_local(scope);
+            let args: Vec<v8::Local<v8::Value>> =
+                args.iter().map(|v| v.as_local(scope)).collect();
+
+            return match function.call(scope, this, &args) {
+                Some(v) => Ok(v.as_value(scope)),
+                None => Err(caught_exception(scope)),
+            };
+        })?
+    }
+
+    /// The function's source text, what `String(fn)` gives in JS. Fails
+    /// with `InvalidContext` the first time if the context is gone.
+    pub fn source(&self) -> Result<&str, Error> {
+        if let Some(source) = self.0.source.get() {
+            return Ok(source);
+        }
+
+        let source = context::with_scope_for(self.0.context_id, |scope| {
+            self.as_local(scope).to_rust_string_lossy(scope)
+        })?;
+        return Ok(self.0.source.get_or_init(|| source));
+    }
+
+    /// Whether both refer to the same JS function, `===` in JS.
+    pub fn same(&self, other: &Function) -> bool {
+        Rc::ptr_eq(&self.0, &other.0) || self.0.function == other.0.function
+    }
+}
+
+impl std::fmt::Debug for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Function")
+            .field("context_id", &self.0.context_id)
+            .field("source", &self.0.source.get())
+            .finish()
+    }
+}
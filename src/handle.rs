@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use rusty_v8 as v8;
+
+use crate::{context, error::Error, helper::AsValue, Context, Value};
+
+/// A JS value that outlives the call it came from, e.g. an object kept in a
+/// Rust struct or a Godot node between frames. Dropping it releases the
+/// value, also when its context is already gone.
+pub struct JsHandle<T: HandleType> {
+    context_id: u64,
+    global: v8::Global<v8::Value>,
+    _type: PhantomData<T>,
+}
+
+pub type ObjectHandle = JsHandle<v8::Object>;
+pub type FunctionHandle = JsHandle<v8::Function>;
+pub type PromiseHandle = JsHandle<v8::Promise>;
+pub type ArrayBufferHandle = JsHandle<v8::ArrayBuffer>;
+
+/// The V8 types a `JsHandle` can hold.
+pub trait HandleType: Sized {
+    /// Name used in type errors.
+    const NAME: &'static str;
+
+    fn is(value: v8::Local<v8::Value>) -> bool;
+}
+
+macro_rules! handle_type {
+    ($($type:ident $name:literal $is:ident),*) => {
+        $(impl HandleType for v8::$type {
+            const NAME: &'static str = $name;
+
+            fn is(value: v8::Local<v8::Value>) -> bool {
+                value.$is()
+            }
+        })*
+    };
+}
+
+handle_type!(
+    Object "object" is_object,
+    Function "function" is_function,
+    Promise "promise" is_promise,
+    ArrayBuffer "ArrayBuffer" is_array_buffer
+);
+
+impl HandleType for v8::Value {
+    const NAME: &'static str = "value";
+
+    fn is(_: v8::Local<v8::Value>) -> bool {
+        true
+    }
+}
+
+impl<T: HandleType> JsHandle<T> {
+    /// Takes hold of `value`, which has to come from `context`. Fails with a
+    /// `TypeError` if it isn't a `T`.
+    pub fn new(context: &Context, value: v8::Local<v8::Value>) -> Result<Self, Error> {
+        context::with_scope_for(context.id(), |scope| {
+            Self::from_local(scope, context.id(), value)
+        })?
+    }
+
+    /// Like `new`, in a scope of the context with id `context_id`.
+    pub(crate) fn from_local(
+        scope: &mut v8::HandleScope<'_>,
+        context_id: u64,
+        value: v8::Local<v8::Value>,
+    ) -> Result<Self, Error> {
+        if !T::is(value) {
+            return Err(Error::TypeError(format!("expected {}", T::NAME)));
+        }
+
+        return Ok(Self {
+            context_id,
+            global: v8::Global::new(scope, value),
+            _type: PhantomData,
+        });
+    }
+
+    /// Calls `f` with the value as a local of `context`, which has to be the
+    /// context it was created in. The local lives as long as the scope `f`
+    /// gets, which also works inside a native callback.
+    pub fn open<R>(
+        &self,
+        context: &Context,
+        f: impl for<'s> FnOnce(&mut v8::HandleScope<'s>, v8::Local<'s, T>) -> R,
+    ) -> Result<R, Error>
+    where
+        for<'s> v8::Local<'s, T>: TryFrom<v8::Local<'s, v8::Value>>,
+    {
+        if context.id() != self.context_id {
+            return Err(Error::InvalidContext);
+        }
+
+        context::with_scope_for(self.context_id, |scope| {
+            let value = self.local(scope, self.context_id)?;
+            Ok(f(scope, value))
+        })?
+    }
+
+    /// Like `open`, without the cast to `T`.
+    pub fn open_value<R>(
+        &self,
+        context: &Context,
+        f: impl for<'s> FnOnce(&mut v8::HandleScope<'s>, v8::Local<'s, v8::Value>) -> R,
+    ) -> Result<R, Error> {
+        if context.id() != self.context_id {
+            return Err(Error::InvalidContext);
+        }
+
+        context::with_scope_for(self.context_id, |scope| {
+            let value = v8::Local::new(scope, &self.global);
+            f(scope, value)
+        })
+    }
+
+    /// Like `open`, in a scope of the context with id `context_id`.
+    pub(crate) fn local<'s>(
+        &self,
+        scope: &mut v8::HandleScope<'s>,
+        context_id: u64,
+    ) -> Result<v8::Local<'s, T>, Error>
+    where
+        v8::Local<'s, T>: TryFrom<v8::Local<'s, v8::Value>>,
+    {
+        if context_id != self.context_id {
+            return Err(Error::InvalidContext);
+        }
+
+        let value = v8::Local::new(scope, &self.global);
+        return match v8::Local::<T>::try_from(value) {
+            Ok(v) => Ok(v),
+            Err(_) => Err(Error::TypeError(format!("expected {}", T::NAME))),
+        };
+    }
+
+    /// Copies the value out into a `Value`.
+    pub fn to_value(&self, context: &Context) -> Result<Value, Error> {
+        self.open_value(context, |scope, value| value.as_value(scope))
+    }
+
+    /// Forgets the type, e.g. to keep handles of mixed types in one list.
+    pub fn erase(self) -> JsHandle<v8::Value> {
+        JsHandle {
+            context_id: self.context_id,
+            global: self.global,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl JsHandle<v8::Value> {
+    /// Checks the type of an erased handle.
+    pub fn downcast<T: HandleType>(self) -> Result<JsHandle<T>, Self> {
+        let matches = context::with_scope_for(self.context_id, |scope| {
+            T::is(v8::Local::new(scope, &self.global))
+        })
+        .unwrap_or(false);
+
+        return match matches {
+            true => Ok(JsHandle {
+                context_id: self.context_id,
+                global: self.global,
+                _type: PhantomData,
+            }),
+            false => Err(self),
+        };
+    }
+}
+
+impl<T: HandleType> std::fmt::Debug for JsHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsHandle")
+            .field("type", &T::NAME)
+            .field("context_id", &self.context_id)
+            .finish()
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{error::Error, Value};
+use crate::{
+    context, error::Error, Array, ArrayBuffer, Date, Function, JsError, Object, RegExp, TypedArray,
+    Value,
+};
 use rusty_v8 as v8;
 
 pub trait AsLocal<T> {
@@ -22,6 +25,305 @@ pub trait AsValue {
 
 impl AsValue for v8::Local<'_, v8::Value> {
     fn as_value(&self, scope: &mut v8::HandleScope<'_>) -> Value {
-        Value::String(self.to_rust_string_lossy(scope))
+        as_value_at(scope, *self, 0)
     }
 }
+
+/// Cyclic objects are cut off as `undefined` at this depth.
+const MAX_DEPTH: usize = 512;
+
+fn as_value_at(
+    scope: &mut v8::HandleScope<'_>,
+    value: v8::Local<'_, v8::Value>,
+    depth: usize,
+) -> Value {
+    if value.is_undefined() || depth == MAX_DEPTH {
+        return Value::Undefined;
+    }
+
+    if value.is_null() {
+        return Value::Null;
+    }
+
+    if value.is_boolean() {
+        return Value::Boolean(value.is_true());
+    }
+
+    if value.is_number() {
+        return Value::Number(value.number_value(scope).unwrap_or(f64::NAN));
+    }
+
+    if let Ok(array) = v8::Local::<v8::Array>::try_from(value) {
+        let values = (0..array.length())
+            .map(|i| match array.get_index(scope, i) {
+                Some(v) => as_value_at(scope, v, depth + 1),
+                None => Value::Undefined,
+            })
+            .collect();
+        return Value::Array(Array::new(values));
+    }
+
+    if let Some(array) = TypedArray::from_local(scope, value) {
+        return Value::TypedArray(array);
+    }
+
+    if let Some(buffer) = ArrayBuffer::from_local(value) {
+        return Value::ArrayBuffer(buffer);
+    }
+
+    if let Ok(date) = v8::Local::<v8::Date>::try_from(value) {
+        return Value::Date(Date::from_time_value(date.value_of()));
+    }
+
+    if value.is_reg_exp() {
+        let object = value.to_object(scope).unwrap();
+        return Value::RegExp(RegExp::new(
+            string_property(scope, object, "source").unwrap_or_default(),
+            string_property(scope, object, "flags").unwrap_or_default(),
+        ));
+    }
+
+    if let Ok(map) = v8::Local::<v8::Map>::try_from(value) {
+        // keys and values alternate in the flattened array
+        let flat = map.as_array(scope);
+        let mut entries = Vec::with_capacity(flat.length() as usize / 2);
+        for i in (0..flat.length()).step_by(2) {
+            let key = match flat.get_index(scope, i) {
+                Some(v) => as_value_at(scope, v, depth + 1),
+                None => Value::Undefined,
+            };
+            let value = match flat.get_index(scope, i + 1) {
+                Some(v) => as_value_at(scope, v, depth + 1),
+                None => Value::Undefined,
+            };
+            entries.push((key, value));
+        }
+        return Value::Map(entries);
+    }
+
+    if value.is_set() {
+        let values = match set_values(scope, value) {
+            Some(array) => (0..array.length())
+                .map(|i| match array.get_index(scope, i) {
+                    Some(v) => as_value_at(scope, v, depth + 1),
+                    None => Value::Undefined,
+                })
+                .collect(),
+            None => vec![],
+        };
+        return Value::Set(values);
+    }
+
+    if value.is_native_error() {
+        let object = value.to_object(scope).unwrap();
+        return Value::Error(JsError {
+            name: string_property(scope, object, "name").unwrap_or_default(),
+            message: string_property(scope, object, "message").unwrap_or_default(),
+            stack: string_property(scope, object, "stack"),
+        });
+    }
+
+    if let Ok(function) = v8::Local::<v8::Function>::try_from(value) {
+        return match context::current_context_id(scope) {
+            Some(id) => Value::Function(Function::new(scope, id, function)),
+            None => Value::String(value.to_rust_string_lossy(scope)),
+        };
+    }
+
+    if value.is_object() {
+        let object = value.to_object(scope).unwrap();
+        let keys = match object.get_own_property_names(scope) {
+            Some(v) => v,
+            None => return Value::Object(Object::new()),
+        };
+
+        let mut result = Object::with_capacity(keys.length() as usize);
+        for i in 0..keys.length() {
+            let key = match keys.get_index(scope, i) {
+                Some(v) => v,
+                None => continue,
+            };
+            let item = match object.get(scope, key) {
+                Some(v) => as_value_at(scope, v, depth + 1),
+                None => Value::Undefined,
+            };
+            result.insert(key.to_rust_string_lossy(scope), item);
+        }
+        return Value::Object(result);
+    }
+
+    Value::String(value.to_rust_string_lossy(scope))
+}
+
+/// A property read as a string, `None` if it is missing or `undefined`.
+fn string_property(
+    scope: &mut v8::HandleScope<'_>,
+    object: v8::Local<v8::Object>,
+    key: &str,
+) -> Option<String> {
+    let key = v8::String::new(scope, key)?.into();
+    match object.get(scope, key) {
+        Some(v) if !v.is_undefined() => Some(v.to_rust_string_lossy(scope)),
+        _ => None,
+    }
+}
+
+/// Name of the private global property holding the builtins `builtin` hands
+/// out.
+const BUILTINS_KEY: &str = "gdv8::builtins";
+
+/// The builtins values are built and read with.
+const BUILTINS: [&str; 4] = ["RegExp", "Set", "Array.from", "Object.freeze"];
+
+fn builtins_key<'s>(scope: &mut v8::HandleScope<'s>) -> v8::Local<'s, v8::Private> {
+    let name = v8::String::new(scope, BUILTINS_KEY).unwrap();
+    v8::Private::for_api(scope, Some(name))
+}
+
+/// Copies the builtins off the global object of a new context, before a
+/// script can replace them.
+pub(crate) fn snapshot_builtins(scope: &mut v8::HandleScope<'_>) {
+    let global = scope.get_current_context().global(scope);
+    let builtins = v8::Object::new(scope);
+
+    for path in BUILTINS {
+        let mut value: Option<v8::Local<v8::Value>> = Some(global.into());
+        for segment in path.split('.') {
+            let key = v8::String::new(scope, segment).unwrap().into();
+            value = value
+                .and_then(|v| v.to_object(scope))
+                .and_then(|v| v.get(scope, key));
+        }
+
+        if let Some(value) = value {
+            let key = v8::String::new(scope, path).unwrap().into();
+            builtins.set(scope, key, value);
+        }
+    }
+
+    let key = builtins_key(scope);
+    global.set_private(scope, key, builtins.into());
+}
+
+/// A builtin like `Set` or `Array.from` as it was when the context was
+/// created.
+fn builtin<'s>(scope: &mut v8::HandleScope<'s>, path: &str) -> Option<v8::Local<'s, v8::Function>> {
+    let global = scope.get_current_context().global(scope);
+    let key = builtins_key(scope);
+    let builtins = global.get_private(scope, key)?.to_object(scope)?;
+
+    let key = v8::String::new(scope, path)?.into();
+    v8::Local::<v8::Function>::try_from(builtins.get(scope, key)?).ok()
+}
+
+/// `new name(...args)` for a builtin constructor, `None` if it throws.
+pub(crate) fn construct<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    name: &str,
+    args: &[v8::Local<v8::Value>],
+) -> Option<v8::Local<'s, v8::Object>> {
+    let scope = &mut v8::TryCatch::new(scope);
+    builtin(scope, name)?.new_instance(scope, args)
+}
+
+/// `Array.from(set)`, V8 has no API to read a `Set` directly. `None` if it
+/// throws.
+pub(crate) fn set_values<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    set: v8::Local<v8::Value>,
+) -> Option<v8::Local<'s, v8::Array>> {
+    let scope = &mut v8::TryCatch::new(scope);
+    let from = builtin(scope, "Array.from")?;
+    let receiver = v8::undefined(scope).into();
+    let array = from.call(scope, receiver, &[set])?;
+    v8::Local::<v8::Array>::try_from(array).ok()
+}
+
+/// `Object.freeze(object)`.
+pub(crate) fn freeze(scope: &mut v8::HandleScope<'_>, object: v8::Local<v8::Object>) -> Option<()> {
+    let freeze = builtin(scope, "Object.freeze")?;
+    let receiver = v8::undefined(scope).into();
+    freeze.call(scope, receiver, &[object.into()])?;
+    Some(())
+}
+
+/// Creates the error through the matching constructor, keeping the original
+/// stack instead of one pointing here.
+pub(crate) fn error_to_local<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    error: &JsError,
+) -> v8::Local<'s, v8::Value> {
+    let message = v8::String::new(scope, &error.message).unwrap();
+    let (exception, custom_name) = match error.name.as_str() {
+        "Error" => (v8::Exception::error(scope, message), false),
+        "TypeError" => (v8::Exception::type_error(scope, message), false),
+        "RangeError" => (v8::Exception::range_error(scope, message), false),
+        "ReferenceError" => (v8::Exception::reference_error(scope, message), false),
+        "SyntaxError" => (v8::Exception::syntax_error(scope, message), false),
+        _ => (v8::Exception::error(scope, message), true),
+    };
+
+    if let Some(object) = exception.to_object(scope) {
+        if custom_name {
+            let name_key = v8::String::new(scope, "name").unwrap().into();
+            let name = v8::String::new(scope, &error.name).unwrap().into();
+            object.set(scope, name_key, name);
+        }
+
+        if let Some(stack) = &error.stack {
+            let stack_key = v8::String::new(scope, "stack").unwrap().into();
+            let stack = v8::String::new(scope, stack).unwrap().into();
+            object.set(scope, stack_key, stack);
+        }
+    }
+
+    exception
+}
+
+/// Reads a file through Godot so `res://` and `user://` paths resolve.
+pub fn read_file(path: &str) -> Option<Vec<u8>> {
+    let path = godot::builtin::GString::from(path);
+    match godot::classes::FileAccess::file_exists(path.clone()) {
+        true => Some(godot::classes::FileAccess::get_file_as_bytes(path).to_vec()),
+        false => None,
+    }
+}
+
+/// Writes a file through Godot, creating missing directories on the way.
+pub fn write_file(path: &str, bytes: &[u8]) -> Result<(), Error> {
+    use godot::classes::{file_access::ModeFlags, DirAccess, FileAccess};
+
+    let path = godot::builtin::GString::from(path);
+    DirAccess::make_dir_recursive_absolute(path.get_base_dir());
+
+    let mut file = match FileAccess::open(path.clone(), ModeFlags::WRITE) {
+        Some(v) => v,
+        None => return Err(Error::Io(format!("could not write {path}"))),
+    };
+
+    file.store_buffer(godot::builtin::PackedByteArray::from(bytes));
+    file.close();
+
+    return Ok(());
+}
+
+/// FNV-1a, stable across builds unlike the std hasher.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The url of a trailing `//# sourceMappingURL=` comment, if the source has one.
+pub fn source_mapping_url(source: &str) -> Option<&str> {
+    source
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| {
+            line.strip_prefix("//# sourceMappingURL=")
+                .or_else(|| line.strip_prefix("//@ sourceMappingURL="))
+        })
+        .map(str::trim)
+}
@@ -0,0 +1,373 @@
+use crate::{coercion::number_to_string, error::Error, Array, Object, Value};
+
+/// Deeper documents are rejected instead of overflowing the stack.
+const MAX_DEPTH: usize = 512;
+
+/// Parses a JSON document the way `JSON.parse` does, duplicate keys keep the
+/// last value.
+pub fn parse(source: &str) -> Result<Value, Error> {
+    let mut parser = Parser {
+        source,
+        bytes: source.as_bytes(),
+        position: 0,
+        depth: 0,
+    };
+
+    let value = parser.value()?;
+    parser.whitespace();
+
+    return match parser.peek() {
+        Some(_) => Err(parser.unexpected()),
+        None => Ok(value),
+    };
+}
+
+/// Serializes `value` the way `JSON.stringify` does: `undefined` and function
+/// properties are left out, such array items and non-finite numbers become
+/// `null`. A top-level `undefined` or function gives `None`, where JS gives
+/// `undefined`.
+pub fn stringify(value: &Value) -> Option<String> {
+    if matches!(value, Value::Undefined | Value::Function(_)) {
+        return None;
+    }
+
+    let mut out = String::new();
+    write_value(&mut out, value);
+    Some(out)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        let before = &self.source[..self.position];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        Error::Json(format!("{message} at line {line} column {column}"))
+    }
+
+    fn unexpected(&self) -> Error {
+        match self.source[self.position..].chars().next() {
+            Some(c) => self.error(&format!("unexpected `{c}`")),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        self.whitespace();
+        match self.peek() == Some(byte) {
+            true => {
+                self.position += 1;
+                Ok(())
+            }
+            false => Err(self.unexpected()),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.whitespace();
+
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Value::Boolean(true)),
+            Some(b'f') => self.literal("false", Value::Boolean(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value, Error>) -> Result<Value, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("document nested too deeply"));
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Value, Error> {
+        match self.source[self.position..].starts_with(literal) {
+            true => {
+                self.position += literal.len();
+                Ok(value)
+            }
+            false => Err(self.unexpected()),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Error> {
+        let mut object = Object::new();
+        self.position += 1;
+        self.whitespace();
+
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+            return Ok(Value::Object(object));
+        }
+
+        loop {
+            self.whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.unexpected());
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            let value = self.value()?;
+            object.insert(key, value);
+
+            self.whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(object));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Error> {
+        let mut values = vec![];
+        self.position += 1;
+        self.whitespace();
+
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Value::Array(Array::new(values)));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.whitespace();
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(Array::new(values)));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// Checks the JSON number grammar, the conversion itself is left to
+    /// `str::parse` which rounds correctly.
+    fn number(&mut self) -> Result<Value, Error> {
+        let start = self.position;
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => {
+                self.digits();
+            }
+            _ => return Err(self.unexpected()),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            if self.digits() == 0 {
+                return Err(self.unexpected());
+            }
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.unexpected());
+            }
+        }
+
+        match self.source[start..self.position].parse::<f64>() {
+            Ok(v) => Ok(Value::Number(v)),
+            Err(_) => Err(self.error("invalid number")),
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = match self.source.get(self.position..self.position + 4) {
+            Some(v) if v.bytes().all(|b| b.is_ascii_hexdigit()) => v,
+            _ => return Err(self.error("invalid unicode escape")),
+        };
+
+        self.position += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let mut out = String::new();
+        self.position += 1;
+
+        loop {
+            let start = self.position;
+            while let Some(b) = self.peek() {
+                if b == b'"' || b == b'\\' || b < 0x20 {
+                    break;
+                }
+                self.position += 1;
+            }
+            out.push_str(&self.source[start..self.position]);
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => {
+                    self.position += 1;
+                    let escape = self.peek();
+                    self.position += 1;
+                    match escape {
+                        Some(b'"') => out.push('"'),
+                        Some(b'\\') => out.push('\\'),
+                        Some(b'/') => out.push('/'),
+                        Some(b'b') => out.push('\u{8}'),
+                        Some(b'f') => out.push('\u{c}'),
+                        Some(b'n') => out.push('\n'),
+                        Some(b'r') => out.push('\r'),
+                        Some(b't') => out.push('\t'),
+                        Some(b'u') => out.push(self.unicode_escape()?),
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error("invalid escape"));
+                        }
+                    }
+                }
+                Some(_) => return Err(self.error("control character in string")),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    /// `\uXXXX`, joining surrogate pairs. Lone surrogates can't live in a Rust
+    /// string and become U+FFFD.
+    fn unicode_escape(&mut self) -> Result<char, Error> {
+        let high = self.hex4()?;
+
+        if (0xD800..0xDC00).contains(&high) && self.source[self.position..].starts_with("\\u") {
+            let position = self.position;
+            self.position += 2;
+            let low = self.hex4()?;
+            if (0xDC00..0xE000).contains(&low) {
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return Ok(char::from_u32(code).unwrap_or('\u{FFFD}'));
+            }
+            self.position = position;
+        }
+
+        Ok(char::from_u32(high).unwrap_or('\u{FFFD}'))
+    }
+}
+
+fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::String(v) => write_string(out, v),
+        Value::Number(v) if !v.is_finite() => out.push_str("null"),
+        Value::Number(v) => out.push_str(&number_to_string(*v)),
+        Value::Boolean(v) => out.push_str(if *v { "true" } else { "false" }),
+        Value::Null | Value::Undefined | Value::Function(_) => out.push_str("null"),
+        Value::Array(v) => {
+            out.push('[');
+            for (i, value) in v.items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, value);
+            }
+            out.push(']');
+        }
+        // `toJSON`, `null` for an invalid date
+        Value::Date(v) => match v.to_iso_string() {
+            Some(iso) => write_string(out, &iso),
+            None => out.push_str("null"),
+        },
+        // no enumerable own properties
+        Value::ArrayBuffer(_)
+        | Value::RegExp(_)
+        | Value::Map(_)
+        | Value::Set(_)
+        | Value::Error(_) => out.push_str("{}"),
+        // like JS, a typed array is written as an object keyed by index
+        Value::TypedArray(v) => {
+            out.push('{');
+            for (i, value) in v.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, &i.to_string());
+                out.push(':');
+                write_value(out, &Value::Number(value));
+            }
+            out.push('}');
+        }
+        Value::Object(v) => {
+            out.push('{');
+            let entries = v
+                .iter()
+                .filter(|(_, v)| !matches!(v, Value::Undefined | Value::Function(_)));
+            for (i, (key, value)) in entries.enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, key);
+                out.push(':');
+                write_value(out, value);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -1,14 +1,57 @@
+mod array;
+mod builtin;
+mod callback;
+mod class;
+mod code_cache;
+mod coercion;
 mod context;
+mod declarations;
 mod error;
+mod function;
+mod handle;
 mod helper;
+mod json;
+mod namespace;
+mod object;
+mod owned;
+mod proxy;
+mod runtime;
+mod snapshot;
+mod source_map;
+mod typed_array;
+mod typescript;
 mod value;
+mod value_ops;
+mod variant;
+#[cfg(feature = "serde")]
+mod value_serde;
 
-use std::{cell::OnceCell, collections::HashMap, sync::Mutex};
+use std::{cell::OnceCell, collections::HashMap, rc::Rc, sync::Mutex};
 use rusty_v8 as v8;
 
-pub use context::Context;
-pub use error::Error;
+pub use array::Array;
+pub use builtin::{Date, JsError, RegExp};
+pub use callback::CallbackArgs;
+pub use class::{ClassDescriptor, ConstructorFn, MethodFn, NativeClass, StaticFn};
+pub use code_cache::CodeCache;
+pub use coercion::Hint;
+pub use context::{Context, JSContext};
+pub use declarations::{Declarations, JSDeclarations, Signature, TsType};
+pub use error::{Error, Exception, PathSegment, ValueError};
+pub use function::Function;
+pub use handle::{
+    ArrayBufferHandle, FunctionHandle, HandleType, JsHandle, ObjectHandle, PromiseHandle,
+};
+pub use gdv8_macros::{class, function, FromValue, IntoValue};
+pub use namespace::NamespaceDescriptor;
+pub use object::Object;
+pub use runtime::RuntimeBuilder;
+pub use snapshot::SnapshotBuilder;
+pub use typed_array::{ArrayBuffer, Element, TypedArray, TypedArrayKind};
+pub use typescript::{is_typescript, transpile};
 pub use value::*;
+#[cfg(feature = "serde")]
+pub use value_serde::{from_value, to_value};
 
 static V8_RUNTIME: Mutex<OnceCell<Runtime>> = Mutex::new(OnceCell::new());
 
@@ -17,23 +60,26 @@ unsafe impl Sync for Runtime {}
 
 struct Runtime {
     isolate_ptr: *mut v8::OwnedIsolate,
-    registry: HashMap<u64, HashMap<String, Callable>>,
+    registry: HashMap<u64, HashMap<String, Rc<Callable>>>,
+    signatures: HashMap<String, Signature>,
 }
 
 pub enum Callable {
     Godot(godot::builtin::Callable),
-    Closure(Box<dyn Fn(Vec<Value>) -> Value>),
+    /// Returning an `Err` throws it into the calling script.
+    Closure(Box<dyn Fn(Vec<Value>) -> Result<Value, Error>>),
+    /// Like `Closure`, but only converts the arguments it reads.
+    Native(Box<dyn Fn(&mut CallbackArgs<'_, '_>) -> Result<Value, Error>>),
 }
 
 impl Runtime {
-    pub fn new() -> Self {
-        let platform = v8::new_default_platform(0, false).make_shared();
-        v8::V8::initialize_platform(platform);
-        v8::V8::initialize();
-
+    /// Creates the isolate, V8 has to be initialized with
+    /// `RuntimeBuilder::initialize_v8` first.
+    pub fn new(builder: RuntimeBuilder) -> Self {
         Self {
-            isolate_ptr: Box::leak(Box::new(v8::Isolate::new(v8::CreateParams::default()))),
+            isolate_ptr: Box::leak(Box::new(v8::Isolate::new(builder.create_params()))),
             registry: HashMap::new(),
+            signatures: HashMap::new(),
         }
     }
     pub fn get_isolate(&self) -> Result<&'static mut v8::OwnedIsolate, Error> {
@@ -44,7 +90,7 @@ impl Runtime {
             }
         };
     }
-    pub fn get_registry(&mut self, id: u64) -> &mut HashMap<String, Callable> {
+    pub fn get_registry(&mut self, id: u64) -> &mut HashMap<String, Rc<Callable>> {
         match self.registry.contains_key(&id) {
             true => self.registry.get_mut(&id).unwrap(),
             false => {
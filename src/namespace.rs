@@ -0,0 +1,52 @@
+use crate::{Callable, Signature};
+
+/// A JS object of native callables, e.g. `game.entities` with `spawn` and
+/// `despawn`, registered in one go by `Context::register_namespace`.
+pub struct NamespaceDescriptor {
+    pub(crate) path: String,
+    pub(crate) callables: Vec<(String, Callable)>,
+    pub(crate) signatures: Vec<(String, Signature)>,
+    pub(crate) namespaces: Vec<NamespaceDescriptor>,
+    pub(crate) frozen: bool,
+}
+
+impl NamespaceDescriptor {
+    /// `path` is dotted, e.g. `game.entities`. For a nested namespace it is
+    /// relative to the parent.
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            callables: vec![],
+            signatures: vec![],
+            namespaces: vec![],
+            frozen: false,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn callable(mut self, name: &str, callable: Callable) -> Self {
+        self.callables.push((name.to_string(), callable));
+        self
+    }
+
+    /// Types of the callable `name` for the `.d.ts` generator.
+    pub fn declare(mut self, name: &str, signature: Signature) -> Self {
+        self.signatures.push((name.to_string(), signature));
+        self
+    }
+
+    pub fn namespace(mut self, namespace: NamespaceDescriptor) -> Self {
+        self.namespaces.push(namespace);
+        self
+    }
+
+    /// `Object.freeze` the namespace object once it is filled, so scripts
+    /// can't replace or add to its members.
+    pub fn frozen(mut self) -> Self {
+        self.frozen = true;
+        self
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use crate::Value;
+
+/// A JS object's own properties in JS enumeration order: array index keys
+/// ascending first, then every other key in insertion order.
+#[derive(Clone, Default)]
+pub struct Object {
+    entries: Vec<(String, Value)>,
+    positions: HashMap<String, usize>,
+    /// Number of leading entries keyed by an array index.
+    indices: usize,
+}
+
+/// `key` as an array index, `"01"` or `"4294967295"` are plain keys in JS.
+pub(crate) fn array_index(key: &str) -> Option<u32> {
+    if key.len() > 1 && key.starts_with('0') {
+        return None;
+    }
+
+    match key.parse::<u32>() {
+        Ok(v) if v != u32::MAX && key.bytes().all(|b| b.is_ascii_digit()) => Some(v),
+        _ => None,
+    }
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            positions: HashMap::with_capacity(capacity),
+            indices: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.positions.get(key).map(|i| &self.entries[*i].1)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        match self.positions.get(key) {
+            Some(i) => Some(&mut self.entries[*i].1),
+            None => None,
+        }
+    }
+
+    /// Sets a property, an existing key keeps its position like assignment
+    /// in JS does. Returns the previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: Value) -> Option<Value> {
+        let key = key.into();
+
+        if let Some(i) = self.positions.get(&key) {
+            return Some(std::mem::replace(&mut self.entries[*i].1, value));
+        }
+
+        match array_index(&key) {
+            Some(index) => {
+                let at = self.entries[..self.indices]
+                    .partition_point(|(k, _)| array_index(k).unwrap_or(u32::MAX) < index);
+                self.entries.insert(at, (key, value));
+                self.indices += 1;
+                self.reindex(at);
+            }
+            None => {
+                self.positions.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+
+        return None;
+    }
+
+    /// Deletes a property, the others keep their order.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let i = self.positions.remove(key)?;
+        let (_, value) = self.entries.remove(i);
+
+        if i < self.indices {
+            self.indices -= 1;
+        }
+        self.reindex(i);
+
+        return Some(value);
+    }
+
+    /// Updates the positions of every entry from `from` onwards after the
+    /// entries moved.
+    fn reindex(&mut self, from: usize) {
+        for (i, (key, _)) in self.entries.iter().enumerate().skip(from) {
+            match self.positions.get_mut(key) {
+                Some(position) => *position = i,
+                None => {
+                    self.positions.insert(key.clone(), i);
+                }
+            }
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(k, _)| k.as_str())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Value> {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.entries.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut(self.entries.iter_mut())
+    }
+}
+
+impl std::fmt::Debug for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl Index<&str> for Object {
+    type Output = Value;
+
+    /// Missing properties read as `undefined`.
+    fn index(&self, key: &str) -> &Value {
+        self.get(key).unwrap_or(&Value::Undefined)
+    }
+}
+
+impl IndexMut<&str> for Object {
+    /// Missing properties are created as `undefined` first, so
+    /// `object["key"] = value` works like assignment in JS.
+    fn index_mut(&mut self, key: &str) -> &mut Value {
+        if !self.contains_key(key) {
+            self.insert(key, Value::Undefined);
+        }
+        self.get_mut(key).unwrap()
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, Value)> for Object {
+    fn from_iter<I: IntoIterator<Item = (K, Value)>>(iter: I) -> Self {
+        let mut object = Object::new();
+        object.extend(iter);
+        object
+    }
+}
+
+impl<K: Into<String>> Extend<(K, Value)> for Object {
+    fn extend<I: IntoIterator<Item = (K, Value)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+pub struct Iter<'a>(std::slice::Iter<'a, (String, Value)>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a str, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k.as_str(), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+pub struct IterMut<'a>(std::slice::IterMut<'a, (String, Value)>);
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = (&'a str, &'a mut Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k.as_str(), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IterMut<'_> {}
+
+pub struct IntoIter(std::vec::IntoIter<(String, Value)>);
+
+impl Iterator for IntoIter {
+    type Item = (String, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for Object {
+    type Item = (String, Value);
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter(self.entries.into_iter())
+    }
+}
+
+impl<'a> IntoIterator for &'a Object {
+    type Item = (&'a str, &'a Value);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Object {
+    type Item = (&'a str, &'a mut Value);
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> IterMut<'a> {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,66 @@
+use std::{any::Any, cell::RefCell, collections::HashMap};
+
+use crate::context::current_context_id;
+use rusty_v8 as v8;
+
+/// Native state owned by the JS objects of one context, by address.
+#[derive(Default)]
+struct Owned {
+    live: HashMap<usize, (v8::Weak<v8::Object>, Box<dyn Any>)>,
+    /// Weak handles whose finalizer ran. A handle can't be dropped from its
+    /// own finalizer, they go on the next `attach`.
+    finalized: Vec<v8::Weak<v8::Object>>,
+}
+
+thread_local! {
+    static OWNED: RefCell<HashMap<u64, Owned>> = RefCell::new(HashMap::new());
+}
+
+/// Keeps `state` until `object` is collected or its context is dropped. The
+/// pointer is meant for an `External` only `object` can reach, boxing first
+/// lets the object be built around it.
+pub(crate) fn attach<T: 'static>(
+    scope: &mut v8::HandleScope<'_>,
+    object: v8::Local<v8::Object>,
+    mut state: Box<T>,
+) -> *mut T {
+    let context_id = current_context_id(scope).unwrap_or(0);
+    let pointer = &mut *state as *mut T;
+    let address = pointer as usize;
+
+    let weak = v8::Weak::with_finalizer(
+        scope,
+        object,
+        Box::new(move |_| finalize(context_id, address)),
+    );
+
+    let finalized = OWNED.with(|owned| {
+        let mut owned = owned.borrow_mut();
+        let owned = owned.entry(context_id).or_default();
+        owned.live.insert(address, (weak, state));
+        std::mem::take(&mut owned.finalized)
+    });
+    drop(finalized);
+
+    pointer
+}
+
+fn finalize(context_id: u64, address: usize) {
+    let state = OWNED.with(|owned| {
+        let mut owned = owned.borrow_mut();
+        let owned = owned.get_mut(&context_id)?;
+        let (weak, state) = owned.live.remove(&address)?;
+        owned.finalized.push(weak);
+        Some(state)
+    });
+
+    // dropped without the borrow, the state may hold handles of its own
+    drop(state);
+}
+
+/// Frees the state of the objects a dropped context leaves behind, V8 runs
+/// no finalizers for them.
+pub(crate) fn release(context_id: u64) {
+    let owned = OWNED.with(|owned| owned.borrow_mut().remove(&context_id));
+    drop(owned);
+}
@@ -0,0 +1,486 @@
+//! Live JS views of Godot Dictionaries and Arrays. rusty_v8 doesn't expose
+//! V8's named and indexed property handlers, so a view is a `Proxy` whose
+//! traps are native functions reading and writing the collection itself.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ffi::c_void,
+};
+
+use godot::{
+    builtin::{Dictionary, GString, StringName, Variant, VariantArray, VariantType},
+    global::is_same,
+    meta::ToGodot,
+};
+use rusty_v8::{self as v8, MapFnTo};
+
+use crate::{context::throw_error, error::Error, owned, variant};
+
+/// Longest a script can make an array through a view. Godot arrays are
+/// dense, a stray `array[4e9] = 0` would otherwise fill gigabytes of `null`.
+const MAX_LENGTH: usize = 1 << 24;
+
+/// Name of the private property on a view's proxy target pointing to its
+/// `Backing`.
+const BACKING_KEY: &str = "gdv8::view";
+
+/// The collection behind a view. Clones share the same Godot collection.
+#[derive(Clone)]
+enum Target {
+    Dictionary(Dictionary),
+    Array(VariantArray),
+}
+
+impl Target {
+    fn to_variant(&self) -> Variant {
+        match self {
+            Target::Dictionary(dictionary) => dictionary.to_variant(),
+            Target::Array(array) => array.to_variant(),
+        }
+    }
+}
+
+/// State of a view, pointed to by the data of its traps. Lives as long as
+/// the handler object.
+struct Backing {
+    target: Target,
+    /// Views of the nested collections read so far by name, so reading the
+    /// same collection twice gives the same object.
+    views: RefCell<HashMap<String, (Variant, v8::Weak<v8::Value>)>>,
+}
+
+/// A JS object reading and writing `dictionary` directly. Keys of any type
+/// show up in their string form, new keys are added as strings.
+pub(crate) fn dictionary_proxy<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    dictionary: Dictionary,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    proxy(scope, Target::Dictionary(dictionary))
+}
+
+/// An array-like JS object reading and writing `array` directly. Array
+/// methods and iteration work on it, `Array.isArray` is `false` though.
+pub(crate) fn array_proxy<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    array: VariantArray,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    proxy(scope, Target::Array(array))
+}
+
+/// A new view for Dictionaries and Arrays, a copy for everything else.
+/// Nested collections are viewed too.
+pub(crate) fn live<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    variant: &Variant,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    match variant.get_type() {
+        VariantType::DICTIONARY => dictionary_proxy(scope, variant.to::<Dictionary>()),
+        VariantType::ARRAY => array_proxy(scope, variant.to::<VariantArray>()),
+        _ => variant::to_local(scope, variant, 0),
+    }
+}
+
+fn proxy<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    target: Target,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    let failed = || Error::TypeError("could not create proxy".to_string());
+
+    // properties the traps don't know about, like methods, come from here
+    let object = v8::Object::new(scope);
+    if let Target::Array(_) = target {
+        let array = v8::Array::new(scope, 0);
+        let prototype = array.get_prototype(scope).ok_or_else(failed)?;
+        object.set_prototype(scope, prototype).ok_or_else(failed)?;
+    }
+
+    let handler = v8::Object::new(scope);
+    let backing = Box::new(Backing {
+        target,
+        views: RefCell::new(HashMap::new()),
+    });
+    let pointer = owned::attach(scope, handler, backing);
+    let data: v8::Local<v8::Value> = v8::External::new(scope, pointer as *mut c_void).into();
+    let key = backing_key(scope);
+    object.set_private(scope, key, data).ok_or_else(failed)?;
+
+    macro_rules! trap {
+        ($name:literal, $callback:ident) => {
+            let function = v8::FunctionBuilder::<v8::Function>::new($callback)
+                .data(data)
+                .build(scope)
+                .ok_or_else(failed)?;
+            let key = v8::String::new(scope, $name).unwrap();
+            handler
+                .set(scope, key.into(), function.into())
+                .ok_or_else(failed)?;
+        };
+    }
+
+    trap!("get", get_trap);
+    trap!("set", set_trap);
+    trap!("has", has_trap);
+    trap!("deleteProperty", delete_trap);
+    trap!("ownKeys", own_keys_trap);
+    trap!("getOwnPropertyDescriptor", descriptor_trap);
+    trap!("defineProperty", define_trap);
+
+    let proxy = v8::Proxy::new(scope, object, handler).ok_or_else(failed)?;
+    return Ok(proxy.into());
+}
+
+/// The traps every view hands to V8, which a snapshot has to know about.
+pub(crate) fn external_references() -> Vec<v8::ExternalReference<'static>> {
+    let traps: [v8::FunctionCallback; 7] = [
+        get_trap.map_fn_to(),
+        set_trap.map_fn_to(),
+        has_trap.map_fn_to(),
+        delete_trap.map_fn_to(),
+        own_keys_trap.map_fn_to(),
+        descriptor_trap.map_fn_to(),
+        define_trap.map_fn_to(),
+    ];
+    traps
+        .into_iter()
+        .map(|function| v8::ExternalReference { function })
+        .collect()
+}
+
+fn backing_key<'s>(scope: &mut v8::HandleScope<'s>) -> v8::Local<'s, v8::Private> {
+    let name = v8::String::new(scope, BACKING_KEY).unwrap();
+    v8::Private::for_api(scope, Some(name))
+}
+
+/// The collection `value` is a view of, `None` if it isn't one.
+pub(crate) fn collection(
+    scope: &mut v8::HandleScope<'_>,
+    value: v8::Local<v8::Value>,
+) -> Option<Variant> {
+    let proxy = v8::Local::<v8::Proxy>::try_from(value).ok()?;
+    let object = v8::Local::<v8::Object>::try_from(proxy.get_target(scope)).ok()?;
+    let key = backing_key(scope);
+    let data = object.get_private(scope, key)?;
+    let data = v8::Local::<v8::External>::try_from(data).ok()?;
+
+    // the proxy keeps its handler and with it the backing alive
+    Some(unsafe { (*(data.value() as *const Backing)).target.to_variant() })
+}
+
+fn backing<'a>(args: &v8::FunctionCallbackArguments) -> &'a Backing {
+    let data = v8::Local::<v8::External>::try_from(args.data().unwrap()).unwrap();
+    unsafe { &*(data.value() as *const Backing) }
+}
+
+fn target(args: &v8::FunctionCallbackArguments) -> Target {
+    backing(args).target.clone()
+}
+
+/// Like `live`, but the view read at `name` is reused as long as `name` still
+/// holds the same collection.
+fn cached_live<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    backing: &Backing,
+    name: &str,
+    variant: &Variant,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    if !matches!(
+        variant.get_type(),
+        VariantType::DICTIONARY | VariantType::ARRAY
+    ) {
+        return live(scope, variant);
+    }
+
+    let cached = match backing.views.borrow().get(name) {
+        Some((collection, weak)) if is_same(collection, variant) => weak.to_local(scope),
+        _ => None,
+    };
+    if let Some(view) = cached {
+        return Ok(view);
+    }
+
+    let view = live(scope, variant)?;
+    let weak = v8::Weak::new(scope, view);
+    let mut views = backing.views.borrow_mut();
+    // views scripts let go of leave an empty handle behind
+    views.retain(|_, (_, weak)| !weak.is_empty());
+    views.insert(name.to_string(), (variant.clone(), weak));
+    return Ok(view);
+}
+
+/// The property name of a trap's key, `None` for symbols.
+fn property(scope: &mut v8::HandleScope<'_>, key: v8::Local<v8::Value>) -> Option<String> {
+    match key.is_symbol() {
+        true => None,
+        false => Some(key.to_rust_string_lossy(scope)),
+    }
+}
+
+/// `name` as an array index, only in its canonical form like `3`, not `03`.
+fn index(name: &str) -> Option<usize> {
+    match name.parse::<u32>() {
+        Ok(v) if v != u32::MAX && v.to_string() == name => Some(v as usize),
+        _ => None,
+    }
+}
+
+/// The key of `dictionary` that JS sees as `name`. String, StringName and
+/// integer keys are looked up directly, other keys by their string form.
+fn dictionary_key(dictionary: &Dictionary, name: &str) -> Option<Variant> {
+    let mut keys = vec![
+        GString::from(name).to_variant(),
+        StringName::from(name).to_variant(),
+    ];
+    if let Ok(v) = name.parse::<i64>() {
+        if v.to_string() == name {
+            keys.push(v.to_variant());
+        }
+    }
+    let found = keys
+        .into_iter()
+        .find(|key| dictionary.contains_key(key.clone()));
+    if found.is_some() {
+        return found;
+    }
+
+    dictionary
+        .iter_shared()
+        .map(|(key, _)| key)
+        .find(|key| key.to_string() == name)
+}
+
+/// The own value at `name`, `None` where JS would look at the prototype.
+fn own_value(target: &Target, name: &str) -> Option<Variant> {
+    match target {
+        Target::Dictionary(dictionary) => {
+            dictionary_key(dictionary, name).and_then(|key| dictionary.get(key))
+        }
+        Target::Array(array) if name == "length" => Some((array.len() as i64).to_variant()),
+        Target::Array(array) => index(name).and_then(|i| array.get(i)),
+    }
+}
+
+/// Grows with `null` or shrinks `array` to `length` elements, a
+/// `RangeError` beyond `MAX_LENGTH`.
+fn set_length(array: &mut VariantArray, length: usize) -> Result<(), Error> {
+    if length > MAX_LENGTH {
+        return Err(Error::RangeError(format!(
+            "Invalid array length {length}, views of Godot arrays are limited to {MAX_LENGTH}"
+        )));
+    }
+
+    array.resize(length, &Variant::nil());
+    return Ok(());
+}
+
+/// Writes `value` at `name`, `false` where an array has no such element.
+fn write(target: Target, name: &str, value: Variant) -> Result<bool, Error> {
+    match target {
+        Target::Dictionary(mut dictionary) => {
+            let key = dictionary_key(&dictionary, name)
+                .unwrap_or_else(|| GString::from(name).to_variant());
+            dictionary.set(key, value);
+            Ok(true)
+        }
+        Target::Array(mut array) if name == "length" => {
+            let length = match value.get_type() {
+                VariantType::INT => value.to::<i64>() as f64,
+                VariantType::FLOAT => value.to::<f64>(),
+                _ => f64::NAN,
+            };
+            match length >= 0.0 && length.fract() == 0.0 && length < u32::MAX as f64 {
+                true => set_length(&mut array, length as usize).map(|_| true),
+                false => Err(Error::RangeError("Invalid array length".to_string())),
+            }
+        }
+        Target::Array(mut array) => match index(name) {
+            Some(i) => {
+                if i >= array.len() {
+                    set_length(&mut array, i + 1)?;
+                }
+                array.set(i, value);
+                Ok(true)
+            }
+            None => Ok(false),
+        },
+    }
+}
+
+fn get_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let key = args.get(1);
+    let name = property(scope, key);
+    let value = name
+        .as_ref()
+        .and_then(|name| own_value(&target(&args), name));
+
+    let result = match (name, value) {
+        (Some(name), Some(v)) => cached_live(scope, backing(&args), &name, &v),
+        _ => {
+            let object = args.get(0).to_object(scope).unwrap();
+            Ok(object
+                .get(scope, key)
+                .unwrap_or_else(|| v8::undefined(scope).into()))
+        }
+    };
+
+    match result {
+        Ok(v) => retval.set(v),
+        Err(e) => throw_error(scope, e),
+    };
+}
+
+fn set_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let name = match property(scope, args.get(1)) {
+        Some(v) => v,
+        None => return retval.set(v8::Boolean::new(scope, false).into()),
+    };
+    let value = match variant::from_local(scope, args.get(2), 0) {
+        Ok(v) => v,
+        Err(e) => return throw_error(scope, e),
+    };
+
+    match write(target(&args), &name, value) {
+        Ok(v) => retval.set(v8::Boolean::new(scope, v).into()),
+        Err(e) => throw_error(scope, e),
+    };
+}
+
+fn has_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let key = args.get(1);
+    let has = match property(scope, key).and_then(|name| own_value(&target(&args), &name)) {
+        Some(_) => true,
+        None => {
+            let object = args.get(0).to_object(scope).unwrap();
+            object.has(scope, key).unwrap_or(false)
+        }
+    };
+
+    retval.set(v8::Boolean::new(scope, has).into());
+}
+
+/// Removes a Dictionary key. Array elements become `null` like the hole
+/// `delete` leaves in a JS array, the length stays.
+fn delete_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    if let Some(name) = property(scope, args.get(1)) {
+        match target(&args) {
+            Target::Dictionary(mut dictionary) => {
+                if let Some(key) = dictionary_key(&dictionary, &name) {
+                    dictionary.remove(key);
+                }
+            }
+            Target::Array(mut array) => match index(&name) {
+                Some(i) if i < array.len() => array.set(i, Variant::nil()),
+                _ => {}
+            },
+        }
+    }
+
+    retval.set(v8::Boolean::new(scope, true).into());
+}
+
+fn own_keys_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let names: Vec<String> = match target(&args) {
+        // keys like `1` and `"1"` both show up as "1", which a proxy may only
+        // report once
+        Target::Dictionary(dictionary) => {
+            let mut seen = HashSet::new();
+            dictionary
+                .iter_shared()
+                .map(|(key, _)| key.to_string())
+                .filter(|name| seen.insert(name.clone()))
+                .collect()
+        }
+        Target::Array(array) => (0..array.len()).map(|i| i.to_string()).collect(),
+    };
+
+    let keys: Vec<v8::Local<v8::Value>> = names
+        .iter()
+        .map(|name| v8::String::new(scope, name).unwrap().into())
+        .collect();
+    retval.set(v8::Array::new_with_elements(scope, &keys).into());
+}
+
+/// Every element and entry is a plain writable, enumerable data property,
+/// only an array's `length` isn't enumerable.
+fn descriptor_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let name = match property(scope, args.get(1)) {
+        Some(v) => v,
+        None => return,
+    };
+    let target = target(&args);
+    let value = match own_value(&target, &name) {
+        Some(v) => v,
+        None => return,
+    };
+    let value = match cached_live(scope, backing(&args), &name, &value) {
+        Ok(v) => v,
+        Err(e) => return throw_error(scope, e),
+    };
+    let enumerable = !matches!(target, Target::Array(_)) || name != "length";
+
+    let descriptor = v8::Object::new(scope);
+    let fields = [
+        ("value", value),
+        ("writable", v8::Boolean::new(scope, true).into()),
+        ("enumerable", v8::Boolean::new(scope, enumerable).into()),
+        ("configurable", v8::Boolean::new(scope, true).into()),
+    ];
+    for (key, value) in fields {
+        let key = v8::String::new(scope, key).unwrap();
+        descriptor.set(scope, key.into(), value);
+    }
+
+    retval.set(descriptor.into());
+}
+
+/// Only data descriptors, they are written like an assignment.
+fn define_trap(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let name = property(scope, args.get(1));
+    let value = args.get(2).to_object(scope).and_then(|descriptor| {
+        let key = v8::String::new(scope, "value").unwrap();
+        match descriptor.has(scope, key.into()) {
+            Some(true) => descriptor.get(scope, key.into()),
+            _ => None,
+        }
+    });
+
+    let defined = match (name, value) {
+        (Some(name), Some(value)) => {
+            variant::from_local(scope, value, 0).and_then(|v| write(target(&args), &name, v))
+        }
+        _ => Ok(false),
+    };
+
+    match defined {
+        Ok(v) => retval.set(v8::Boolean::new(scope, v).into()),
+        Err(e) => throw_error(scope, e),
+    };
+}
@@ -0,0 +1,272 @@
+use crate::{
+    class::{self, ClassReferences},
+    error::Error,
+    NativeClass, Runtime, V8_RUNTIME,
+};
+use godot::{
+    builtin::{GString, Variant},
+    classes::ProjectSettings,
+    meta::ToGodot,
+};
+use rusty_v8 as v8;
+use std::sync::OnceLock;
+
+/// The flags and platform settings V8 was initialized with.
+static V8_SETTINGS: OnceLock<(String, u32, bool)> = OnceLock::new();
+
+const SETTING_THREAD_POOL_SIZE: &str = "gdv8/runtime/thread_pool_size";
+const SETTING_IDLE_TASK_SUPPORT: &str = "gdv8/runtime/idle_task_support";
+const SETTING_EXPOSE_GC: &str = "gdv8/runtime/expose_gc";
+const SETTING_JITLESS: &str = "gdv8/runtime/jitless";
+const SETTING_STACK_SIZE: &str = "gdv8/runtime/stack_size";
+const SETTING_INITIAL_HEAP_SIZE: &str = "gdv8/runtime/initial_heap_size";
+const SETTING_MAX_HEAP_SIZE: &str = "gdv8/runtime/max_heap_size";
+const SETTING_FLAGS: &str = "gdv8/runtime/flags";
+const SETTING_SNAPSHOT_BLOB: &str = "gdv8/runtime/snapshot_blob";
+
+/// Configures the process wide V8 runtime before the first `Context` is created.
+///
+/// V8 can only be initialized once per process, so the builder has to be
+/// applied with `init` before anything else touches the runtime.
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeBuilder {
+    thread_pool_size: u32,
+    idle_task_support: bool,
+    expose_gc: bool,
+    jitless: bool,
+    stack_size: Option<usize>,
+    heap_limits: Option<(usize, usize)>,
+    flags: Vec<String>,
+    snapshot_blob: Option<Vec<u8>>,
+    classes: Vec<ClassReferences>,
+}
+
+impl RuntimeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the builder from the `gdv8/runtime/*` project settings.
+    /// Settings that are missing keep their default value.
+    pub fn from_project_settings() -> Self {
+        let settings = ProjectSettings::singleton();
+        let get = |name: &str| -> Option<Variant> {
+            match settings.has_setting(GString::from(name)) {
+                true => Some(settings.get_setting(GString::from(name))),
+                false => None,
+            }
+        };
+
+        let mut builder = Self::new();
+
+        if let Some(v) = get(SETTING_THREAD_POOL_SIZE).and_then(|v| v.try_to::<i64>().ok()) {
+            builder = builder.thread_pool_size(v.max(0) as u32);
+        }
+        if let Some(v) = get(SETTING_IDLE_TASK_SUPPORT).and_then(|v| v.try_to::<bool>().ok()) {
+            builder = builder.idle_task_support(v);
+        }
+        if let Some(v) = get(SETTING_EXPOSE_GC).and_then(|v| v.try_to::<bool>().ok()) {
+            builder = builder.expose_gc(v);
+        }
+        if let Some(v) = get(SETTING_JITLESS).and_then(|v| v.try_to::<bool>().ok()) {
+            builder = builder.jitless(v);
+        }
+        if let Some(v) = get(SETTING_STACK_SIZE).and_then(|v| v.try_to::<i64>().ok()) {
+            if v > 0 {
+                builder = builder.stack_size(v as usize);
+            }
+        }
+
+        let initial = get(SETTING_INITIAL_HEAP_SIZE).and_then(|v| v.try_to::<i64>().ok());
+        let max = get(SETTING_MAX_HEAP_SIZE).and_then(|v| v.try_to::<i64>().ok());
+        if let (Some(initial), Some(max)) = (initial, max) {
+            if max > 0 {
+                builder = builder.heap_limits(initial.max(0) as usize, max as usize);
+            }
+        }
+
+        if let Some(v) = get(SETTING_FLAGS).and_then(|v| v.try_to::<GString>().ok()) {
+            for flag in v.to_string().split_whitespace() {
+                builder = builder.flag(flag);
+            }
+        }
+
+        if let Some(v) = get(SETTING_SNAPSHOT_BLOB).and_then(|v| v.try_to::<GString>().ok()) {
+            let path = v.to_string();
+            if !path.is_empty() {
+                if let Some(blob) = crate::helper::read_file(&path) {
+                    builder = builder.snapshot_blob(blob);
+                }
+            }
+        }
+
+        return builder;
+    }
+
+    /// Registers the `gdv8/runtime/*` settings with their defaults so they
+    /// show up in the project settings dialog.
+    pub fn register_project_settings() {
+        let defaults = Self::default();
+        let mut settings = ProjectSettings::singleton();
+
+        let entries: [(&str, Variant); 9] = [
+            (SETTING_THREAD_POOL_SIZE, (defaults.thread_pool_size as i64).to_variant()),
+            (SETTING_IDLE_TASK_SUPPORT, defaults.idle_task_support.to_variant()),
+            (SETTING_EXPOSE_GC, defaults.expose_gc.to_variant()),
+            (SETTING_JITLESS, defaults.jitless.to_variant()),
+            (SETTING_STACK_SIZE, 0i64.to_variant()),
+            (SETTING_INITIAL_HEAP_SIZE, 0i64.to_variant()),
+            (SETTING_MAX_HEAP_SIZE, 0i64.to_variant()),
+            (SETTING_FLAGS, GString::new().to_variant()),
+            (SETTING_SNAPSHOT_BLOB, GString::new().to_variant()),
+        ];
+
+        for (name, value) in entries {
+            let name = GString::from(name);
+            if !settings.has_setting(name.clone()) {
+                settings.set_setting(name.clone(), value.clone());
+            }
+            settings.set_initial_value(name, value);
+        }
+    }
+
+    /// Number of worker threads for the V8 platform, 0 lets V8 decide.
+    pub fn thread_pool_size(mut self, size: u32) -> Self {
+        self.thread_pool_size = size;
+        self
+    }
+
+    pub fn idle_task_support(mut self, enabled: bool) -> Self {
+        self.idle_task_support = enabled;
+        self
+    }
+
+    /// Exposes `gc()` on the global object.
+    pub fn expose_gc(mut self, enabled: bool) -> Self {
+        self.expose_gc = enabled;
+        self
+    }
+
+    /// Runs V8 without a JIT, for platforms that forbid executable memory.
+    pub fn jitless(mut self, enabled: bool) -> Self {
+        self.jitless = enabled;
+        self
+    }
+
+    /// Stack size in KiB used by V8 for stack overflow checks.
+    pub fn stack_size(mut self, kib: usize) -> Self {
+        self.stack_size = Some(kib);
+        self
+    }
+
+    /// Initial and maximum heap size in bytes.
+    pub fn heap_limits(mut self, initial: usize, max: usize) -> Self {
+        self.heap_limits = Some((initial, max));
+        self
+    }
+
+    /// Enables a `--harmony-*` feature, the prefix is optional.
+    pub fn harmony(self, feature: &str) -> Self {
+        let feature = feature.trim_start_matches("--").trim_start_matches("harmony-");
+        self.flag(&format!("--harmony-{feature}"))
+    }
+
+    /// Passes a raw flag to V8, e.g. `--max-lazy`.
+    pub fn flag(mut self, flag: &str) -> Self {
+        self.flags.push(flag.to_string());
+        self
+    }
+
+    pub fn snapshot_blob(mut self, blob: Vec<u8>) -> Self {
+        self.snapshot_blob = Some(blob);
+        self
+    }
+
+    /// Lets the runtime load a snapshot holding the class `T`, see
+    /// `SnapshotBuilder::class`. Classes have to be added in the same order.
+    pub fn class<T: NativeClass>(mut self) -> Self {
+        self.classes.push(class::class_references::<T>);
+        self
+    }
+
+    /// Loads a snapshot produced by `SnapshotBuilder`, `res://` paths work.
+    pub fn snapshot_path(self, path: &str) -> Result<Self, Error> {
+        return match crate::helper::read_file(path) {
+            Some(blob) => Ok(self.snapshot_blob(blob)),
+            None => Err(Error::Io(format!("could not read snapshot {path}"))),
+        };
+    }
+
+    /// Initializes the runtime with this configuration. Fails with
+    /// `V8AlreadyInitialized` if a `SnapshotBuilder` already initialized V8
+    /// with other flags or platform settings.
+    pub fn init(self) -> Result<(), Error> {
+        let guard = V8_RUNTIME.lock().unwrap();
+        if guard.get().is_some() {
+            return Err(Error::RuntimeAlreadyInitialized);
+        }
+
+        self.initialize_v8()?;
+        let _ = guard.set(Runtime::new(self));
+        return Ok(());
+    }
+
+    /// Sets the flags and initializes the V8 platform, once per process.
+    /// Later calls only check that they ask for the same flags and platform,
+    /// heap limits and snapshots are per isolate and may differ.
+    pub(crate) fn initialize_v8(&self) -> Result<(), Error> {
+        let settings = (
+            self.v8_flags(),
+            self.thread_pool_size,
+            self.idle_task_support,
+        );
+
+        let initialized = V8_SETTINGS.get_or_init(|| {
+            if !settings.0.is_empty() {
+                v8::V8::set_flags_from_string(&settings.0);
+            }
+
+            v8::V8::initialize_platform(self.platform());
+            v8::V8::initialize();
+            settings.clone()
+        });
+
+        return match *initialized == settings {
+            true => Ok(()),
+            false => Err(Error::V8AlreadyInitialized),
+        };
+    }
+
+    pub(crate) fn v8_flags(&self) -> String {
+        let mut flags: Vec<String> = vec![];
+
+        if self.expose_gc {
+            flags.push("--expose-gc".to_string());
+        }
+        if self.jitless {
+            flags.push("--jitless".to_string());
+        }
+        if let Some(size) = self.stack_size {
+            flags.push(format!("--stack-size={size}"));
+        }
+        flags.extend(self.flags.iter().cloned());
+
+        flags.join(" ")
+    }
+
+    pub(crate) fn platform(&self) -> v8::SharedRef<v8::Platform> {
+        v8::new_default_platform(self.thread_pool_size, self.idle_task_support).make_shared()
+    }
+
+    pub(crate) fn create_params(&self) -> v8::CreateParams {
+        let references = crate::snapshot::external_references(&self.classes);
+        let mut params = v8::CreateParams::default().external_references(&**references);
+        if let Some((initial, max)) = self.heap_limits {
+            params = params.heap_limits(initial, max);
+        }
+        if let Some(blob) = &self.snapshot_blob {
+            params = params.snapshot_blob(blob.clone());
+        }
+        params
+    }
+}
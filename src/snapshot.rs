@@ -0,0 +1,163 @@
+use crate::{
+    class::{self, ClassReferences, ClassRegister},
+    context::{
+        accessor_getter, accessor_setter, callable_trampoline, caught_exception,
+        define_callable_stub, owned_callable_trampoline, script_origin,
+    },
+    error::Error,
+    proxy,
+    source_map::remap_callback,
+    NativeClass, RuntimeBuilder,
+};
+use rusty_v8::{self as v8, MapFnTo};
+
+/// Native functions that can end up inside a snapshot. Isolates that load a
+/// snapshot have to be created with the exact same list, so with the same
+/// classes in the same order.
+pub(crate) fn external_references(classes: &[ClassReferences]) -> &'static v8::ExternalReferences {
+    let mut references = vec![
+        v8::ExternalReference {
+            function: callable_trampoline.map_fn_to(),
+        },
+        v8::ExternalReference {
+            function: owned_callable_trampoline.map_fn_to(),
+        },
+        v8::ExternalReference {
+            getter: accessor_getter.map_fn_to(),
+        },
+        v8::ExternalReference {
+            setter: accessor_setter.map_fn_to(),
+        },
+        v8::ExternalReference {
+            function: remap_callback.map_fn_to(),
+        },
+    ];
+    references.extend(proxy::external_references());
+    for class in classes {
+        references.extend(class());
+    }
+
+    // V8 reads the list for as long as the isolate lives
+    Box::leak(Box::new(v8::ExternalReferences::new(&references)))
+}
+
+/// Builds a V8 startup snapshot from a set of bootstrap scripts.
+///
+/// Every `Context` created by a runtime that loads the snapshot starts with
+/// the global environment the scripts left behind, without running them again.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotBuilder {
+    scripts: Vec<(String, String)>,
+    callables: Vec<String>,
+    classes: Vec<(ClassReferences, ClassRegister)>,
+    runtime: RuntimeBuilder,
+}
+
+impl SnapshotBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a bootstrap script, scripts run in the order they were added.
+    pub fn script(mut self, name: &str, source: &str) -> Self {
+        self.scripts.push((name.to_string(), source.to_string()));
+        self
+    }
+
+    /// Declares a function at a dotted path like `game.spawn` that the
+    /// bootstrap scripts can already use. Rust closures can't be stored in a
+    /// snapshot, each context supplies the callable with `Context::implement`
+    /// and the function throws until it does.
+    pub fn callable(mut self, path: &str) -> Self {
+        self.callables.push(path.to_string());
+        self
+    }
+
+    /// Defines the class `T` for the bootstrap scripts. The runtime loading
+    /// the snapshot needs the same classes in the same order, see
+    /// `RuntimeBuilder::class`. Instances can't be part of the snapshot,
+    /// their native state lives outside the V8 heap.
+    pub fn class<T: NativeClass>(mut self) -> Self {
+        self.classes
+            .push((class::class_references::<T>, class::register::<T>));
+        self
+    }
+
+    /// The runtime configuration V8 is initialized with if the snapshot is
+    /// built before the runtime, use the same one as `RuntimeBuilder::init`
+    /// when both run in one process.
+    pub fn runtime(mut self, runtime: RuntimeBuilder) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
+    /// Runs the bootstrap scripts and returns the snapshot blob.
+    pub fn build(&self) -> Result<Vec<u8>, Error> {
+        // the snapshot creator brings its own isolate, it only needs the
+        // platform and leaves the runtime alone
+        self.runtime.initialize_v8()?;
+
+        let classes: Vec<ClassReferences> = self.classes.iter().map(|(v, _)| *v).collect();
+        let mut snapshot_creator = v8::SnapshotCreator::new(Some(external_references(&classes)));
+        // the isolate is owned by the snapshot creator and must not be dropped
+        let mut isolate = unsafe { snapshot_creator.get_owned_isolate() };
+
+        let result = {
+            let scope = &mut v8::HandleScope::new(&mut isolate);
+            let context = v8::Context::new(scope);
+            let scope = &mut v8::ContextScope::new(scope, context);
+
+            let result = self
+                .classes
+                .iter()
+                .try_for_each(|(_, register)| register(scope))
+                .and_then(|_| {
+                    self.callables
+                        .iter()
+                        .try_for_each(|path| define_callable_stub(scope, path))
+                })
+                .and_then(|_| {
+                    self.scripts
+                        .iter()
+                        .try_for_each(|(name, source)| run_bootstrap_script(scope, name, source))
+                });
+
+            snapshot_creator.set_default_context(context);
+            result
+        };
+
+        std::mem::forget(isolate);
+
+        result?;
+
+        return match snapshot_creator.create_blob(v8::FunctionCodeHandling::Keep) {
+            Some(blob) => Ok(blob.to_vec()),
+            None => Err(Error::SnapshotCreationFailed),
+        };
+    }
+
+    /// Builds the snapshot and writes it to `path`, meant to be called from a
+    /// build script or editor tool to produce the `.bin` shipped in `res://`.
+    pub fn write(&self, path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+        let blob = self.build()?;
+        return std::fs::write(path, blob).map_err(|e| Error::Io(e.to_string()));
+    }
+}
+
+fn run_bootstrap_script(
+    scope: &mut v8::HandleScope<'_>,
+    name: &str,
+    source: &str,
+) -> Result<(), Error> {
+    let scope = &mut v8::TryCatch::new(scope);
+    let origin = script_origin(scope, source, name, 0, 0);
+    let source = v8::String::new(scope, source).unwrap();
+
+    let result =
+        v8::Script::compile(scope, source, Some(&origin)).and_then(|script| script.run(scope));
+
+    return match result {
+        Some(_) => Ok(()),
+        None => Err(caught_exception(scope)),
+    };
+}
@@ -0,0 +1,146 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::{error::Exception, helper};
+use godot::builtin::GString;
+use rusty_v8 as v8;
+use sourcemap::DecodedMap;
+
+static SOURCE_MAPS: Mutex<Option<HashMap<String, SourceMap>>> = Mutex::new(None);
+
+/// A registered map and the offsets its script was compiled at, the map
+/// itself knows nothing about them.
+struct SourceMap {
+    map: DecodedMap,
+    line_offset: u32,
+    column_offset: u32,
+}
+
+/// Installs `Error.prepareStackTrace` so `Error.stack` reports original
+/// positions. The remap function is handed in instead of living on the global.
+const PREPARE_STACK_TRACE: &str = r#"(remap) => {
+    Error.prepareStackTrace = (error, callsites) => {
+        const frames = callsites.map((site) => {
+            let file = site.getFileName();
+            let line = site.getLineNumber();
+            let column = site.getColumnNumber();
+
+            if (file && line !== null) {
+                const mapped = remap(file, line, column);
+                if (mapped) [file, line, column] = mapped;
+            }
+
+            const location = file ? `${file}:${line}:${column}` : "<anonymous>";
+            const name = site.getFunctionName();
+            return name ? `    at ${name} (${location})` : `    at ${location}`;
+        });
+        return [String(error), ...frames].join("\n");
+    };
+}"#;
+
+/// Original position for a 1-based line and column in a generated script,
+/// as V8 reports it with the script's offsets included.
+pub fn lookup(resource_name: &str, line: u32, column: u32) -> Option<(String, u32, u32)> {
+    let guard = SOURCE_MAPS.lock().unwrap();
+    let entry = guard.as_ref()?.get(resource_name)?;
+
+    // the column offset only shifts the first line
+    let line = line.checked_sub(entry.line_offset + 1)?;
+    let column = match line {
+        0 => column.checked_sub(entry.column_offset)?,
+        _ => column,
+    };
+    let token = entry.map.lookup_token(line, column.saturating_sub(1))?;
+
+    Some((
+        token.get_source().unwrap_or(resource_name).to_string(),
+        token.get_src_line() + 1,
+        token.get_src_col() + 1,
+    ))
+}
+
+/// Reads the source map referenced by `source`, either inline as a data url or
+/// as a file relative to the script, and registers it for `resource_name`.
+/// A script without one drops the map an earlier script of that name had.
+/// The offsets are the ones the script is compiled at.
+pub fn register(resource_name: &str, source: &str, line_offset: i32, column_offset: i32) {
+    let url = match helper::source_mapping_url(source) {
+        Some(v) => v,
+        None => {
+            if let Some(maps) = SOURCE_MAPS.lock().unwrap().as_mut() {
+                maps.remove(resource_name);
+            }
+            return;
+        }
+    };
+
+    let map = match url.starts_with("data:") {
+        true => sourcemap::decode_data_url(url).ok(),
+        false => {
+            let path = GString::from(resource_name).get_base_dir().path_join(GString::from(url));
+            helper::read_file(&path.to_string())
+                .and_then(|bytes| sourcemap::decode_slice(&bytes).ok())
+        }
+    };
+
+    let mut guard = SOURCE_MAPS.lock().unwrap();
+    let maps = guard.get_or_insert_with(HashMap::new);
+    match map {
+        Some(map) => maps.insert(
+            resource_name.to_string(),
+            SourceMap {
+                map,
+                line_offset: line_offset.max(0) as u32,
+                column_offset: column_offset.max(0) as u32,
+            },
+        ),
+        None => maps.remove(resource_name),
+    };
+}
+
+/// Rewrites the position of an exception to the original sources. The stack
+/// already is in original positions, `Error.prepareStackTrace` remapped it.
+pub fn remap_exception(exception: &mut Exception) {
+    if let (Some(name), Some(line)) = (&exception.resource_name, exception.line) {
+        let column = exception.column.unwrap_or(0) as u32 + 1;
+        if let Some((source, line, column)) = lookup(name, line as u32, column) {
+            exception.resource_name = Some(source);
+            exception.line = Some(line as usize);
+            exception.column = Some(column as usize - 1);
+        }
+    }
+}
+
+pub(crate) fn remap_callback(
+    scope: &mut v8::HandleScope<'_>,
+    args: v8::FunctionCallbackArguments,
+    mut retval: v8::ReturnValue,
+) {
+    let name = args.get(0).to_rust_string_lossy(scope);
+    let line = args.get(1).uint32_value(scope).unwrap_or(0);
+    let column = args.get(2).uint32_value(scope).unwrap_or(0);
+
+    if let Some((source, line, column)) = lookup(&name, line, column) {
+        let source = v8::String::new(scope, &source).unwrap().into();
+        let line = v8::Integer::new_from_unsigned(scope, line).into();
+        let column = v8::Integer::new_from_unsigned(scope, column).into();
+
+        retval.set(v8::Array::new_with_elements(scope, &[source, line, column]).into());
+    }
+}
+
+/// Hooks `Error.stack` formatting of the current context up to the source maps.
+pub(crate) fn install(scope: &mut v8::HandleScope<'_>) {
+    let source = v8::String::new(scope, PREPARE_STACK_TRACE).unwrap();
+    let installer = v8::Script::compile(scope, source, None)
+        .and_then(|script| script.run(scope))
+        .and_then(|installer| v8::Local::<v8::Function>::try_from(installer).ok())
+        .expect("stack trace installer should compile");
+
+    let remap = v8::FunctionBuilder::<v8::FunctionTemplate>::new(remap_callback)
+        .build(scope)
+        .get_function(scope)
+        .unwrap();
+
+    let recv = v8::undefined(scope).into();
+    installer.call(scope, recv, &[remap.into()]);
+}
@@ -0,0 +1,488 @@
+//! `ArrayBuffer` and typed arrays, sharing their memory with V8.
+//!
+//! A `Value::ArrayBuffer` or `Value::TypedArray` holds the V8 backing store
+//! itself, so passing one between JS and Rust never copies the bytes and
+//! writes on either side are seen by the other. Godot's packed arrays are
+//! copy-on-write values that JS could otherwise write through, so they are
+//! copied in bulk once when they cross over.
+
+use std::cell::Cell;
+
+use godot::builtin::{
+    PackedByteArray, PackedColorArray, PackedFloat32Array, PackedFloat64Array, PackedInt32Array,
+    PackedInt64Array, PackedVector2Array, PackedVector3Array, Variant, VariantType,
+};
+use rusty_v8 as v8;
+
+use crate::error::Error;
+
+/// Raw bytes shared with a JS `ArrayBuffer`, clones share the same memory.
+#[derive(Clone)]
+pub struct ArrayBuffer(v8::SharedRef<v8::BackingStore>);
+
+impl ArrayBuffer {
+    /// Takes ownership of `bytes` without copying them.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(
+            v8::ArrayBuffer::new_backing_store_from_boxed_slice(bytes.into_boxed_slice())
+                .make_shared(),
+        )
+    }
+
+    /// The buffer behind a JS `ArrayBuffer`.
+    pub(crate) fn from_local(value: v8::Local<v8::Value>) -> Option<Self> {
+        let buffer = v8::Local::<v8::ArrayBuffer>::try_from(value).ok()?;
+        Some(Self(buffer.get_backing_store()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.byte_length()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bytes in place. They are `Cell`s since scripts can write to the
+    /// buffer while Rust holds on to it.
+    pub fn as_cells(&self) -> &[Cell<u8>] {
+        &self.0
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        copy_cells(self.as_cells())
+    }
+
+    /// Whether both are views of the same memory.
+    pub fn same(&self, other: &ArrayBuffer) -> bool {
+        std::ptr::eq(self.as_cells().as_ptr(), other.as_cells().as_ptr())
+    }
+
+    pub(crate) fn as_local<'s>(
+        &self,
+        scope: &mut v8::HandleScope<'s>,
+    ) -> v8::Local<'s, v8::ArrayBuffer> {
+        v8::ArrayBuffer::with_backing_store(scope, &self.0)
+    }
+}
+
+impl std::fmt::Debug for ArrayBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayBuffer")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// The element type of a typed array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+}
+
+impl TypedArrayKind {
+    pub fn element_size(self) -> usize {
+        match self {
+            TypedArrayKind::Int8 | TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => 1,
+            TypedArrayKind::Int16 | TypedArrayKind::Uint16 => 2,
+            TypedArrayKind::Int32 | TypedArrayKind::Uint32 | TypedArrayKind::Float32 => 4,
+            TypedArrayKind::Float64 | TypedArrayKind::BigInt64 => 8,
+        }
+    }
+
+    /// The JS constructor name, e.g. `Float32Array`.
+    pub fn name(self) -> &'static str {
+        match self {
+            TypedArrayKind::Int8 => "Int8Array",
+            TypedArrayKind::Uint8 => "Uint8Array",
+            TypedArrayKind::Uint8Clamped => "Uint8ClampedArray",
+            TypedArrayKind::Int16 => "Int16Array",
+            TypedArrayKind::Uint16 => "Uint16Array",
+            TypedArrayKind::Int32 => "Int32Array",
+            TypedArrayKind::Uint32 => "Uint32Array",
+            TypedArrayKind::Float32 => "Float32Array",
+            TypedArrayKind::Float64 => "Float64Array",
+            TypedArrayKind::BigInt64 => "BigInt64Array",
+        }
+    }
+
+    fn of(value: v8::Local<v8::Value>) -> Option<Self> {
+        let kind = match () {
+            _ if value.is_int8_array() => TypedArrayKind::Int8,
+            _ if value.is_uint8_array() => TypedArrayKind::Uint8,
+            _ if value.is_uint8_clamped_array() => TypedArrayKind::Uint8Clamped,
+            _ if value.is_int16_array() => TypedArrayKind::Int16,
+            _ if value.is_uint16_array() => TypedArrayKind::Uint16,
+            _ if value.is_int32_array() => TypedArrayKind::Int32,
+            _ if value.is_uint32_array() => TypedArrayKind::Uint32,
+            _ if value.is_float32_array() => TypedArrayKind::Float32,
+            _ if value.is_float64_array() => TypedArrayKind::Float64,
+            _ if value.is_big_int64_array() => TypedArrayKind::BigInt64,
+            _ => return None,
+        };
+        return Some(kind);
+    }
+
+    /// The element in `cells` as a JS number, `BigInt64` ones lose precision
+    /// beyond 2^53.
+    fn number(self, cells: &[Cell<u8>]) -> f64 {
+        let mut buffer = [0; 8];
+        for (byte, cell) in buffer.iter_mut().zip(cells) {
+            *byte = cell.get();
+        }
+        let bytes = &buffer[..self.element_size()];
+
+        match self {
+            TypedArrayKind::Int8 => i8::from_bytes(bytes) as f64,
+            TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => bytes[0] as f64,
+            TypedArrayKind::Int16 => i16::from_bytes(bytes) as f64,
+            TypedArrayKind::Uint16 => u16::from_bytes(bytes) as f64,
+            TypedArrayKind::Int32 => i32::from_bytes(bytes) as f64,
+            TypedArrayKind::Uint32 => u32::from_bytes(bytes) as f64,
+            TypedArrayKind::Float32 => f32::from_bytes(bytes) as f64,
+            TypedArrayKind::Float64 => f64::from_bytes(bytes),
+            TypedArrayKind::BigInt64 => i64::from_bytes(bytes) as f64,
+        }
+    }
+}
+
+/// Copies `cells` out in one go.
+fn copy_cells(cells: &[Cell<u8>]) -> Vec<u8> {
+    // `Cell<u8>` has the layout of `u8` and no script runs during the copy
+    unsafe { &*(cells as *const [Cell<u8>] as *const [u8]) }.to_vec()
+}
+
+/// The bytes of `values` in one copy. Only for plain numbers and Godot's
+/// vectors and colors, which have no padding.
+fn bytes_of<T: Copy>(values: &[T]) -> Vec<u8> {
+    unsafe {
+        std::slice::from_raw_parts(values.as_ptr().cast::<u8>(), std::mem::size_of_val(values))
+    }
+    .to_vec()
+}
+
+/// A Rust type stored in a typed array.
+pub trait Element: Copy {
+    const KIND: TypedArrayKind;
+
+    fn from_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! element {
+    ($($type:ty => $kind:ident),*) => {
+        $(impl Element for $type {
+            const KIND: TypedArrayKind = TypedArrayKind::$kind;
+
+            fn from_bytes(bytes: &[u8]) -> Self {
+                <$type>::from_ne_bytes(bytes.try_into().unwrap())
+            }
+        })*
+    };
+}
+
+element!(
+    i8 => Int8, u8 => Uint8, i16 => Int16, u16 => Uint16, i32 => Int32, u32 => Uint32,
+    f32 => Float32, f64 => Float64, i64 => BigInt64
+);
+
+/// A view of `length` elements into an `ArrayBuffer`, like a JS typed array.
+#[derive(Clone, Debug)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    buffer: ArrayBuffer,
+    byte_offset: usize,
+    length: usize,
+}
+
+impl TypedArray {
+    /// A view into `buffer`, failing like the JS constructor does when it
+    /// doesn't fit or `byte_offset` isn't a multiple of the element size.
+    pub fn new(
+        kind: TypedArrayKind,
+        buffer: ArrayBuffer,
+        byte_offset: usize,
+        length: usize,
+    ) -> Result<Self, Error> {
+        let size = kind.element_size();
+        if byte_offset % size != 0 {
+            return Err(Error::RangeError(format!(
+                "start offset of {} should be a multiple of {size}",
+                kind.name()
+            )));
+        }
+        let end = length
+            .checked_mul(size)
+            .and_then(|v| v.checked_add(byte_offset));
+        if !matches!(end, Some(end) if end <= buffer.len()) {
+            return Err(Error::RangeError(format!(
+                "invalid {} length {length}",
+                kind.name()
+            )));
+        }
+
+        return Ok(Self {
+            kind,
+            buffer,
+            byte_offset,
+            length,
+        });
+    }
+
+    /// Copies `values` into a new buffer.
+    pub fn from_slice<T: Element>(values: &[T]) -> Self {
+        Self {
+            kind: T::KIND,
+            buffer: ArrayBuffer::new(bytes_of(values)),
+            byte_offset: 0,
+            length: values.len(),
+        }
+    }
+
+    /// A `Float32Array` of the interleaved components of Godot vectors or
+    /// colors, copied in one go unless Godot stores them as `f64`.
+    fn from_components<V: Copy, const N: usize>(
+        values: &[V],
+        components: impl Fn(&V) -> [f32; N],
+    ) -> Self {
+        let bytes = match std::mem::size_of::<V>() == N * 4 {
+            true => bytes_of(values),
+            false => bytes_of(&values.iter().flat_map(components).collect::<Vec<_>>()),
+        };
+
+        Self {
+            kind: TypedArrayKind::Float32,
+            buffer: ArrayBuffer::new(bytes),
+            byte_offset: 0,
+            length: values.len() * N,
+        }
+    }
+
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    pub fn buffer(&self) -> &ArrayBuffer {
+        &self.buffer
+    }
+
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    pub fn byte_length(&self) -> usize {
+        self.length * self.kind.element_size()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The viewed bytes in place.
+    fn cells(&self) -> &[Cell<u8>] {
+        &self.buffer.as_cells()[self.byte_offset..self.byte_offset + self.byte_length()]
+    }
+
+    /// Copies the viewed bytes out.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        copy_cells(self.cells())
+    }
+
+    /// Copies the elements out, `None` if they aren't `T`s.
+    pub fn to_vec<T: Element>(&self) -> Option<Vec<T>> {
+        let same_kind = match (T::KIND, self.kind) {
+            (TypedArrayKind::Uint8, TypedArrayKind::Uint8Clamped) => true,
+            (a, b) => a == b,
+        };
+        if !same_kind {
+            return None;
+        }
+
+        let mut values = Vec::<T>::with_capacity(self.length);
+        // the elements are plain numbers of the same size, copied bytewise
+        // since the view may not be aligned for `T`
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                self.cells().as_ptr().cast::<u8>(),
+                values.as_mut_ptr().cast::<u8>(),
+                self.byte_length(),
+            );
+            values.set_len(self.length);
+        }
+        return Some(values);
+    }
+
+    /// The element at `index` as a JS number.
+    pub fn get(&self, index: usize) -> Option<f64> {
+        if index >= self.length {
+            return None;
+        }
+
+        let size = self.kind.element_size();
+        return Some(self.kind.number(&self.cells()[index * size..][..size]));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = f64> + '_ {
+        self.cells()
+            .chunks_exact(self.kind.element_size())
+            .map(|cells| self.kind.number(cells))
+    }
+
+    /// Whether both view the same elements of the same memory.
+    pub fn same(&self, other: &TypedArray) -> bool {
+        self.kind == other.kind
+            && self.buffer.same(&other.buffer)
+            && self.byte_offset == other.byte_offset
+            && self.length == other.length
+    }
+
+    pub(crate) fn from_local(
+        scope: &mut v8::HandleScope<'_>,
+        value: v8::Local<v8::Value>,
+    ) -> Option<Self> {
+        let kind = TypedArrayKind::of(value)?;
+        let view = v8::Local::<v8::ArrayBufferView>::try_from(value).ok()?;
+        let buffer = view.buffer(scope)?;
+
+        Some(Self {
+            kind,
+            buffer: ArrayBuffer(buffer.get_backing_store()),
+            byte_offset: view.byte_offset(),
+            length: view.byte_length() / kind.element_size(),
+        })
+    }
+
+    pub(crate) fn as_local<'s>(&self, scope: &mut v8::HandleScope<'s>) -> v8::Local<'s, v8::Value> {
+        let buffer = self.buffer.as_local(scope);
+        let (offset, length) = (self.byte_offset, self.length);
+
+        let array: Option<v8::Local<v8::Value>> = match self.kind {
+            TypedArrayKind::Int8 => {
+                v8::Int8Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Uint8 => {
+                v8::Uint8Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Uint8Clamped => {
+                v8::Uint8ClampedArray::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Int16 => {
+                v8::Int16Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Uint16 => {
+                v8::Uint16Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Int32 => {
+                v8::Int32Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Uint32 => {
+                v8::Uint32Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Float32 => {
+                v8::Float32Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::Float64 => {
+                v8::Float64Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+            TypedArrayKind::BigInt64 => {
+                v8::BigInt64Array::new(scope, buffer, offset, length).map(Into::into)
+            }
+        };
+
+        // the bounds were checked when the view was made
+        array.unwrap()
+    }
+
+    /// The packed array Godot stores these elements in. Elements narrower
+    /// than Godot's integer arrays are widened, `Uint32` to 64 bits.
+    pub fn to_variant(&self) -> Variant {
+        match self.kind {
+            TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => {
+                Variant::from(PackedByteArray::from(self.to_bytes().as_slice()))
+            }
+            TypedArrayKind::Int8 | TypedArrayKind::Int16 | TypedArrayKind::Uint16 => {
+                let values: Vec<i32> = match self.kind {
+                    TypedArrayKind::Int8 => self.widened::<i8, i32>(),
+                    TypedArrayKind::Int16 => self.widened::<i16, i32>(),
+                    _ => self.widened::<u16, i32>(),
+                };
+                Variant::from(PackedInt32Array::from(values.as_slice()))
+            }
+            TypedArrayKind::Int32 => {
+                let values = self.to_vec::<i32>().unwrap();
+                Variant::from(PackedInt32Array::from(values.as_slice()))
+            }
+            TypedArrayKind::Uint32 => {
+                let values = self.widened::<u32, i64>();
+                Variant::from(PackedInt64Array::from(values.as_slice()))
+            }
+            TypedArrayKind::BigInt64 => {
+                let values = self.to_vec::<i64>().unwrap();
+                Variant::from(PackedInt64Array::from(values.as_slice()))
+            }
+            TypedArrayKind::Float32 => {
+                let values = self.to_vec::<f32>().unwrap();
+                Variant::from(PackedFloat32Array::from(values.as_slice()))
+            }
+            TypedArrayKind::Float64 => {
+                let values = self.to_vec::<f64>().unwrap();
+                Variant::from(PackedFloat64Array::from(values.as_slice()))
+            }
+        }
+    }
+
+    /// The elements copied out as `T`s and widened to `W`s.
+    fn widened<T: Element, W: From<T>>(&self) -> Vec<W> {
+        let values = self.to_vec::<T>().unwrap();
+        values.into_iter().map(W::from).collect()
+    }
+
+    /// The typed array for a Godot packed array, `None` for other variants.
+    /// `PackedInt64Array` becomes a `BigInt64Array`, vector and color arrays a
+    /// `Float32Array` of their interleaved components.
+    pub fn from_variant(variant: &Variant) -> Option<Self> {
+        let array = match variant.get_type() {
+            VariantType::PACKED_BYTE_ARRAY => {
+                Self::from_slice(variant.to::<PackedByteArray>().as_slice())
+            }
+            VariantType::PACKED_INT32_ARRAY => {
+                Self::from_slice(variant.to::<PackedInt32Array>().as_slice())
+            }
+            VariantType::PACKED_INT64_ARRAY => {
+                Self::from_slice(variant.to::<PackedInt64Array>().as_slice())
+            }
+            VariantType::PACKED_FLOAT32_ARRAY => {
+                Self::from_slice(variant.to::<PackedFloat32Array>().as_slice())
+            }
+            VariantType::PACKED_FLOAT64_ARRAY => {
+                Self::from_slice(variant.to::<PackedFloat64Array>().as_slice())
+            }
+            VariantType::PACKED_VECTOR2_ARRAY => {
+                let values = variant.to::<PackedVector2Array>();
+                Self::from_components(values.as_slice(), |v| [v.x as f32, v.y as f32])
+            }
+            VariantType::PACKED_VECTOR3_ARRAY => {
+                let values = variant.to::<PackedVector3Array>();
+                Self::from_components(values.as_slice(), |v| [v.x as f32, v.y as f32, v.z as f32])
+            }
+            VariantType::PACKED_COLOR_ARRAY => {
+                let values = variant.to::<PackedColorArray>();
+                Self::from_components(values.as_slice(), |c| [c.r, c.g, c.b, c.a])
+            }
+            _ => return None,
+        };
+        return Some(array);
+    }
+}
@@ -0,0 +1,84 @@
+use std::{borrow::Cow, collections::HashMap, sync::Mutex};
+
+use crate::error::Error;
+
+static TRANSPILED: Mutex<Option<HashMap<(String, u64), String>>> = Mutex::new(None);
+
+/// Whether a script name refers to TypeScript that has to be transpiled.
+pub fn is_typescript(name: &str) -> bool {
+    [".ts", ".tsx", ".mts", ".cts"]
+        .iter()
+        .any(|ext| name.ends_with(ext) && !name.ends_with(".d.ts"))
+}
+
+/// Strips types from `source`, leaving plain JS with an inline source map that
+/// points back to `name`. Results are cached by name and source hash.
+pub fn transpile(name: &str, source: &str) -> Result<String, Error> {
+    let key = (name.to_string(), crate::helper::hash(source.as_bytes()));
+
+    if let Some(v) = TRANSPILED.lock().unwrap().as_ref().and_then(|cache| cache.get(&key)) {
+        return Ok(v.clone());
+    }
+
+    let transpiled = strip_types(name, source)?;
+
+    TRANSPILED
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(key, transpiled.clone());
+
+    return Ok(transpiled);
+}
+
+/// Transpiles `source` if `name` says it is TypeScript, JS passes through.
+pub(crate) fn maybe_transpile<'a>(name: &str, source: &'a str) -> Result<Cow<'a, str>, Error> {
+    return match is_typescript(name) {
+        true => transpile(name, source).map(Cow::Owned),
+        false => Ok(Cow::Borrowed(source)),
+    };
+}
+
+#[cfg(feature = "typescript")]
+fn strip_types(name: &str, source: &str) -> Result<String, Error> {
+    use deno_ast::{EmitOptions, MediaType, ModuleSpecifier, ParseParams, SourceMapOption};
+
+    let specifier = ModuleSpecifier::parse(name)
+        .or_else(|_| ModuleSpecifier::parse(&format!("file:///{}", name.trim_start_matches('/'))))
+        .map_err(|e| Error::Transpile(format!("{name}: {e}")))?;
+
+    let media_type = match name.ends_with(".tsx") {
+        true => MediaType::Tsx,
+        false => MediaType::TypeScript,
+    };
+
+    // the output is compiled as a classic script, parse it as one so that
+    // `import` and `export` fail here instead of after transpiling
+    let parsed = deno_ast::parse_script(ParseParams {
+        specifier,
+        text: source.into(),
+        media_type,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|e| Error::Transpile(e.to_string()))?;
+
+    let emit_options = EmitOptions {
+        source_map: SourceMapOption::Inline,
+        inline_sources: true,
+        ..Default::default()
+    };
+
+    return parsed
+        .transpile(&Default::default(), &emit_options)
+        .map(|result| result.into_source().text)
+        .map_err(|e| Error::Transpile(e.to_string()));
+}
+
+#[cfg(not(feature = "typescript"))]
+fn strip_types(name: &str, _source: &str) -> Result<String, Error> {
+    Err(Error::Transpile(format!(
+        "{name}: gdv8 was built without the `typescript` feature"
+    )))
+}
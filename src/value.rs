@@ -1,22 +1,36 @@
-use std::iter::FromIterator;
 use std::{collections::HashMap, ops::Deref};
 use godot::builtin::VariantType;
 use rusty_v8::{self as v8, HandleScope};
 
 use godot::meta::{FromGodot, GodotConvert, ToGodot};
 
+use crate::{error::Error, helper, object::array_index, Array, ArrayBuffer, Date, Function, JsError, Object, RegExp, TypedArray};
+
+/// A JS value copied out of a context. Not `Send` or `Sync` since a
+/// `Function` holds a handle into its context.
 #[derive(Clone, Debug)]
 pub enum Value {
     String(String),
     Number(f64),
     Object(Object),
     Array(Array),
+    Function(Function),
+    ArrayBuffer(ArrayBuffer),
+    TypedArray(TypedArray),
+    Date(Date),
+    RegExp(RegExp),
+    /// Entries in insertion order, keys can be any value like in JS.
+    Map(Vec<(Value, Value)>),
+    Set(Vec<Value>),
+    Error(JsError),
+    Boolean(bool),
+    Null,
     Undefined,
 }
 
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.coerce_to_string())
+        write!(f, "{}", self.to_string())
     }
 }
 
@@ -37,6 +51,30 @@ impl ToGodot for Value {
             Value::Array(_) => {
                 godot::builtin::Variant::nil()
             }
+            Value::Function(_) => godot::builtin::Variant::nil(),
+            Value::ArrayBuffer(v) => godot::builtin::Variant::from(
+                godot::builtin::PackedByteArray::from(v.to_vec().as_slice()),
+            ),
+            Value::TypedArray(v) => v.to_variant(),
+            // Unix time in seconds, see `Date::to_datetime_dict` for the fields
+            Value::Date(v) => godot::builtin::Variant::from(v.unix_time()),
+            Value::RegExp(v) => match v.to_godot_regex() {
+                Some(regex) => godot::builtin::Variant::from(regex),
+                None => godot::builtin::Variant::nil(),
+            },
+            Value::Map(entries) => {
+                let mut dictionary = godot::builtin::Dictionary::new();
+                for (key, value) in entries {
+                    dictionary.set(key.to_godot(), value.to_godot());
+                }
+                godot::builtin::Variant::from(dictionary)
+            }
+            Value::Set(values) => godot::builtin::Variant::from(
+                godot::builtin::VariantArray::from_iter(values.iter().map(|v| v.to_godot())),
+            ),
+            Value::Error(v) => godot::builtin::Variant::from(v.to_dictionary()),
+            Value::Boolean(v) => godot::builtin::Variant::from(*v),
+            Value::Null => godot::builtin::Variant::nil(),
             Value::Undefined => godot::builtin::Variant::nil(),
         }
     }
@@ -46,91 +84,92 @@ impl FromGodot for Value {
     fn try_from_godot(via: Self::Via) -> Result<Self, godot::prelude::ConvertError> {
         match via.get_type() {
             VariantType::NIL => Ok(Value::Undefined),
+            VariantType::BOOL => Ok(Value::Boolean(via.to())),
             VariantType::STRING => Ok(Value::String(via.to_string())),
-            _ => Err(godot::prelude::ConvertError::new("type not representable by gdv8::Value"))
+            VariantType::OBJECT => match via.try_to::<godot::obj::Gd<godot::classes::RegEx>>() {
+                Ok(regex) => Ok(Value::RegExp(RegExp::from_godot_regex(&regex))),
+                Err(e) => Err(e),
+            },
+            _ => match TypedArray::from_variant(&via) {
+                Some(v) => Ok(Value::TypedArray(v)),
+                None => Err(godot::prelude::ConvertError::new("type not representable by gdv8::Value")),
+            },
         }
     }
 }
 
-impl std::ops::Add for &Value {
+impl std::ops::Index<&str> for Value {
     type Output = Value;
-
-    fn add(self, rhs: &Value) -> Self::Output {
+    fn index(&self, key: &str) -> &<Self as std::ops::Index<&str>>::Output {
         match self {
-            Value::String(self_str) => match rhs {
-                Value::String(rhs_str) => Value::from(self_str.to_owned() + &rhs_str),
-                Value::Undefined => {
-                    Value::from(self_str.to_owned() + &rhs.coerce_to_string())
-                }
-                Value::Object(_) => {
-                    Value::from(self_str.to_owned() + &rhs.coerce_to_string())
-                }
-
-                Value::Number(rhs_num) => {
-                    let self_coerced = self.coerce_to_number();
-                    if self_coerced.is_nan() {
-                        return Value::from(self_str.to_owned() + &rhs_num.to_string());
-                    } else {
-                        return Value::from(self_coerced + rhs_num);
-                    }
-                }
-                Value::Array(_) => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
+            Value::String(_) => &Value::Undefined,
+            Value::Number(_) => &Value::Undefined,
+            Value::Object(obj) => &obj[key],
+            Value::Undefined => self,
+            Value::Array(arr) => match array_index(key) {
+                Some(i) => &arr[i as usize],
+                None if key == "length" => arr.length(),
+                None => &Value::Undefined,
             },
+            Value::Function(_) => &Value::Undefined,
+            Value::ArrayBuffer(_) => &Value::Undefined,
+            Value::TypedArray(_) => &Value::Undefined,
+            Value::Date(_) => &Value::Undefined,
+            Value::RegExp(_) => &Value::Undefined,
+            Value::Map(_) => &Value::Undefined,
+            Value::Set(_) => &Value::Undefined,
+            Value::Error(_) => &Value::Undefined,
+            Value::Boolean(_) => &Value::Undefined,
+            Value::Null => &Value::Undefined,
+        }
+    }
+}
 
-            Value::Number(self_num) => {
-                let rhs_coerced = rhs.coerce_to_number();
-                if rhs_coerced.is_nan() {
-                    return Value::from(self.coerce_to_string() + &rhs.coerce_to_string());
-                } else {
-                    return Value::from(self_num + rhs_coerced);
-                }
-            }
-            Value::Object(_) => match rhs {
-                Value::String(rhs_str) => Value::from(self.coerce_to_string() + &rhs_str),
-                Value::Number(_) => Value::from(f64::NAN),
-                Value::Object(_) => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
-                Value::Undefined => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
-                Value::Array(_) => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
-            },
-            Value::Undefined => match rhs {
-                Value::String(rhs_str) => Value::from(self.coerce_to_string() + &rhs_str),
-                Value::Number(_) => Value::from(f64::NAN),
-                Value::Undefined => Value::from(f64::NAN),
-                Value::Object(_) => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
-                Value::Array(_) => {
-                    Value::from(self.coerce_to_string() + &rhs.coerce_to_string())
-                }
-            },
-            Value::Array(_) => Value::from(self.coerce_to_string() + &rhs.coerce_to_string()),
+impl std::ops::IndexMut<&str> for Value {
+    /// `undefined` and `null` turn into an empty object first. Arrays take
+    /// index keys like `"0"`, indexing anything else that isn't an object
+    /// panics.
+    fn index_mut(&mut self, key: &str) -> &mut Value {
+        if let Value::Undefined | Value::Null = self {
+            *self = Value::Object(Object::new());
+        }
+
+        match (self, array_index(key)) {
+            (Value::Object(obj), _) => &mut obj[key],
+            (Value::Array(arr), Some(i)) => &mut arr[i as usize],
+            (value, _) => panic!("cannot set property {key} on a {}", value.type_name()),
         }
     }
 }
 
-impl std::ops::Index<&str> for Value {
+impl std::ops::Index<usize> for Value {
     type Output = Value;
-    fn index(&self, key: &str) -> &<Self as std::ops::Index<&str>>::Output {
+
+    /// Array elements, and objects keyed by the index like `{ "0": .. }`.
+    /// Elements that need to be computed, like the characters of a string,
+    /// are only available through `Value::get_index`.
+    fn index(&self, index: usize) -> &Value {
         match self {
-            Value::String(_) => &Value::Undefined,
-            Value::Number(_) => &Value::Undefined,
-            Value::Object(obj) => {
-                if obj.0.contains_key(key) {
-                    &obj.0[key]
-                } else {
-                    &Value::Undefined
-                }
-            }
-            Value::Undefined => self,
-            Value::Array(_) => &Value::Undefined,
+            Value::Array(arr) => &arr[index],
+            Value::Object(obj) => &obj[index.to_string().as_str()],
+            _ => &Value::Undefined,
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for Value {
+    /// `undefined` and `null` turn into an empty array first, assigning past
+    /// the end of an array fills the gap with `undefined`. Panics where
+    /// `Array::set` fails.
+    fn index_mut(&mut self, index: usize) -> &mut Value {
+        if let Value::Undefined | Value::Null = self {
+            *self = Value::Array(Array::default());
+        }
+
+        match self {
+            Value::Array(arr) => &mut arr[index],
+            Value::Object(obj) => &mut obj[index.to_string().as_str()],
+            _ => panic!("cannot set index {index} on a {}", self.type_name()),
         }
     }
 }
@@ -143,56 +182,426 @@ impl Value {
         value.into()
     }
 
-    fn coerce_to_number(&self) -> f64 {
+    pub fn as_local<'a>(&self, scope: &mut HandleScope<'a>) -> v8::Local<'a, v8::Value> {
         match self {
-            Value::String(str) => {
-                let parsing_result = str.parse::<f64>();
-                parsing_result.unwrap_or(f64::NAN)
+            Value::String(v) => v8::String::new(scope, v).unwrap().into(),
+            Value::Number(v) => v8::Number::new(scope, *v).into(),
+            Value::Object(obj) => {
+                let object = v8::Object::new(scope);
+                for (key, value) in obj.iter() {
+                    let key = v8::String::new(scope, key).unwrap().into();
+                    let value = value.as_local(scope);
+                    object.set(scope, key, value);
+                }
+                object.into()
+            }
+            Value::Array(arr) => {
+                let elements: Vec<v8::Local<v8::Value>> =
+                    arr.items.iter().map(|v| v.as_local(scope)).collect();
+                v8::Array::new_with_elements(scope, &elements).into()
+            }
+            Value::Function(f) => f.as_local(scope).into(),
+            Value::ArrayBuffer(v) => v.as_local(scope).into(),
+            Value::TypedArray(v) => v.as_local(scope),
+            Value::Date(v) => match v8::Date::new(scope, v.time_value()) {
+                Some(date) => date.into(),
+                None => v8::undefined(scope).into(),
+            },
+            Value::RegExp(v) => {
+                let source = v8::String::new(scope, &v.source).unwrap().into();
+                let flags = v8::String::new(scope, &v.flags).unwrap().into();
+                match helper::construct(scope, "RegExp", &[source, flags]) {
+                    Some(regexp) => regexp.into(),
+                    None => v8::undefined(scope).into(),
+                }
+            }
+            Value::Map(entries) => {
+                let map = v8::Map::new(scope);
+                for (key, value) in entries {
+                    let key = key.as_local(scope);
+                    let value = value.as_local(scope);
+                    map.set(scope, key, value);
+                }
+                map.into()
+            }
+            Value::Set(values) => {
+                let elements: Vec<v8::Local<v8::Value>> =
+                    values.iter().map(|v| v.as_local(scope)).collect();
+                let values = v8::Array::new_with_elements(scope, &elements).into();
+                match helper::construct(scope, "Set", &[values]) {
+                    Some(set) => set.into(),
+                    None => v8::undefined(scope).into(),
+                }
             }
-            Value::Number(num) => *num,
-            Value::Object(_) => f64::NAN,
-            Value::Undefined => f64::NAN,
-            Value::Array(_) => f64::NAN,
+            Value::Error(v) => helper::error_to_local(scope, v),
+            Value::Boolean(v) => v8::Boolean::new(scope, *v).into(),
+            Value::Null => v8::null(scope).into(),
+            Value::Undefined => v8::undefined(scope).into(),
         }
     }
 
-    fn coerce_to_string(&self) -> String {
-        match self {
-            Value::String(str) => str.to_string(),
-            Value::Number(num) => num.to_string(),
-            Value::Object(_) => String::from("[object Object]"),
-            Value::Undefined => String::from("undefined"),
-            Value::Array(_) => {
-                fn reducer(val: &Value) -> String {
-                    match val {
-                        Value::String(_) => val.coerce_to_string(),
-                        Value::Number(_) => val.coerce_to_string(),
-                        Value::Object(_) => val.coerce_to_string(),
-                        Value::Array(arr) => arr
-                            .0
-                            .iter()
-                            .map(reducer)
-                            .reduce(|prev, cur| prev + ", " + &cur)
-                            .unwrap(),
-                        Value::Undefined => val.coerce_to_string(),
-                    }
+    /// A property read like `value[key]` in JS, including the computed ones
+    /// `Index` can't hand out a reference to, such as a string's `length`.
+    pub fn get(&self, key: &str) -> Value {
+        match (self, key) {
+            (Value::Object(obj), _) => obj[key].clone(),
+            (Value::Array(arr), "length") => Value::Number(arr.len() as f64),
+            (Value::String(v), "length") => Value::Number(v.encode_utf16().count() as f64),
+            (Value::TypedArray(v), "length") => Value::Number(v.len() as f64),
+            (Value::TypedArray(v), "byteLength") => Value::Number(v.byte_length() as f64),
+            (Value::TypedArray(v), "byteOffset") => Value::Number(v.byte_offset() as f64),
+            (Value::ArrayBuffer(v), "byteLength") => Value::Number(v.len() as f64),
+            (Value::Map(v), "size") => Value::Number(v.len() as f64),
+            (Value::Set(v), "size") => Value::Number(v.len() as f64),
+            (Value::RegExp(v), "source") => Value::String(v.source.clone()),
+            (Value::RegExp(v), "flags") => Value::String(v.flags.clone()),
+            (Value::Error(v), "name") => Value::String(v.name.clone()),
+            (Value::Error(v), "message") => Value::String(v.message.clone()),
+            (Value::Error(v), "stack") => v.stack.clone().map_or(Value::Undefined, Value::String),
+            (Value::Array(_) | Value::String(_) | Value::TypedArray(_), _) => {
+                match array_index(key) {
+                    Some(i) => self.get_index(i as usize),
+                    None => Value::Undefined,
                 }
-                reducer(self)
             }
+            _ => Value::Undefined,
         }
     }
 
-    pub fn as_local<'a>(&self, scope: &mut HandleScope<'a>) -> v8::Local<'a, v8::Value> {
+    /// `value[index]` in JS. Strings give the UTF-16 code unit at `index`.
+    pub fn get_index(&self, index: usize) -> Value {
         match self {
-            Value::String(v) => v8::String::new(scope, v).unwrap().into(),
-            Value::Number(_) => todo!(),
-            Value::Object(_) => todo!(),
-            Value::Array(_) => v8::String::new(scope, &self.coerce_to_string()).unwrap().into(),
-            Value::Undefined => v8::undefined(scope).into(),
+            Value::Array(arr) => arr[index].clone(),
+            Value::String(v) => match v.encode_utf16().nth(index) {
+                Some(unit) => Value::String(String::from_utf16_lossy(&[unit])),
+                None => Value::Undefined,
+            },
+            Value::TypedArray(v) => v.get(index).map_or(Value::Undefined, Value::Number),
+            Value::Object(obj) => obj[index.to_string().as_str()].clone(),
+            _ => Value::Undefined,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Array> {
+        match self {
+            Value::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_array_mut(&mut self) -> Option<&mut Array> {
+        match self {
+            Value::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&Object> {
+        match self {
+            Value::Object(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_object_mut(&mut self) -> Option<&mut Object> {
+        match self {
+            Value::Object(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Parses a JSON document without going through V8.
+    pub fn from_json(json: &str) -> Result<Value, Error> {
+        crate::json::parse(json)
+    }
+
+    /// Serializes the value like `JSON.stringify`, `None` for `undefined`
+    /// and functions which have no JSON form.
+    pub fn to_json(&self) -> Option<String> {
+        crate::json::stringify(self)
+    }
+
+    /// Name of the variant as used in type errors.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Number(_) => "number",
+            Value::Object(_) => "object",
+            Value::Array(_) => "array",
+            Value::Function(_) => "function",
+            Value::ArrayBuffer(_) => "ArrayBuffer",
+            Value::TypedArray(v) => v.kind().name(),
+            Value::Date(_) => "Date",
+            Value::RegExp(_) => "RegExp",
+            Value::Map(_) => "Map",
+            Value::Set(_) => "Set",
+            Value::Error(_) => "Error",
+            Value::Boolean(_) => "boolean",
+            Value::Null => "null",
+            Value::Undefined => "undefined",
         }
     }
 }
 
+/// Conversion out of a `Value`, failing with a `TypeError` on mismatch.
+pub trait FromValue: Sized {
+    fn from_value(value: Value) -> Result<Self, Error>;
+}
+
+fn type_error(expected: &str, value: &Value) -> Error {
+    Error::expected(expected, value)
+}
+
+impl FromValue for Value {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        Ok(value)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Number(v) => Ok(v),
+            _ => Err(type_error("number", &value)),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Boolean(v) => Ok(v),
+            _ => Err(type_error("boolean", &value)),
+        }
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        f64::from_value(value).map(|v| v as f32)
+    }
+}
+
+impl FromValue for i32 {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Number(v)
+                if v.fract() == 0.0 && v >= i32::MIN as f64 && v <= i32::MAX as f64 =>
+            {
+                Ok(v as i32)
+            }
+            _ => Err(type_error("integer", &value)),
+        }
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Number(v) if v.fract() == 0.0 && v.is_finite() => Ok(v as i64),
+            _ => Err(type_error("integer", &value)),
+        }
+    }
+}
+
+impl FromValue for u32 {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Number(v) if v.fract() == 0.0 && v >= 0.0 && v <= u32::MAX as f64 => {
+                Ok(v as u32)
+            }
+            _ => Err(type_error("unsigned integer", &value)),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::String(v) => Ok(v),
+            _ => Err(type_error("string", &value)),
+        }
+    }
+}
+
+impl FromValue for Object {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Object(v) => Ok(v),
+            _ => Err(type_error("object", &value)),
+        }
+    }
+}
+
+impl FromValue for Array {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Array(v) => Ok(v),
+            _ => Err(type_error("array", &value)),
+        }
+    }
+}
+
+impl FromValue for Function {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Function(v) => Ok(v),
+            _ => Err(type_error("function", &value)),
+        }
+    }
+}
+
+impl FromValue for ArrayBuffer {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::ArrayBuffer(v) => Ok(v),
+            _ => Err(type_error("ArrayBuffer", &value)),
+        }
+    }
+}
+
+impl FromValue for TypedArray {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::TypedArray(v) => Ok(v),
+            _ => Err(type_error("typed array", &value)),
+        }
+    }
+}
+
+impl FromValue for Date {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Date(v) => Ok(v),
+            _ => Err(type_error("Date", &value)),
+        }
+    }
+}
+
+impl FromValue for RegExp {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::RegExp(v) => Ok(v),
+            _ => Err(type_error("RegExp", &value)),
+        }
+    }
+}
+
+impl FromValue for JsError {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Error(v) => Ok(v),
+            _ => Err(type_error("Error", &value)),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Array(v) => {
+                v.items.into_iter()
+                    .enumerate()
+                    .map(|(i, v)| T::from_value(v).map_err(|e| e.at_index(i)))
+                    .collect()
+            }
+            _ => Err(type_error("array", &value)),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Undefined | Value::Null => Ok(None),
+            v => T::from_value(v).map(Some),
+        }
+    }
+}
+
+/// Conversion into a `Value`, implemented by `#[derive(IntoValue)]`.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+macro_rules! into_value_via_into {
+    ($($t:ty),*) => {
+        $(impl IntoValue for $t {
+            fn into_value(self) -> Value {
+                self.into()
+            }
+        })*
+    };
+}
+
+into_value_via_into!(
+    (), bool, f64, i32, &str, String, Object, Array, Function, ArrayBuffer, TypedArray, Date, RegExp,
+    JsError
+);
+
+impl IntoValue for f32 {
+    fn into_value(self) -> Value {
+        Value::Number(self as f64)
+    }
+}
+
+impl IntoValue for i64 {
+    fn into_value(self) -> Value {
+        Value::Number(self as f64)
+    }
+}
+
+impl IntoValue for u32 {
+    fn into_value(self) -> Value {
+        Value::Number(self as f64)
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_value).collect())
+    }
+}
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    fn into_value(self) -> Value {
+        match self {
+            Some(v) => v.into_value(),
+            None => Value::Undefined,
+        }
+    }
+}
+
+impl<T: IntoValue> IntoValue for Box<T> {
+    fn into_value(self) -> Value {
+        (*self).into_value()
+    }
+}
+
+impl<T: FromValue> FromValue for Box<T> {
+    fn from_value(value: Value) -> Result<Self, Error> {
+        T::from_value(value).map(Box::new)
+    }
+}
+
+impl Into<Value> for () {
+    fn into(self) -> Value {
+        Value::Undefined
+    }
+}
+
+impl Into<Value> for bool {
+    fn into(self) -> Value {
+        Value::Boolean(self)
+    }
+}
+
 impl Into<Value> for f64 {
     fn into(self) -> Value {
         Value::Number(self)
@@ -219,7 +628,7 @@ impl Into<Value> for String {
 
 impl Into<Value> for HashMap<&'static str, Value> {
     fn into(self) -> Value {
-        Value::Object(Object(self))
+        Value::Object(self.into_iter().collect())
     }
 }
 
@@ -229,49 +638,70 @@ impl Into<Value> for Object {
     }
 }
 
-impl Into<Value> for Vec<Value> {
+impl Into<Value> for Function {
     fn into(self) -> Value {
-        Value::Array(Array(self))
+        Value::Function(self)
     }
 }
 
-impl Into<Value> for Array {
+impl Into<Value> for ArrayBuffer {
     fn into(self) -> Value {
-        Value::Array(self)
+        Value::ArrayBuffer(self)
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct Array(Vec<Value>);
+impl Into<Value> for TypedArray {
+    fn into(self) -> Value {
+        Value::TypedArray(self)
+    }
+}
+
+impl Into<Value> for Date {
+    fn into(self) -> Value {
+        Value::Date(self)
+    }
+}
 
-impl Array {
-    pub fn from(arr: &[Value]) -> Value {
-        Value::from(Array(arr.to_vec()))
+impl Into<Value> for RegExp {
+    fn into(self) -> Value {
+        Value::RegExp(self)
     }
-    pub fn new(initial:Vec<Value>) -> Self{
-        Array(initial)
+}
+
+impl Into<Value> for JsError {
+    fn into(self) -> Value {
+        Value::Error(self)
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct Object(HashMap<&'static str, Value>);
+impl<T: Into<Value>> Into<Value> for Vec<T> {
+    fn into(self) -> Value {
+        Value::Array(Array::from_iter(self.into_iter().map(Into::into)))
+    }
+}
+
+impl Into<Value> for Array {
+    fn into(self) -> Value {
+        Value::Array(self)
+    }
+}
 
 pub trait FromValues<T> {
     fn from_values(value: T) -> Value;
 }
 
-impl<const N: usize> FromValues<[(&'static str, Value); N]> for Object {
-    fn from_values(arr: [(&'static str, Value); N]) -> Value {
-        Value::from(Object(HashMap::from_iter(arr)))
+impl<K: Into<String>, const N: usize> FromValues<[(K, Value); N]> for Object {
+    fn from_values(arr: [(K, Value); N]) -> Value {
+        Value::from(Object::from_iter(arr))
     }
 }
 
 #[allow(non_snake_case)]
 pub fn Number(n: impl Into<Value>) -> Value {
-    Value::from(n.into().coerce_to_number())
+    Value::from(n.into().to_number())
 }
 
 #[allow(non_snake_case)]
 pub fn String(s: impl Into<Value>) -> Value {
-    Value::from(s.into().coerce_to_string())
+    Value::from(s.into().to_string())
 }
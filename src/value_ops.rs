@@ -0,0 +1,296 @@
+//! JS operators on `Value`, following the ECMAScript algorithms.
+
+use std::{
+    cmp::Ordering,
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub},
+};
+
+use crate::{Hint, Value};
+
+/// ToNumeric, the operand conversion of the arithmetic operators.
+fn to_numeric(value: &Value) -> f64 {
+    value.to_primitive(Hint::Number).to_number()
+}
+
+/// The right operand of the shifts, only its low 5 bits count.
+fn shift_count(value: &Value) -> u32 {
+    value.to_uint32() & 31
+}
+
+fn is_object(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Object(_)
+            | Value::Array(_)
+            | Value::Function(_)
+            | Value::ArrayBuffer(_)
+            | Value::TypedArray(_)
+            | Value::Date(_)
+            | Value::RegExp(_)
+            | Value::Map(_)
+            | Value::Set(_)
+            | Value::Error(_)
+    )
+}
+
+impl Value {
+    /// The result of JS `typeof`.
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Number(_) => "number",
+            Value::Boolean(_) => "boolean",
+            Value::Undefined => "undefined",
+            Value::Function(_) => "function",
+            Value::Object(_)
+            | Value::Array(_)
+            | Value::ArrayBuffer(_)
+            | Value::TypedArray(_)
+            | Value::Date(_)
+            | Value::RegExp(_)
+            | Value::Map(_)
+            | Value::Set(_)
+            | Value::Error(_)
+            | Value::Null => "object",
+        }
+    }
+
+    /// ToBoolean, what `if (value)` and `!value` test.
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Value::String(v) => !v.is_empty(),
+            Value::Number(v) => !(*v == 0.0 || v.is_nan()),
+            Value::Boolean(v) => *v,
+            Value::Object(_)
+            | Value::Array(_)
+            | Value::Function(_)
+            | Value::ArrayBuffer(_)
+            | Value::TypedArray(_)
+            | Value::Date(_)
+            | Value::RegExp(_)
+            | Value::Map(_)
+            | Value::Set(_)
+            | Value::Error(_) => true,
+            Value::Null | Value::Undefined => false,
+        }
+    }
+
+    /// `===`. A `Value` holds its own copy of an object, so objects and arrays
+    /// are only strictly equal to themselves, not to an equal looking clone.
+    /// Functions and buffers are references and equal when they are the same
+    /// JS function or view the same memory.
+    pub fn strict_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+            (Value::Null, Value::Null) | (Value::Undefined, Value::Undefined) => true,
+            (Value::Object(_), Value::Object(_)) | (Value::Array(_), Value::Array(_)) => {
+                std::ptr::eq(self, other)
+            }
+            (Value::Date(_), Value::Date(_))
+            | (Value::RegExp(_), Value::RegExp(_))
+            | (Value::Map(_), Value::Map(_))
+            | (Value::Set(_), Value::Set(_))
+            | (Value::Error(_), Value::Error(_)) => std::ptr::eq(self, other),
+            (Value::Function(a), Value::Function(b)) => a.same(b),
+            (Value::ArrayBuffer(a), Value::ArrayBuffer(b)) => a.same(b),
+            (Value::TypedArray(a), Value::TypedArray(b)) => a.same(b),
+            _ => false,
+        }
+    }
+
+    /// `==`, IsLooselyEqual.
+    pub fn loose_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Null | Value::Undefined, Value::Null | Value::Undefined) => true,
+            (Value::Null | Value::Undefined, _) | (_, Value::Null | Value::Undefined) => false,
+            (Value::Number(a), Value::String(_)) => *a == other.to_number(),
+            (Value::String(_), Value::Number(b)) => self.to_number() == *b,
+            (Value::Boolean(_), _) => Value::Number(self.to_number()).loose_eq(other),
+            (_, Value::Boolean(_)) => self.loose_eq(&Value::Number(other.to_number())),
+            (a, b) if is_object(a) && !is_object(b) => a.to_primitive(Hint::Default).loose_eq(b),
+            (a, b) if !is_object(a) && is_object(b) => a.loose_eq(&b.to_primitive(Hint::Default)),
+            (a, b) => a.strict_eq(b),
+        }
+    }
+
+    /// `>>>`, which has no Rust operator.
+    pub fn unsigned_shr(&self, rhs: &Value) -> Value {
+        Value::Number((self.to_uint32() >> shift_count(rhs)) as f64)
+    }
+
+    /// `~`, the same as `!value`. Logical not is `!value.to_boolean()`.
+    pub fn bitwise_not(&self) -> Value {
+        !self
+    }
+
+    /// The abstract relational comparison `self < other`, `None` when a side
+    /// converts to `NaN`. Strings compare by UTF-16 code units, mixed
+    /// operands as numbers, so `"10" < 9` is false while `"10" < "9"` is true.
+    /// `a > b` is `b.less_than(a)`, `a >= b` is `a.less_than(b) == Some(false)`.
+    pub fn less_than(&self, other: &Value) -> Option<bool> {
+        match (
+            self.to_primitive(Hint::Number),
+            other.to_primitive(Hint::Number),
+        ) {
+            (Value::String(a), Value::String(b)) => Some(a.encode_utf16().lt(b.encode_utf16())),
+            (a, b) => {
+                let (a, b) = (a.to_number(), b.to_number());
+                match a.is_nan() || b.is_nan() {
+                    true => None,
+                    false => Some(a < b),
+                }
+            }
+        }
+    }
+}
+
+/// Structural for objects, arrays, buffers and the builtin objects, `===` for
+/// everything else.
+/// Unlike `strict_eq` two clones of an object compare equal.
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Object(a), Value::Object(b)) => {
+                a.len() == b.len() && a.iter().all(|(k, v)| b.get(k) == Some(v))
+            }
+            (Value::Array(a), Value::Array(b)) => a.items == b.items,
+            (Value::ArrayBuffer(a), Value::ArrayBuffer(b)) => a.to_vec() == b.to_vec(),
+            (Value::TypedArray(a), Value::TypedArray(b)) => {
+                a.kind() == b.kind() && a.to_bytes() == b.to_bytes()
+            }
+            (Value::Date(a), Value::Date(b)) => a == b,
+            (Value::RegExp(a), Value::RegExp(b)) => a == b,
+            (Value::Map(a), Value::Map(b)) => a == b,
+            (Value::Set(a), Value::Set(b)) => a == b,
+            (Value::Error(a), Value::Error(b)) => a == b,
+            (a, b) => a.strict_eq(b),
+        }
+    }
+}
+
+/// `<` and `>` follow the abstract relational comparison, see
+/// `Value::less_than`. `None` when a side converts to `NaN` or the values are
+/// neither ordered nor equal, so `"1" <= 1` is false here while JS says true.
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        match (self.less_than(other)?, other.less_than(self)?) {
+            (true, _) => Some(Ordering::Less),
+            (_, true) => Some(Ordering::Greater),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl Add for &Value {
+    type Output = Value;
+
+    /// String concatenation if either side is a string after ToPrimitive,
+    /// numeric addition otherwise.
+    fn add(self, rhs: &Value) -> Value {
+        match (
+            self.to_primitive(Hint::Default),
+            rhs.to_primitive(Hint::Default),
+        ) {
+            (a @ Value::String(_), b) | (a, b @ Value::String(_)) => {
+                Value::String(a.to_string() + &b.to_string())
+            }
+            (a, b) => Value::Number(a.to_number() + b.to_number()),
+        }
+    }
+}
+
+macro_rules! numeric_op {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(impl $trait for &Value {
+            type Output = Value;
+
+            fn $method(self, rhs: &Value) -> Value {
+                Value::Number(to_numeric(self) $op to_numeric(rhs))
+            }
+        })*
+    };
+}
+
+// f64 `%` truncates like JS `%`, the result takes the sign of the dividend
+numeric_op!(Sub sub -, Mul mul *, Div div /, Rem rem %);
+
+macro_rules! int32_op {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(impl $trait for &Value {
+            type Output = Value;
+
+            fn $method(self, rhs: &Value) -> Value {
+                Value::Number((self.to_int32() $op rhs.to_int32()) as f64)
+            }
+        })*
+    };
+}
+
+int32_op!(BitAnd bitand &, BitOr bitor |, BitXor bitxor ^);
+
+impl Shl for &Value {
+    type Output = Value;
+
+    fn shl(self, rhs: &Value) -> Value {
+        Value::Number(self.to_int32().wrapping_shl(shift_count(rhs)) as f64)
+    }
+}
+
+impl Shr for &Value {
+    type Output = Value;
+
+    /// `>>`, sign propagating. See `Value::unsigned_shr` for `>>>`.
+    fn shr(self, rhs: &Value) -> Value {
+        Value::Number((self.to_int32() >> shift_count(rhs)) as f64)
+    }
+}
+
+impl Neg for &Value {
+    type Output = Value;
+
+    fn neg(self) -> Value {
+        Value::Number(-to_numeric(self))
+    }
+}
+
+impl Not for &Value {
+    type Output = Value;
+
+    /// `~`, like `!` on Rust integers. Logical not is `!value.to_boolean()`.
+    fn not(self) -> Value {
+        Value::Number(!self.to_int32() as f64)
+    }
+}
+
+macro_rules! owned_op {
+    ($($trait:ident $method:ident),*) => {
+        $(impl $trait for Value {
+            type Output = Value;
+
+            fn $method(self, rhs: Value) -> Value {
+                (&self).$method(&rhs)
+            }
+        })*
+    };
+}
+
+owned_op!(Add add, Sub sub, Mul mul, Div div, Rem rem, BitAnd bitand, BitOr bitor, BitXor bitxor, Shl shl, Shr shr);
+
+impl Neg for Value {
+    type Output = Value;
+
+    fn neg(self) -> Value {
+        -&self
+    }
+}
+
+impl Not for Value {
+    type Output = Value;
+
+    fn not(self) -> Value {
+        !&self
+    }
+}
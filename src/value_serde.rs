@@ -0,0 +1,693 @@
+//! serde support for `Value`, enabled by the `serde` feature.
+//!
+//! `to_value` and `from_value` go through `Value`, so anything `Serialize`
+//! can be handed to JS and anything `Deserialize` read back without a
+//! hand-written conversion.
+
+use std::fmt;
+
+use serde::{
+    de::{
+        self, value::StringDeserializer, DeserializeOwned, DeserializeSeed, IntoDeserializer,
+        MapAccess, SeqAccess, VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{self, Serialize},
+    Deserialize, Deserializer, Serializer,
+};
+
+use crate::{
+    error::{Error, ValueError},
+    Array, JsError, Object, Value,
+};
+
+/// Serializes `value` into a `Value`.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, Error> {
+    value.serialize(ValueSerializer)
+}
+
+/// Deserializes a `T` out of `value`, errors carry the path to the mismatch.
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+    T::deserialize(value)
+}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::InvalidValue(ValueError::new(msg.to_string()))
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::InvalidValue(ValueError::new(msg.to_string()))
+    }
+}
+
+/// Integral numbers in the safe integer range, serialized as integers the
+/// way `JSON.stringify` would print them. `-0` stays a float, as an integer
+/// it would lose its sign.
+fn as_integer(number: f64) -> Option<i64> {
+    let negative_zero = number == 0.0 && number.is_sign_negative();
+    match number.fract() == 0.0 && number.abs() <= 9007199254740991.0 && !negative_zero {
+        true => Some(number as i64),
+        false => None,
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::String(v) => serializer.serialize_str(v),
+            Value::Number(v) => match as_integer(*v) {
+                Some(v) => serializer.serialize_i64(v),
+                None => serializer.serialize_f64(*v),
+            },
+            Value::Boolean(v) => serializer.serialize_bool(*v),
+            Value::Object(v) => serializer.collect_map(v.iter()),
+            Value::Array(v) => serializer.collect_seq(v.items.iter()),
+            Value::Null | Value::Undefined => serializer.serialize_none(),
+            Value::Function(_) => Err(ser::Error::custom("functions can't be serialized")),
+            Value::ArrayBuffer(v) => serializer.serialize_bytes(&v.to_vec()),
+            Value::TypedArray(v) => serializer.collect_seq(v.iter().map(Value::Number)),
+            Value::Date(v) => match v.to_iso_string() {
+                Some(iso) => serializer.serialize_str(&iso),
+                None => serializer.serialize_none(),
+            },
+            Value::RegExp(v) => serializer.collect_str(v),
+            Value::Map(entries) => serializer.collect_map(entries.iter().map(|(k, v)| (k, v))),
+            Value::Set(values) => serializer.collect_seq(values),
+            Value::Error(v) => serializer.collect_map(error_object(v).iter()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a JS value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Number(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Value::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Array(Array::new(values)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut object = Object::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeVariant<SerializeArray>;
+    type SerializeMap = SerializeObject;
+    type SerializeStruct = SerializeObject;
+    type SerializeStructVariant = SerializeVariant<SerializeObject>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::Number(v as f64))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::Number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        let values = v.iter().map(|b| Value::Number(*b as f64)).collect();
+        Ok(Value::Array(Array::new(values)))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let mut object = Object::new();
+        object.insert(variant, to_value(value).map_err(|e| e.at_key(variant))?);
+        Ok(Value::Object(object))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeArray>, Error> {
+        Ok(SerializeVariant(variant, self.serialize_seq(Some(len))?))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeObject, Error> {
+        Ok(SerializeObject(Object::new(), None))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeObject, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeObject>, Error> {
+        Ok(SerializeVariant(variant, self.serialize_map(Some(len))?))
+    }
+}
+
+struct SerializeArray(Vec<Value>);
+
+impl SerializeArray {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let index = self.0.len();
+        self.0.push(to_value(value).map_err(|e| e.at_index(index))?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Array(Array::new(self.0)))
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// An object under construction and the key waiting for its value.
+struct SerializeObject(Object, Option<String>);
+
+impl ser::SerializeMap for SerializeObject {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = match to_value(key)? {
+            Value::String(v) => v,
+            Value::Number(v) => Value::Number(v).to_string(),
+            other => return Err(Error::expected("string key", &other)),
+        };
+        self.1 = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .1
+            .take()
+            .expect("serialize_value called before serialize_key");
+        let value = to_value(value).map_err(|e| e.at_key(&key))?;
+        self.0.insert(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.0))
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.0
+            .insert(key, to_value(value).map_err(|e| e.at_key(key))?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.0))
+    }
+}
+
+/// Data of an enum variant, wrapped as `{ "Variant": data }` once done.
+struct SerializeVariant<T>(&'static str, T);
+
+impl<T> SerializeVariant<T> {
+    fn wrap(variant: &'static str, value: Value) -> Result<Value, Error> {
+        let mut object = Object::new();
+        object.insert(variant, value);
+        Ok(Value::Object(object))
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeArray> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let variant = self.0;
+        self.1.push(value).map_err(|e| e.at_key(variant))
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Self::wrap(self.0, ser::SerializeSeq::end(self.1)?)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeObject> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        let variant = self.0;
+        ser::SerializeStruct::serialize_field(&mut self.1, key, value)
+            .map_err(|e| e.at_key(variant))
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Self::wrap(self.0, Value::Object(self.1 .0))
+    }
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::String(v) => visitor.visit_string(v),
+            Value::Number(v) => match as_integer(v) {
+                Some(v) => visitor.visit_i64(v),
+                None => visitor.visit_f64(v),
+            },
+            Value::Boolean(v) => visitor.visit_bool(v),
+            Value::Object(v) => visitor.visit_map(ObjectAccess {
+                entries: v.into_iter(),
+                key: None,
+                value: None,
+            }),
+            Value::Array(v) => visitor.visit_seq(ArrayAccess {
+                values: v.items.into_iter().enumerate(),
+            }),
+            Value::Null | Value::Undefined => visitor.visit_unit(),
+            v @ Value::Function(_) => Err(Error::expected("serializable value", &v)),
+            Value::ArrayBuffer(v) => visitor.visit_byte_buf(v.to_vec()),
+            Value::TypedArray(v) => visitor.visit_seq(ArrayAccess {
+                values: v
+                    .iter()
+                    .map(Value::Number)
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .enumerate(),
+            }),
+            Value::Date(v) => match v.to_iso_string() {
+                Some(iso) => visitor.visit_string(iso),
+                None => visitor.visit_unit(),
+            },
+            Value::RegExp(v) => visitor.visit_string(v.to_string()),
+            Value::Map(entries) => visitor.visit_map(EntriesAccess {
+                entries: entries.into_iter(),
+                value: None,
+            }),
+            Value::Set(values) => visitor.visit_seq(ArrayAccess {
+                values: values.into_iter().enumerate(),
+            }),
+            Value::Error(v) => Value::Object(error_object(&v)).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Null | Value::Undefined => visitor.visit_none(),
+            v => visitor.visit_some(v),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self {
+            Value::String(v) => visitor.visit_enum(v.into_deserializer()),
+            Value::Object(v) if v.len() == 1 => {
+                let (variant, value) = v.into_iter().next().unwrap();
+                visitor
+                    .visit_enum(VariantDeserializer {
+                        variant: variant.clone(),
+                        value,
+                    })
+                    .map_err(|e| e.at_key(&variant))
+            }
+            other => Err(Error::expected(&format!("a variant of {name}"), &other)),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+struct ArrayAccess {
+    values: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.values.next() {
+            Some((i, v)) => seed.deserialize(v).map(Some).map_err(|e| e.at_index(i)),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct ObjectAccess {
+    entries: crate::object::IntoIter,
+    key: Option<String>,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.key = Some(key.clone());
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let key = self.key.take().unwrap_or_default();
+        let value = self.value.take().unwrap_or(Value::Undefined);
+        seed.deserialize(value).map_err(|e| e.at_key(&key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// Map entries, keys are deserialized from any value.
+struct EntriesAccess {
+    entries: std::vec::IntoIter<(Value, Value)>,
+    value: Option<Value>,
+}
+
+impl<'de> MapAccess<'de> for EntriesAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(self.value.take().unwrap_or(Value::Undefined))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// An error as `{ name, message, stack }`.
+fn error_object(error: &JsError) -> Object {
+    let mut object = Object::new();
+    object.insert("name", Value::String(error.name.clone()));
+    object.insert("message", Value::String(error.message.clone()));
+    if let Some(stack) = &error.stack {
+        object.insert("stack", Value::String(stack.clone()));
+    }
+    object
+}
+
+/// `{ "Variant": data }`, the externally tagged form `to_value` produces.
+struct VariantDeserializer {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantDeserializer {
+    type Error = Error;
+    type Variant = Value;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Value), Error> {
+        let variant: StringDeserializer<Error> = self.variant.into_deserializer();
+        Ok((seed.deserialize(variant)?, self.value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Value {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self {
+            Value::Null | Value::Undefined => Ok(()),
+            other => Err(Error::expected("unit variant", &other)),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+}
@@ -0,0 +1,200 @@
+use godot::{
+    builtin::{Dictionary, GString, PackedByteArray, Variant, VariantArray, VariantType},
+    classes::RegEx,
+    meta::ToGodot,
+    obj::Gd,
+};
+use rusty_v8 as v8;
+
+use crate::{
+    error::Error,
+    helper::{self, AsValue},
+    proxy, ArrayBuffer, RegExp, TypedArray, Value,
+};
+
+/// Cyclic JS objects have no Dictionary form, they fail at this depth.
+const MAX_DEPTH: usize = 512;
+
+fn too_deep() -> Error {
+    Error::TypeError("value nested too deeply, is it cyclic?".to_string())
+}
+
+/// Builds the JS counterpart of a Godot variant directly, Dictionaries
+/// become objects in their key order, Arrays become arrays and packed arrays
+/// typed arrays. Types without a JS counterpart are passed as their string
+/// form.
+pub(crate) fn to_local<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    variant: &Variant,
+    depth: usize,
+) -> Result<v8::Local<'s, v8::Value>, Error> {
+    if depth == MAX_DEPTH {
+        return Err(too_deep());
+    }
+
+    let value = match variant.get_type() {
+        VariantType::NIL => v8::null(scope).into(),
+        VariantType::BOOL => v8::Boolean::new(scope, variant.to::<bool>()).into(),
+        VariantType::INT => v8::Number::new(scope, variant.to::<i64>() as f64).into(),
+        VariantType::FLOAT => v8::Number::new(scope, variant.to::<f64>()).into(),
+        VariantType::STRING | VariantType::STRING_NAME => {
+            v8::String::new(scope, &variant.to_string()).unwrap().into()
+        }
+        VariantType::DICTIONARY => {
+            let object = v8::Object::new(scope);
+            for (key, value) in variant.to::<Dictionary>().iter_shared() {
+                let key = v8::String::new(scope, &key.to_string()).unwrap();
+                let value = to_local(scope, &value, depth + 1)?;
+                object.set(scope, key.into(), value);
+            }
+            object.into()
+        }
+        VariantType::ARRAY => {
+            let array = variant.to::<VariantArray>();
+            let elements = array
+                .iter_shared()
+                .map(|v| to_local(scope, &v, depth + 1))
+                .collect::<Result<Vec<_>, Error>>()?;
+            v8::Array::new_with_elements(scope, &elements).into()
+        }
+        VariantType::OBJECT => match variant.try_to::<Gd<RegEx>>() {
+            Ok(regex) => Value::RegExp(RegExp::from_godot_regex(&regex)).as_local(scope),
+            Err(_) => v8::String::new(scope, &variant.stringify().to_string())
+                .unwrap()
+                .into(),
+        },
+        _ => match TypedArray::from_variant(variant) {
+            Some(array) => array.as_local(scope),
+            None => v8::String::new(scope, &variant.stringify().to_string())
+                .unwrap()
+                .into(),
+        },
+    };
+
+    return Ok(value);
+}
+
+/// Reads a JS value back into a Godot variant. Integral numbers in the safe
+/// integer range become `int`, other numbers `float`, and objects
+/// Dictionaries keyed by their own enumerable properties.
+pub(crate) fn from_local(
+    scope: &mut v8::HandleScope<'_>,
+    value: v8::Local<v8::Value>,
+    depth: usize,
+) -> Result<Variant, Error> {
+    if depth == MAX_DEPTH {
+        return Err(too_deep());
+    }
+
+    if value.is_null_or_undefined() {
+        return Ok(Variant::nil());
+    }
+
+    if value.is_boolean() {
+        return Ok(Variant::from(value.is_true()));
+    }
+
+    if value.is_number() {
+        let number = value.number_value(scope).unwrap_or(f64::NAN);
+        return match number.fract() == 0.0 && number.abs() <= 9007199254740991.0 {
+            true => Ok(Variant::from(number as i64)),
+            false => Ok(Variant::from(number)),
+        };
+    }
+
+    if value.is_string() {
+        return Ok(Variant::from(GString::from(
+            value.to_rust_string_lossy(scope),
+        )));
+    }
+
+    // a view of a Godot collection stands for the collection, not a copy
+    if let Some(collection) = proxy::collection(scope, value) {
+        return Ok(collection);
+    }
+
+    if let Some(array) = TypedArray::from_local(scope, value) {
+        return Ok(array.to_variant());
+    }
+
+    if let Some(buffer) = ArrayBuffer::from_local(value) {
+        return Ok(Variant::from(PackedByteArray::from(
+            buffer.to_vec().as_slice(),
+        )));
+    }
+
+    if let Ok(array) = v8::Local::<v8::Array>::try_from(value) {
+        let mut result = VariantArray::new();
+        for i in 0..array.length() {
+            let item = match array.get_index(scope, i) {
+                Some(v) => from_local(scope, v, depth + 1)?,
+                None => Variant::nil(),
+            };
+            result.push(item);
+        }
+        return Ok(Variant::from(result));
+    }
+
+    if value.is_function() {
+        return Ok(Variant::nil());
+    }
+
+    // unix time, RegEx and `{ name, message, stack }` like `Value` gives them
+    if value.is_date() || value.is_reg_exp() || value.is_native_error() {
+        return Ok(value.as_value(scope).to_godot());
+    }
+
+    if let Ok(map) = v8::Local::<v8::Map>::try_from(value) {
+        let mut result = Dictionary::new();
+        let flat = map.as_array(scope);
+        for i in (0..flat.length()).step_by(2) {
+            let (key, item) = match (flat.get_index(scope, i), flat.get_index(scope, i + 1)) {
+                (Some(key), Some(item)) => (key, item),
+                _ => continue,
+            };
+            let key = from_local(scope, key, depth + 1)?;
+            let item = from_local(scope, item, depth + 1)?;
+            result.set(key, item);
+        }
+        return Ok(Variant::from(result));
+    }
+
+    if value.is_set() {
+        let mut result = VariantArray::new();
+        if let Some(array) = helper::set_values(scope, value) {
+            for i in 0..array.length() {
+                let item = match array.get_index(scope, i) {
+                    Some(v) => from_local(scope, v, depth + 1)?,
+                    None => Variant::nil(),
+                };
+                result.push(item);
+            }
+        }
+        return Ok(Variant::from(result));
+    }
+
+    if let Ok(object) = v8::Local::<v8::Object>::try_from(value) {
+        let mut result = Dictionary::new();
+        let keys = match object.get_own_property_names(scope) {
+            Some(v) => v,
+            None => return Ok(Variant::from(result)),
+        };
+
+        for i in 0..keys.length() {
+            let key = match keys.get_index(scope, i) {
+                Some(v) => v,
+                None => continue,
+            };
+            let item = match object.get(scope, key) {
+                Some(v) => from_local(scope, v, depth + 1)?,
+                None => Variant::nil(),
+            };
+            result.set(GString::from(key.to_rust_string_lossy(scope)), item);
+        }
+        return Ok(Variant::from(result));
+    }
+
+    return Ok(Variant::from(GString::from(
+        value.to_rust_string_lossy(scope),
+    )));
+}
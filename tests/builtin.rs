@@ -0,0 +1,83 @@
+//! `Date` formatting against the results V8 gives and `RegExp` flags
+//! through PCRE patterns.
+
+use gdv8::{Date, RegExp, Value};
+
+#[test]
+fn iso_string() {
+    let cases: &[(f64, &str)] = &[
+        (0.0, "1970-01-01T00:00:00.000Z"),
+        (-1.0, "1969-12-31T23:59:59.999Z"),
+        (1.0, "1970-01-01T00:00:00.001Z"),
+        (-0.5, "1970-01-01T00:00:00.000Z"),
+        (1e12 + 0.9, "2001-09-09T01:46:40.000Z"),
+        // leap days, including the century rules
+        (951782400000.0, "2000-02-29T00:00:00.000Z"),
+        (951868799999.0, "2000-02-29T23:59:59.999Z"),
+        (4107542400000.0, "2100-03-01T00:00:00.000Z"),
+        (-2203891200000.0, "1900-03-01T00:00:00.000Z"),
+        // years outside 0..=9999 get a sign and six digits
+        (-62167219200000.0, "0000-01-01T00:00:00.000Z"),
+        (-62167219200001.0, "-000001-12-31T23:59:59.999Z"),
+        (253402300799999.0, "9999-12-31T23:59:59.999Z"),
+        (253402300800000.0, "+010000-01-01T00:00:00.000Z"),
+        (8.64e15, "+275760-09-13T00:00:00.000Z"),
+        (-8.64e15, "-271821-04-20T00:00:00.000Z"),
+    ];
+
+    for (time, expected) in cases {
+        let date = Date::from_time_value(*time);
+        assert_eq!(date.to_iso_string().as_deref(), Some(*expected), "{time}");
+    }
+}
+
+#[test]
+fn invalid_dates() {
+    for time in [f64::NAN, f64::INFINITY, 8.64e15 + 1.0, -8.64e15 - 1.0] {
+        let date = Date::from_time_value(time);
+        assert!(!date.is_valid(), "{time}");
+        assert_eq!(date.to_iso_string(), None, "{time}");
+        assert_eq!(Value::Date(date).to_string(), "Invalid Date");
+    }
+}
+
+#[test]
+fn date_string() {
+    let cases: &[(f64, &str)] = &[
+        (0.0, "Thu Jan 01 1970 00:00:00"),
+        (951782400000.0, "Tue Feb 29 2000 00:00:00"),
+        (-62167219200001.0, "Fri Dec 31 -0001 23:59:59"),
+        (8.64e15, "Sat Sep 13 275760 00:00:00"),
+    ];
+
+    for (time, expected) in cases {
+        let date = Date::from_time_value(*time);
+        assert_eq!(
+            Value::Date(date).to_string(),
+            format!("{expected} GMT+0000 (Coordinated Universal Time)")
+        );
+    }
+}
+
+#[test]
+fn regexp_flags_round_trip() {
+    for flags in ["", "i", "m", "s", "im", "ims"] {
+        let regexp = RegExp::new("a(b)?c", flags);
+        assert_eq!(RegExp::from_pcre_pattern(&regexp.to_pcre_pattern()), regexp);
+    }
+
+    assert_eq!(RegExp::new("a", "si").to_pcre_pattern(), "(?si)a");
+    // flags without a PCRE modifier are dropped
+    assert_eq!(RegExp::new("a", "gimuy").to_pcre_pattern(), "(?im)a");
+    assert_eq!(
+        RegExp::from_pcre_pattern("(?smi)a"),
+        RegExp::new("a", "ims")
+    );
+}
+
+#[test]
+fn regexp_other_modifiers_stay() {
+    for pattern in ["(?x)a", "(?i-m)a", "(?)a", "(?:a)", "a(?i)"] {
+        assert_eq!(RegExp::from_pcre_pattern(pattern), RegExp::new(pattern, ""));
+    }
+}
@@ -0,0 +1,119 @@
+//! ToString, ToNumber, ToInt32 and ToUint32 against the results V8 gives.
+
+use gdv8::Value;
+
+#[test]
+fn number_to_string() {
+    let cases: &[(f64, &str)] = &[
+        (0.0, "0"),
+        (-0.0, "0"),
+        (1.0, "1"),
+        (-1.0, "-1"),
+        (1.5, "1.5"),
+        (0.1, "0.1"),
+        (0.1 + 0.2, "0.30000000000000004"),
+        (100.0, "100"),
+        (123456789012.0, "123456789012"),
+        (1e20, "100000000000000000000"),
+        (1e21, "1e+21"),
+        (1.5e21, "1.5e+21"),
+        (123e-20, "1.23e-18"),
+        (1e-6, "0.000001"),
+        (0.000001234, "0.000001234"),
+        (1e-7, "1e-7"),
+        (1.2345e-7, "1.2345e-7"),
+        (-1e-7, "-1e-7"),
+        (5e-324, "5e-324"),
+        (f64::MAX, "1.7976931348623157e+308"),
+        (9007199254740992.0, "9007199254740992"),
+        (f64::NAN, "NaN"),
+        (f64::INFINITY, "Infinity"),
+        (f64::NEG_INFINITY, "-Infinity"),
+    ];
+
+    for (number, expected) in cases {
+        assert_eq!(Value::Number(*number).to_string(), *expected, "{number:e}");
+    }
+}
+
+#[test]
+fn string_to_number() {
+    let cases: &[(&str, f64)] = &[
+        ("", 0.0),
+        ("  ", 0.0),
+        (" 12 ", 12.0),
+        ("\t\n42 ", 42.0),
+        ("\u{feff}7\u{a0}", 7.0),
+        ("1.", 1.0),
+        (".5", 0.5),
+        ("1e3", 1000.0),
+        ("1E-3", 0.001),
+        ("+1.5", 1.5),
+        ("-.5", -0.5),
+        ("00012", 12.0),
+        ("0x1F", 31.0),
+        ("0X1f", 31.0),
+        ("0b101", 5.0),
+        ("0o17", 15.0),
+        ("Infinity", f64::INFINITY),
+        ("-Infinity", f64::NEG_INFINITY),
+        ("+Infinity", f64::INFINITY),
+        ("1e400", f64::INFINITY),
+        ("0.1e-400", 0.0),
+    ];
+
+    for (source, expected) in cases {
+        assert_eq!(
+            Value::String(source.to_string()).to_number(),
+            *expected,
+            "{source:?}"
+        );
+    }
+
+    let invalid = [
+        ".", "1e", "-0x10", "0x", "0b2", "inf", "nan", "NaN", "1_000", "12px",
+    ];
+    for source in invalid {
+        assert!(
+            Value::String(source.to_string()).to_number().is_nan(),
+            "{source:?}"
+        );
+    }
+
+    assert!(Value::String("-0".to_string())
+        .to_number()
+        .is_sign_negative());
+}
+
+#[test]
+fn to_int32_and_uint32() {
+    let cases: &[(f64, i32, u32)] = &[
+        (0.0, 0, 0),
+        (-0.0, 0, 0),
+        (1.9, 1, 1),
+        (-1.9, -1, 4294967295),
+        (-1.0, -1, 4294967295),
+        (2147483647.0, 2147483647, 2147483647),
+        (2147483648.0, -2147483648, 2147483648),
+        (4294967295.0, -1, 4294967295),
+        (4294967296.0, 0, 0),
+        (4294967301.0, 5, 5),
+        (-2147483649.0, 2147483647, 2147483647),
+        (1e21, -559939584, 3735027712),
+        (9007199254740994.0, 2, 2),
+        (f64::NAN, 0, 0),
+        (f64::INFINITY, 0, 0),
+        (f64::NEG_INFINITY, 0, 0),
+    ];
+
+    for (number, int32, uint32) in cases {
+        let value = Value::Number(*number);
+        assert_eq!(value.to_int32(), *int32, "{number:e}");
+        assert_eq!(value.to_uint32(), *uint32, "{number:e}");
+    }
+
+    // the operand goes through ToNumber first
+    assert_eq!(Value::String(" 0x10 ".to_string()).to_int32(), 16);
+    assert_eq!(Value::Boolean(true).to_uint32(), 1);
+    assert_eq!(Value::Undefined.to_int32(), 0);
+}
@@ -0,0 +1,190 @@
+//! `#[derive(IntoValue, FromValue)]` round trips, attributes and error paths.
+
+use gdv8::{FromValue, IntoValue, Object, Value};
+
+#[derive(IntoValue, FromValue, Debug, PartialEq)]
+#[gdv8(rename_all = "camelCase")]
+struct Player {
+    player_name: String,
+    #[gdv8(rename = "hp")]
+    health: f64,
+    #[gdv8(default)]
+    score: f64,
+    #[gdv8(default = "default_level")]
+    level: f64,
+    nickname: Option<String>,
+    #[gdv8(skip)]
+    cached: Option<f64>,
+}
+
+fn default_level() -> f64 {
+    1.0
+}
+
+#[derive(IntoValue, FromValue, Debug, PartialEq)]
+struct Team {
+    players: Vec<Player>,
+}
+
+#[derive(IntoValue, FromValue, Debug, PartialEq)]
+struct Position {
+    x: f64,
+    y: f64,
+}
+
+#[derive(IntoValue, FromValue, Debug, PartialEq)]
+#[gdv8(tag = "type")]
+enum Shape {
+    Point,
+    Circle { radius: f64 },
+    Moved(Position),
+}
+
+#[derive(IntoValue, FromValue, Debug, PartialEq)]
+enum Command {
+    Stop,
+    Say(String),
+    Go { x: f64 },
+}
+
+#[derive(IntoValue, Debug)]
+#[gdv8(tag = "type")]
+enum Tagged {
+    Number(f64),
+}
+
+fn player(name: &str) -> Player {
+    Player {
+        player_name: name.to_string(),
+        health: 10.0,
+        score: 3.0,
+        level: 2.0,
+        nickname: None,
+        cached: None,
+    }
+}
+
+fn shapes() -> Vec<Shape> {
+    vec![
+        Shape::Point,
+        Shape::Circle { radius: 2.0 },
+        Shape::Moved(Position { x: 1.0, y: 2.0 }),
+    ]
+}
+
+fn commands() -> Vec<Command> {
+    vec![
+        Command::Stop,
+        Command::Say("hi".to_string()),
+        Command::Go { x: 3.0 },
+    ]
+}
+
+fn object(entries: Vec<(&'static str, Value)>) -> Value {
+    let mut object = Object::new();
+    for (key, value) in entries {
+        object.insert(key, value);
+    }
+    Value::Object(object)
+}
+
+fn take(value: &mut Value, key: &str) -> Option<Value> {
+    match value {
+        Value::Object(object) => object.remove(key),
+        _ => None,
+    }
+}
+
+#[test]
+fn struct_round_trip() {
+    let value = player("ann").into_value();
+    assert_eq!(Player::from_value(value).unwrap(), player("ann"));
+}
+
+#[test]
+fn struct_keys() {
+    let mut value = Player {
+        cached: Some(5.0),
+        ..player("ann")
+    }
+    .into_value();
+
+    assert!(matches!(take(&mut value, "playerName"), Some(Value::String(v)) if v == "ann"));
+    assert!(matches!(take(&mut value, "hp"), Some(Value::Number(v)) if v == 10.0));
+    assert!(take(&mut value, "player_name").is_none());
+    assert!(take(&mut value, "health").is_none());
+    assert!(take(&mut value, "cached").is_none());
+}
+
+#[test]
+fn struct_defaults() {
+    let value = object(vec![
+        ("playerName", Value::String("bob".to_string())),
+        ("hp", Value::Number(4.0)),
+        ("cached", Value::Number(5.0)),
+    ]);
+
+    let player = Player::from_value(value).unwrap();
+    assert_eq!(player.score, 0.0);
+    assert_eq!(player.level, 1.0);
+    assert_eq!(player.nickname, None);
+    assert_eq!(player.cached, None);
+}
+
+#[test]
+fn nested_error_path() {
+    let mut players: Vec<Value> = ["a", "b"].iter().map(|v| player(v).into_value()).collect();
+    players.push(object(vec![
+        ("playerName", Value::Number(1.0)),
+        ("hp", Value::Number(1.0)),
+    ]));
+    let value = object(vec![("players", Value::Array(gdv8::Array::new(players)))]);
+
+    let error = Team::from_value(value).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "players[2].playerName: expected string, got number"
+    );
+}
+
+#[test]
+fn missing_field_path() {
+    let value = object(vec![("playerName", Value::String("ann".to_string()))]);
+
+    let error = Player::from_value(value).unwrap_err();
+    assert_eq!(error.to_string(), "hp: expected number, got undefined");
+}
+
+#[test]
+fn internally_tagged_round_trip() {
+    for (shape, expected) in shapes().into_iter().zip(shapes()) {
+        assert_eq!(Shape::from_value(shape.into_value()).unwrap(), expected);
+    }
+
+    let mut value = Shape::Moved(Position { x: 1.0, y: 2.0 }).into_value();
+    assert!(matches!(take(&mut value, "type"), Some(Value::String(v)) if v == "Moved"));
+    assert!(matches!(take(&mut value, "x"), Some(Value::Number(v)) if v == 1.0));
+}
+
+#[test]
+fn internally_tagged_unknown_variant() {
+    let value = object(vec![("type", Value::String("Square".to_string()))]);
+    assert!(Shape::from_value(value).is_err());
+}
+
+#[test]
+fn externally_tagged_round_trip() {
+    for (command, expected) in commands().into_iter().zip(commands()) {
+        assert_eq!(Command::from_value(command.into_value()).unwrap(), expected);
+    }
+
+    assert!(matches!(Command::Stop.into_value(), Value::String(v) if v == "Stop"));
+    let mut value = Command::Go { x: 3.0 }.into_value();
+    assert!(matches!(take(&mut value, "Go"), Some(Value::Object(_))));
+}
+
+#[test]
+#[should_panic(expected = "Tagged::Number")]
+fn internally_tagged_newtype_needs_object() {
+    Tagged::Number(1.0).into_value();
+}
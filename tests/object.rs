@@ -0,0 +1,69 @@
+//! `Object` keeps its keys in JS property order.
+
+use gdv8::{Object, Value};
+
+fn object(keys: &[&str]) -> Object {
+    let mut object = Object::new();
+    for (i, key) in keys.iter().enumerate() {
+        object.insert(*key, Value::Number(i as f64));
+    }
+    object
+}
+
+fn keys(object: &Object) -> Vec<&str> {
+    object.keys().collect()
+}
+
+#[test]
+fn insertion_order() {
+    let object = object(&["b", "a", "c"]);
+    assert_eq!(keys(&object), ["b", "a", "c"]);
+}
+
+#[test]
+fn array_indices_first_ascending() {
+    let object = object(&["b", "10", "a", "2", "0"]);
+    assert_eq!(keys(&object), ["0", "2", "10", "b", "a"]);
+}
+
+#[test]
+fn index_like_keys_are_plain() {
+    // leading zeros, signs and 2^32 - 1 aren't array indices in JS
+    let object = object(&["x", "01", "+1", "-1", "1.5", "4294967295", "4294967294", ""]);
+    assert_eq!(
+        keys(&object),
+        ["4294967294", "x", "01", "+1", "-1", "1.5", "4294967295", ""]
+    );
+}
+
+#[test]
+fn reassignment_keeps_position() {
+    let mut object = object(&["a", "b", "1"]);
+    object.insert("a", Value::Boolean(true));
+    assert_eq!(keys(&object), ["1", "a", "b"]);
+    assert!(matches!(object.get("a"), Some(Value::Boolean(true))));
+}
+
+#[test]
+fn remove_keeps_order() {
+    let mut object = object(&["a", "3", "b", "1", "c"]);
+    assert!(object.remove("3").is_some());
+    assert!(object.remove("b").is_some());
+    assert!(object.remove("missing").is_none());
+    assert_eq!(keys(&object), ["1", "a", "c"]);
+
+    object.insert("2", Value::Null);
+    object.insert("b", Value::Null);
+    assert_eq!(keys(&object), ["1", "2", "a", "c", "b"]);
+    assert!(matches!(object.get("c"), Some(Value::Number(v)) if *v == 4.0));
+}
+
+#[test]
+fn parsed_json_order() {
+    // the order `Object.keys(JSON.parse(...))` gives in V8
+    let value = Value::from_json(r#"{"b":1,"2":2,"a":3,"1":4,"01":5}"#).unwrap();
+    match value {
+        Value::Object(object) => assert_eq!(keys(&object), ["1", "2", "b", "a", "01"]),
+        v => panic!("expected an object, got {v:?}"),
+    }
+}
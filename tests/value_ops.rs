@@ -0,0 +1,110 @@
+//! JS operators on `Value` against the results V8 gives for the same
+//! expressions, see `value_ops/generate.js`.
+
+use gdv8::Value;
+
+#[path = "value_ops/cases.rs"]
+mod cases;
+
+/// An operand or result in the JS source form the generator prints.
+fn value(source: &str) -> Value {
+    match source {
+        "undefined" => Value::Undefined,
+        "NaN" => Value::Number(f64::NAN),
+        "Infinity" => Value::Number(f64::INFINITY),
+        "-Infinity" => Value::Number(f64::NEG_INFINITY),
+        _ => Value::from_json(source).unwrap(),
+    }
+}
+
+/// SameValue, so `NaN` matches itself and `-0` doesn't match `0`.
+fn same(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) if a.is_nan() => b.is_nan(),
+        (Value::Number(a), Value::Number(b)) => {
+            a == b && a.is_sign_negative() == b.is_sign_negative()
+        }
+        (a, b) => a == b,
+    }
+}
+
+fn evaluate(left: &Value, operator: &str, right: &Value) -> Value {
+    match operator {
+        "+" => left + right,
+        "-" => left - right,
+        "*" => left * right,
+        "/" => left / right,
+        "%" => left % right,
+        "&" => left & right,
+        "|" => left | right,
+        "^" => left ^ right,
+        "<<" => left << right,
+        ">>" => left >> right,
+        ">>>" => left.unsigned_shr(right),
+        "==" => Value::Boolean(left.loose_eq(right)),
+        "===" => Value::Boolean(left.strict_eq(right)),
+        "<" => match left.less_than(right) {
+            Some(v) => Value::Boolean(v),
+            None => Value::Undefined,
+        },
+        _ => panic!("unknown operator {operator}"),
+    }
+}
+
+fn evaluate_unary(operator: &str, operand: &Value) -> Value {
+    match operator {
+        "-" => -operand,
+        "~" => !operand,
+        "!" => Value::Boolean(!operand.to_boolean()),
+        "typeof" => Value::String(operand.type_of().to_string()),
+        _ => panic!("unknown operator {operator}"),
+    }
+}
+
+#[test]
+fn operators_match_v8() {
+    let mut failures = vec![];
+
+    for (left, operator, right, expected) in cases::CASES {
+        let result = match left.is_empty() {
+            true => evaluate_unary(operator, &value(right)),
+            false => evaluate(&value(left), operator, &value(right)),
+        };
+
+        if !same(&result, &value(expected)) {
+            failures.push(format!(
+                "{left} {operator} {right}: {result:?}, expected {expected}"
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn comparison_operators() {
+    for (left, operator, right, expected) in cases::CASES {
+        if *operator != "<" {
+            continue;
+        }
+
+        let (a, b) = (value(left), value(right));
+        // `undefined` where a side converts to NaN and every comparison is false
+        assert_eq!(a < b, *expected == "true", "{left} < {right}");
+        assert_eq!(b > a, *expected == "true", "{right} > {left}");
+        if *expected == "undefined" {
+            assert_eq!(a.partial_cmp(&b), None, "{left} <=> {right}");
+            assert!(!a.le(&b) && !a.ge(&b), "{left} <= {right}");
+        }
+    }
+
+    assert!(Value::String("b".to_string()) > Value::String("a".to_string()));
+    assert!(Value::String("10".to_string()) < Value::String("9".to_string()));
+    assert!(Value::Number(10.0) > Value::String("9".to_string()));
+    assert!(Value::Null >= Value::Null);
+    // equal for JS `<=` and `>=` but neither ordered nor `==` here
+    assert_eq!(
+        Value::String("1".to_string()).partial_cmp(&Value::Number(1.0)),
+        None
+    );
+}
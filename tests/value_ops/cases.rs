@@ -0,0 +1,2423 @@
+// Generated by generate.js, do not edit.
+
+/// `(left, operator, right, result)`, `left` is empty for unary operators.
+pub const CASES: &[(&str, &str, &str, &str)] = &[
+    ("undefined", "+", "undefined", "NaN"),
+    ("undefined", "+", "null", "NaN"),
+    ("undefined", "+", "true", "NaN"),
+    ("undefined", "+", "-0", "NaN"),
+    ("undefined", "+", "1.5", "NaN"),
+    ("undefined", "+", "-7", "NaN"),
+    ("undefined", "+", "NaN", "NaN"),
+    ("undefined", "+", "Infinity", "NaN"),
+    ("undefined", "+", "4294967301", "NaN"),
+    ("undefined", "+", r#"" 12 ""#, r#""undefined 12 ""#),
+    ("undefined", "+", r#""abc""#, r#""undefinedabc""#),
+    ("undefined", "+", "[2]", r#""undefined2""#),
+    ("undefined", "+", "{}", r#""undefined[object Object]""#),
+    ("null", "+", "undefined", "NaN"),
+    ("null", "+", "null", "0"),
+    ("null", "+", "true", "1"),
+    ("null", "+", "-0", "0"),
+    ("null", "+", "1.5", "1.5"),
+    ("null", "+", "-7", "-7"),
+    ("null", "+", "NaN", "NaN"),
+    ("null", "+", "Infinity", "Infinity"),
+    ("null", "+", "4294967301", "4294967301"),
+    ("null", "+", r#"" 12 ""#, r#""null 12 ""#),
+    ("null", "+", r#""abc""#, r#""nullabc""#),
+    ("null", "+", "[2]", r#""null2""#),
+    ("null", "+", "{}", r#""null[object Object]""#),
+    ("true", "+", "undefined", "NaN"),
+    ("true", "+", "null", "1"),
+    ("true", "+", "true", "2"),
+    ("true", "+", "-0", "1"),
+    ("true", "+", "1.5", "2.5"),
+    ("true", "+", "-7", "-6"),
+    ("true", "+", "NaN", "NaN"),
+    ("true", "+", "Infinity", "Infinity"),
+    ("true", "+", "4294967301", "4294967302"),
+    ("true", "+", r#"" 12 ""#, r#""true 12 ""#),
+    ("true", "+", r#""abc""#, r#""trueabc""#),
+    ("true", "+", "[2]", r#""true2""#),
+    ("true", "+", "{}", r#""true[object Object]""#),
+    ("-0", "+", "undefined", "NaN"),
+    ("-0", "+", "null", "0"),
+    ("-0", "+", "true", "1"),
+    ("-0", "+", "-0", "-0"),
+    ("-0", "+", "1.5", "1.5"),
+    ("-0", "+", "-7", "-7"),
+    ("-0", "+", "NaN", "NaN"),
+    ("-0", "+", "Infinity", "Infinity"),
+    ("-0", "+", "4294967301", "4294967301"),
+    ("-0", "+", r#"" 12 ""#, r#""0 12 ""#),
+    ("-0", "+", r#""abc""#, r#""0abc""#),
+    ("-0", "+", "[2]", r#""02""#),
+    ("-0", "+", "{}", r#""0[object Object]""#),
+    ("1.5", "+", "undefined", "NaN"),
+    ("1.5", "+", "null", "1.5"),
+    ("1.5", "+", "true", "2.5"),
+    ("1.5", "+", "-0", "1.5"),
+    ("1.5", "+", "1.5", "3"),
+    ("1.5", "+", "-7", "-5.5"),
+    ("1.5", "+", "NaN", "NaN"),
+    ("1.5", "+", "Infinity", "Infinity"),
+    ("1.5", "+", "4294967301", "4294967302.5"),
+    ("1.5", "+", r#"" 12 ""#, r#""1.5 12 ""#),
+    ("1.5", "+", r#""abc""#, r#""1.5abc""#),
+    ("1.5", "+", "[2]", r#""1.52""#),
+    ("1.5", "+", "{}", r#""1.5[object Object]""#),
+    ("-7", "+", "undefined", "NaN"),
+    ("-7", "+", "null", "-7"),
+    ("-7", "+", "true", "-6"),
+    ("-7", "+", "-0", "-7"),
+    ("-7", "+", "1.5", "-5.5"),
+    ("-7", "+", "-7", "-14"),
+    ("-7", "+", "NaN", "NaN"),
+    ("-7", "+", "Infinity", "Infinity"),
+    ("-7", "+", "4294967301", "4294967294"),
+    ("-7", "+", r#"" 12 ""#, r#""-7 12 ""#),
+    ("-7", "+", r#""abc""#, r#""-7abc""#),
+    ("-7", "+", "[2]", r#""-72""#),
+    ("-7", "+", "{}", r#""-7[object Object]""#),
+    ("NaN", "+", "undefined", "NaN"),
+    ("NaN", "+", "null", "NaN"),
+    ("NaN", "+", "true", "NaN"),
+    ("NaN", "+", "-0", "NaN"),
+    ("NaN", "+", "1.5", "NaN"),
+    ("NaN", "+", "-7", "NaN"),
+    ("NaN", "+", "NaN", "NaN"),
+    ("NaN", "+", "Infinity", "NaN"),
+    ("NaN", "+", "4294967301", "NaN"),
+    ("NaN", "+", r#"" 12 ""#, r#""NaN 12 ""#),
+    ("NaN", "+", r#""abc""#, r#""NaNabc""#),
+    ("NaN", "+", "[2]", r#""NaN2""#),
+    ("NaN", "+", "{}", r#""NaN[object Object]""#),
+    ("Infinity", "+", "undefined", "NaN"),
+    ("Infinity", "+", "null", "Infinity"),
+    ("Infinity", "+", "true", "Infinity"),
+    ("Infinity", "+", "-0", "Infinity"),
+    ("Infinity", "+", "1.5", "Infinity"),
+    ("Infinity", "+", "-7", "Infinity"),
+    ("Infinity", "+", "NaN", "NaN"),
+    ("Infinity", "+", "Infinity", "Infinity"),
+    ("Infinity", "+", "4294967301", "Infinity"),
+    ("Infinity", "+", r#"" 12 ""#, r#""Infinity 12 ""#),
+    ("Infinity", "+", r#""abc""#, r#""Infinityabc""#),
+    ("Infinity", "+", "[2]", r#""Infinity2""#),
+    ("Infinity", "+", "{}", r#""Infinity[object Object]""#),
+    ("4294967301", "+", "undefined", "NaN"),
+    ("4294967301", "+", "null", "4294967301"),
+    ("4294967301", "+", "true", "4294967302"),
+    ("4294967301", "+", "-0", "4294967301"),
+    ("4294967301", "+", "1.5", "4294967302.5"),
+    ("4294967301", "+", "-7", "4294967294"),
+    ("4294967301", "+", "NaN", "NaN"),
+    ("4294967301", "+", "Infinity", "Infinity"),
+    ("4294967301", "+", "4294967301", "8589934602"),
+    ("4294967301", "+", r#"" 12 ""#, r#""4294967301 12 ""#),
+    ("4294967301", "+", r#""abc""#, r#""4294967301abc""#),
+    ("4294967301", "+", "[2]", r#""42949673012""#),
+    ("4294967301", "+", "{}", r#""4294967301[object Object]""#),
+    (r#"" 12 ""#, "+", "undefined", r#"" 12 undefined""#),
+    (r#"" 12 ""#, "+", "null", r#"" 12 null""#),
+    (r#"" 12 ""#, "+", "true", r#"" 12 true""#),
+    (r#"" 12 ""#, "+", "-0", r#"" 12 0""#),
+    (r#"" 12 ""#, "+", "1.5", r#"" 12 1.5""#),
+    (r#"" 12 ""#, "+", "-7", r#"" 12 -7""#),
+    (r#"" 12 ""#, "+", "NaN", r#"" 12 NaN""#),
+    (r#"" 12 ""#, "+", "Infinity", r#"" 12 Infinity""#),
+    (r#"" 12 ""#, "+", "4294967301", r#"" 12 4294967301""#),
+    (r#"" 12 ""#, "+", r#"" 12 ""#, r#"" 12  12 ""#),
+    (r#"" 12 ""#, "+", r#""abc""#, r#"" 12 abc""#),
+    (r#"" 12 ""#, "+", "[2]", r#"" 12 2""#),
+    (r#"" 12 ""#, "+", "{}", r#"" 12 [object Object]""#),
+    (r#""abc""#, "+", "undefined", r#""abcundefined""#),
+    (r#""abc""#, "+", "null", r#""abcnull""#),
+    (r#""abc""#, "+", "true", r#""abctrue""#),
+    (r#""abc""#, "+", "-0", r#""abc0""#),
+    (r#""abc""#, "+", "1.5", r#""abc1.5""#),
+    (r#""abc""#, "+", "-7", r#""abc-7""#),
+    (r#""abc""#, "+", "NaN", r#""abcNaN""#),
+    (r#""abc""#, "+", "Infinity", r#""abcInfinity""#),
+    (r#""abc""#, "+", "4294967301", r#""abc4294967301""#),
+    (r#""abc""#, "+", r#"" 12 ""#, r#""abc 12 ""#),
+    (r#""abc""#, "+", r#""abc""#, r#""abcabc""#),
+    (r#""abc""#, "+", "[2]", r#""abc2""#),
+    (r#""abc""#, "+", "{}", r#""abc[object Object]""#),
+    ("[2]", "+", "undefined", r#""2undefined""#),
+    ("[2]", "+", "null", r#""2null""#),
+    ("[2]", "+", "true", r#""2true""#),
+    ("[2]", "+", "-0", r#""20""#),
+    ("[2]", "+", "1.5", r#""21.5""#),
+    ("[2]", "+", "-7", r#""2-7""#),
+    ("[2]", "+", "NaN", r#""2NaN""#),
+    ("[2]", "+", "Infinity", r#""2Infinity""#),
+    ("[2]", "+", "4294967301", r#""24294967301""#),
+    ("[2]", "+", r#"" 12 ""#, r#""2 12 ""#),
+    ("[2]", "+", r#""abc""#, r#""2abc""#),
+    ("[2]", "+", "[2]", r#""22""#),
+    ("[2]", "+", "{}", r#""2[object Object]""#),
+    ("{}", "+", "undefined", r#""[object Object]undefined""#),
+    ("{}", "+", "null", r#""[object Object]null""#),
+    ("{}", "+", "true", r#""[object Object]true""#),
+    ("{}", "+", "-0", r#""[object Object]0""#),
+    ("{}", "+", "1.5", r#""[object Object]1.5""#),
+    ("{}", "+", "-7", r#""[object Object]-7""#),
+    ("{}", "+", "NaN", r#""[object Object]NaN""#),
+    ("{}", "+", "Infinity", r#""[object Object]Infinity""#),
+    ("{}", "+", "4294967301", r#""[object Object]4294967301""#),
+    ("{}", "+", r#"" 12 ""#, r#""[object Object] 12 ""#),
+    ("{}", "+", r#""abc""#, r#""[object Object]abc""#),
+    ("{}", "+", "[2]", r#""[object Object]2""#),
+    ("{}", "+", "{}", r#""[object Object][object Object]""#),
+    ("undefined", "-", "undefined", "NaN"),
+    ("undefined", "-", "null", "NaN"),
+    ("undefined", "-", "true", "NaN"),
+    ("undefined", "-", "-0", "NaN"),
+    ("undefined", "-", "1.5", "NaN"),
+    ("undefined", "-", "-7", "NaN"),
+    ("undefined", "-", "NaN", "NaN"),
+    ("undefined", "-", "Infinity", "NaN"),
+    ("undefined", "-", "4294967301", "NaN"),
+    ("undefined", "-", r#"" 12 ""#, "NaN"),
+    ("undefined", "-", r#""abc""#, "NaN"),
+    ("undefined", "-", "[2]", "NaN"),
+    ("undefined", "-", "{}", "NaN"),
+    ("null", "-", "undefined", "NaN"),
+    ("null", "-", "null", "0"),
+    ("null", "-", "true", "-1"),
+    ("null", "-", "-0", "0"),
+    ("null", "-", "1.5", "-1.5"),
+    ("null", "-", "-7", "7"),
+    ("null", "-", "NaN", "NaN"),
+    ("null", "-", "Infinity", "-Infinity"),
+    ("null", "-", "4294967301", "-4294967301"),
+    ("null", "-", r#"" 12 ""#, "-12"),
+    ("null", "-", r#""abc""#, "NaN"),
+    ("null", "-", "[2]", "-2"),
+    ("null", "-", "{}", "NaN"),
+    ("true", "-", "undefined", "NaN"),
+    ("true", "-", "null", "1"),
+    ("true", "-", "true", "0"),
+    ("true", "-", "-0", "1"),
+    ("true", "-", "1.5", "-0.5"),
+    ("true", "-", "-7", "8"),
+    ("true", "-", "NaN", "NaN"),
+    ("true", "-", "Infinity", "-Infinity"),
+    ("true", "-", "4294967301", "-4294967300"),
+    ("true", "-", r#"" 12 ""#, "-11"),
+    ("true", "-", r#""abc""#, "NaN"),
+    ("true", "-", "[2]", "-1"),
+    ("true", "-", "{}", "NaN"),
+    ("-0", "-", "undefined", "NaN"),
+    ("-0", "-", "null", "-0"),
+    ("-0", "-", "true", "-1"),
+    ("-0", "-", "-0", "0"),
+    ("-0", "-", "1.5", "-1.5"),
+    ("-0", "-", "-7", "7"),
+    ("-0", "-", "NaN", "NaN"),
+    ("-0", "-", "Infinity", "-Infinity"),
+    ("-0", "-", "4294967301", "-4294967301"),
+    ("-0", "-", r#"" 12 ""#, "-12"),
+    ("-0", "-", r#""abc""#, "NaN"),
+    ("-0", "-", "[2]", "-2"),
+    ("-0", "-", "{}", "NaN"),
+    ("1.5", "-", "undefined", "NaN"),
+    ("1.5", "-", "null", "1.5"),
+    ("1.5", "-", "true", "0.5"),
+    ("1.5", "-", "-0", "1.5"),
+    ("1.5", "-", "1.5", "0"),
+    ("1.5", "-", "-7", "8.5"),
+    ("1.5", "-", "NaN", "NaN"),
+    ("1.5", "-", "Infinity", "-Infinity"),
+    ("1.5", "-", "4294967301", "-4294967299.5"),
+    ("1.5", "-", r#"" 12 ""#, "-10.5"),
+    ("1.5", "-", r#""abc""#, "NaN"),
+    ("1.5", "-", "[2]", "-0.5"),
+    ("1.5", "-", "{}", "NaN"),
+    ("-7", "-", "undefined", "NaN"),
+    ("-7", "-", "null", "-7"),
+    ("-7", "-", "true", "-8"),
+    ("-7", "-", "-0", "-7"),
+    ("-7", "-", "1.5", "-8.5"),
+    ("-7", "-", "-7", "0"),
+    ("-7", "-", "NaN", "NaN"),
+    ("-7", "-", "Infinity", "-Infinity"),
+    ("-7", "-", "4294967301", "-4294967308"),
+    ("-7", "-", r#"" 12 ""#, "-19"),
+    ("-7", "-", r#""abc""#, "NaN"),
+    ("-7", "-", "[2]", "-9"),
+    ("-7", "-", "{}", "NaN"),
+    ("NaN", "-", "undefined", "NaN"),
+    ("NaN", "-", "null", "NaN"),
+    ("NaN", "-", "true", "NaN"),
+    ("NaN", "-", "-0", "NaN"),
+    ("NaN", "-", "1.5", "NaN"),
+    ("NaN", "-", "-7", "NaN"),
+    ("NaN", "-", "NaN", "NaN"),
+    ("NaN", "-", "Infinity", "NaN"),
+    ("NaN", "-", "4294967301", "NaN"),
+    ("NaN", "-", r#"" 12 ""#, "NaN"),
+    ("NaN", "-", r#""abc""#, "NaN"),
+    ("NaN", "-", "[2]", "NaN"),
+    ("NaN", "-", "{}", "NaN"),
+    ("Infinity", "-", "undefined", "NaN"),
+    ("Infinity", "-", "null", "Infinity"),
+    ("Infinity", "-", "true", "Infinity"),
+    ("Infinity", "-", "-0", "Infinity"),
+    ("Infinity", "-", "1.5", "Infinity"),
+    ("Infinity", "-", "-7", "Infinity"),
+    ("Infinity", "-", "NaN", "NaN"),
+    ("Infinity", "-", "Infinity", "NaN"),
+    ("Infinity", "-", "4294967301", "Infinity"),
+    ("Infinity", "-", r#"" 12 ""#, "Infinity"),
+    ("Infinity", "-", r#""abc""#, "NaN"),
+    ("Infinity", "-", "[2]", "Infinity"),
+    ("Infinity", "-", "{}", "NaN"),
+    ("4294967301", "-", "undefined", "NaN"),
+    ("4294967301", "-", "null", "4294967301"),
+    ("4294967301", "-", "true", "4294967300"),
+    ("4294967301", "-", "-0", "4294967301"),
+    ("4294967301", "-", "1.5", "4294967299.5"),
+    ("4294967301", "-", "-7", "4294967308"),
+    ("4294967301", "-", "NaN", "NaN"),
+    ("4294967301", "-", "Infinity", "-Infinity"),
+    ("4294967301", "-", "4294967301", "0"),
+    ("4294967301", "-", r#"" 12 ""#, "4294967289"),
+    ("4294967301", "-", r#""abc""#, "NaN"),
+    ("4294967301", "-", "[2]", "4294967299"),
+    ("4294967301", "-", "{}", "NaN"),
+    (r#"" 12 ""#, "-", "undefined", "NaN"),
+    (r#"" 12 ""#, "-", "null", "12"),
+    (r#"" 12 ""#, "-", "true", "11"),
+    (r#"" 12 ""#, "-", "-0", "12"),
+    (r#"" 12 ""#, "-", "1.5", "10.5"),
+    (r#"" 12 ""#, "-", "-7", "19"),
+    (r#"" 12 ""#, "-", "NaN", "NaN"),
+    (r#"" 12 ""#, "-", "Infinity", "-Infinity"),
+    (r#"" 12 ""#, "-", "4294967301", "-4294967289"),
+    (r#"" 12 ""#, "-", r#"" 12 ""#, "0"),
+    (r#"" 12 ""#, "-", r#""abc""#, "NaN"),
+    (r#"" 12 ""#, "-", "[2]", "10"),
+    (r#"" 12 ""#, "-", "{}", "NaN"),
+    (r#""abc""#, "-", "undefined", "NaN"),
+    (r#""abc""#, "-", "null", "NaN"),
+    (r#""abc""#, "-", "true", "NaN"),
+    (r#""abc""#, "-", "-0", "NaN"),
+    (r#""abc""#, "-", "1.5", "NaN"),
+    (r#""abc""#, "-", "-7", "NaN"),
+    (r#""abc""#, "-", "NaN", "NaN"),
+    (r#""abc""#, "-", "Infinity", "NaN"),
+    (r#""abc""#, "-", "4294967301", "NaN"),
+    (r#""abc""#, "-", r#"" 12 ""#, "NaN"),
+    (r#""abc""#, "-", r#""abc""#, "NaN"),
+    (r#""abc""#, "-", "[2]", "NaN"),
+    (r#""abc""#, "-", "{}", "NaN"),
+    ("[2]", "-", "undefined", "NaN"),
+    ("[2]", "-", "null", "2"),
+    ("[2]", "-", "true", "1"),
+    ("[2]", "-", "-0", "2"),
+    ("[2]", "-", "1.5", "0.5"),
+    ("[2]", "-", "-7", "9"),
+    ("[2]", "-", "NaN", "NaN"),
+    ("[2]", "-", "Infinity", "-Infinity"),
+    ("[2]", "-", "4294967301", "-4294967299"),
+    ("[2]", "-", r#"" 12 ""#, "-10"),
+    ("[2]", "-", r#""abc""#, "NaN"),
+    ("[2]", "-", "[2]", "0"),
+    ("[2]", "-", "{}", "NaN"),
+    ("{}", "-", "undefined", "NaN"),
+    ("{}", "-", "null", "NaN"),
+    ("{}", "-", "true", "NaN"),
+    ("{}", "-", "-0", "NaN"),
+    ("{}", "-", "1.5", "NaN"),
+    ("{}", "-", "-7", "NaN"),
+    ("{}", "-", "NaN", "NaN"),
+    ("{}", "-", "Infinity", "NaN"),
+    ("{}", "-", "4294967301", "NaN"),
+    ("{}", "-", r#"" 12 ""#, "NaN"),
+    ("{}", "-", r#""abc""#, "NaN"),
+    ("{}", "-", "[2]", "NaN"),
+    ("{}", "-", "{}", "NaN"),
+    ("undefined", "*", "undefined", "NaN"),
+    ("undefined", "*", "null", "NaN"),
+    ("undefined", "*", "true", "NaN"),
+    ("undefined", "*", "-0", "NaN"),
+    ("undefined", "*", "1.5", "NaN"),
+    ("undefined", "*", "-7", "NaN"),
+    ("undefined", "*", "NaN", "NaN"),
+    ("undefined", "*", "Infinity", "NaN"),
+    ("undefined", "*", "4294967301", "NaN"),
+    ("undefined", "*", r#"" 12 ""#, "NaN"),
+    ("undefined", "*", r#""abc""#, "NaN"),
+    ("undefined", "*", "[2]", "NaN"),
+    ("undefined", "*", "{}", "NaN"),
+    ("null", "*", "undefined", "NaN"),
+    ("null", "*", "null", "0"),
+    ("null", "*", "true", "0"),
+    ("null", "*", "-0", "-0"),
+    ("null", "*", "1.5", "0"),
+    ("null", "*", "-7", "-0"),
+    ("null", "*", "NaN", "NaN"),
+    ("null", "*", "Infinity", "NaN"),
+    ("null", "*", "4294967301", "0"),
+    ("null", "*", r#"" 12 ""#, "0"),
+    ("null", "*", r#""abc""#, "NaN"),
+    ("null", "*", "[2]", "0"),
+    ("null", "*", "{}", "NaN"),
+    ("true", "*", "undefined", "NaN"),
+    ("true", "*", "null", "0"),
+    ("true", "*", "true", "1"),
+    ("true", "*", "-0", "-0"),
+    ("true", "*", "1.5", "1.5"),
+    ("true", "*", "-7", "-7"),
+    ("true", "*", "NaN", "NaN"),
+    ("true", "*", "Infinity", "Infinity"),
+    ("true", "*", "4294967301", "4294967301"),
+    ("true", "*", r#"" 12 ""#, "12"),
+    ("true", "*", r#""abc""#, "NaN"),
+    ("true", "*", "[2]", "2"),
+    ("true", "*", "{}", "NaN"),
+    ("-0", "*", "undefined", "NaN"),
+    ("-0", "*", "null", "-0"),
+    ("-0", "*", "true", "-0"),
+    ("-0", "*", "-0", "0"),
+    ("-0", "*", "1.5", "-0"),
+    ("-0", "*", "-7", "0"),
+    ("-0", "*", "NaN", "NaN"),
+    ("-0", "*", "Infinity", "NaN"),
+    ("-0", "*", "4294967301", "-0"),
+    ("-0", "*", r#"" 12 ""#, "-0"),
+    ("-0", "*", r#""abc""#, "NaN"),
+    ("-0", "*", "[2]", "-0"),
+    ("-0", "*", "{}", "NaN"),
+    ("1.5", "*", "undefined", "NaN"),
+    ("1.5", "*", "null", "0"),
+    ("1.5", "*", "true", "1.5"),
+    ("1.5", "*", "-0", "-0"),
+    ("1.5", "*", "1.5", "2.25"),
+    ("1.5", "*", "-7", "-10.5"),
+    ("1.5", "*", "NaN", "NaN"),
+    ("1.5", "*", "Infinity", "Infinity"),
+    ("1.5", "*", "4294967301", "6442450951.5"),
+    ("1.5", "*", r#"" 12 ""#, "18"),
+    ("1.5", "*", r#""abc""#, "NaN"),
+    ("1.5", "*", "[2]", "3"),
+    ("1.5", "*", "{}", "NaN"),
+    ("-7", "*", "undefined", "NaN"),
+    ("-7", "*", "null", "-0"),
+    ("-7", "*", "true", "-7"),
+    ("-7", "*", "-0", "0"),
+    ("-7", "*", "1.5", "-10.5"),
+    ("-7", "*", "-7", "49"),
+    ("-7", "*", "NaN", "NaN"),
+    ("-7", "*", "Infinity", "-Infinity"),
+    ("-7", "*", "4294967301", "-30064771107"),
+    ("-7", "*", r#"" 12 ""#, "-84"),
+    ("-7", "*", r#""abc""#, "NaN"),
+    ("-7", "*", "[2]", "-14"),
+    ("-7", "*", "{}", "NaN"),
+    ("NaN", "*", "undefined", "NaN"),
+    ("NaN", "*", "null", "NaN"),
+    ("NaN", "*", "true", "NaN"),
+    ("NaN", "*", "-0", "NaN"),
+    ("NaN", "*", "1.5", "NaN"),
+    ("NaN", "*", "-7", "NaN"),
+    ("NaN", "*", "NaN", "NaN"),
+    ("NaN", "*", "Infinity", "NaN"),
+    ("NaN", "*", "4294967301", "NaN"),
+    ("NaN", "*", r#"" 12 ""#, "NaN"),
+    ("NaN", "*", r#""abc""#, "NaN"),
+    ("NaN", "*", "[2]", "NaN"),
+    ("NaN", "*", "{}", "NaN"),
+    ("Infinity", "*", "undefined", "NaN"),
+    ("Infinity", "*", "null", "NaN"),
+    ("Infinity", "*", "true", "Infinity"),
+    ("Infinity", "*", "-0", "NaN"),
+    ("Infinity", "*", "1.5", "Infinity"),
+    ("Infinity", "*", "-7", "-Infinity"),
+    ("Infinity", "*", "NaN", "NaN"),
+    ("Infinity", "*", "Infinity", "Infinity"),
+    ("Infinity", "*", "4294967301", "Infinity"),
+    ("Infinity", "*", r#"" 12 ""#, "Infinity"),
+    ("Infinity", "*", r#""abc""#, "NaN"),
+    ("Infinity", "*", "[2]", "Infinity"),
+    ("Infinity", "*", "{}", "NaN"),
+    ("4294967301", "*", "undefined", "NaN"),
+    ("4294967301", "*", "null", "0"),
+    ("4294967301", "*", "true", "4294967301"),
+    ("4294967301", "*", "-0", "-0"),
+    ("4294967301", "*", "1.5", "6442450951.5"),
+    ("4294967301", "*", "-7", "-30064771107"),
+    ("4294967301", "*", "NaN", "NaN"),
+    ("4294967301", "*", "Infinity", "Infinity"),
+    ("4294967301", "*", "4294967301", "18446744116659225000"),
+    ("4294967301", "*", r#"" 12 ""#, "51539607612"),
+    ("4294967301", "*", r#""abc""#, "NaN"),
+    ("4294967301", "*", "[2]", "8589934602"),
+    ("4294967301", "*", "{}", "NaN"),
+    (r#"" 12 ""#, "*", "undefined", "NaN"),
+    (r#"" 12 ""#, "*", "null", "0"),
+    (r#"" 12 ""#, "*", "true", "12"),
+    (r#"" 12 ""#, "*", "-0", "-0"),
+    (r#"" 12 ""#, "*", "1.5", "18"),
+    (r#"" 12 ""#, "*", "-7", "-84"),
+    (r#"" 12 ""#, "*", "NaN", "NaN"),
+    (r#"" 12 ""#, "*", "Infinity", "Infinity"),
+    (r#"" 12 ""#, "*", "4294967301", "51539607612"),
+    (r#"" 12 ""#, "*", r#"" 12 ""#, "144"),
+    (r#"" 12 ""#, "*", r#""abc""#, "NaN"),
+    (r#"" 12 ""#, "*", "[2]", "24"),
+    (r#"" 12 ""#, "*", "{}", "NaN"),
+    (r#""abc""#, "*", "undefined", "NaN"),
+    (r#""abc""#, "*", "null", "NaN"),
+    (r#""abc""#, "*", "true", "NaN"),
+    (r#""abc""#, "*", "-0", "NaN"),
+    (r#""abc""#, "*", "1.5", "NaN"),
+    (r#""abc""#, "*", "-7", "NaN"),
+    (r#""abc""#, "*", "NaN", "NaN"),
+    (r#""abc""#, "*", "Infinity", "NaN"),
+    (r#""abc""#, "*", "4294967301", "NaN"),
+    (r#""abc""#, "*", r#"" 12 ""#, "NaN"),
+    (r#""abc""#, "*", r#""abc""#, "NaN"),
+    (r#""abc""#, "*", "[2]", "NaN"),
+    (r#""abc""#, "*", "{}", "NaN"),
+    ("[2]", "*", "undefined", "NaN"),
+    ("[2]", "*", "null", "0"),
+    ("[2]", "*", "true", "2"),
+    ("[2]", "*", "-0", "-0"),
+    ("[2]", "*", "1.5", "3"),
+    ("[2]", "*", "-7", "-14"),
+    ("[2]", "*", "NaN", "NaN"),
+    ("[2]", "*", "Infinity", "Infinity"),
+    ("[2]", "*", "4294967301", "8589934602"),
+    ("[2]", "*", r#"" 12 ""#, "24"),
+    ("[2]", "*", r#""abc""#, "NaN"),
+    ("[2]", "*", "[2]", "4"),
+    ("[2]", "*", "{}", "NaN"),
+    ("{}", "*", "undefined", "NaN"),
+    ("{}", "*", "null", "NaN"),
+    ("{}", "*", "true", "NaN"),
+    ("{}", "*", "-0", "NaN"),
+    ("{}", "*", "1.5", "NaN"),
+    ("{}", "*", "-7", "NaN"),
+    ("{}", "*", "NaN", "NaN"),
+    ("{}", "*", "Infinity", "NaN"),
+    ("{}", "*", "4294967301", "NaN"),
+    ("{}", "*", r#"" 12 ""#, "NaN"),
+    ("{}", "*", r#""abc""#, "NaN"),
+    ("{}", "*", "[2]", "NaN"),
+    ("{}", "*", "{}", "NaN"),
+    ("undefined", "/", "undefined", "NaN"),
+    ("undefined", "/", "null", "NaN"),
+    ("undefined", "/", "true", "NaN"),
+    ("undefined", "/", "-0", "NaN"),
+    ("undefined", "/", "1.5", "NaN"),
+    ("undefined", "/", "-7", "NaN"),
+    ("undefined", "/", "NaN", "NaN"),
+    ("undefined", "/", "Infinity", "NaN"),
+    ("undefined", "/", "4294967301", "NaN"),
+    ("undefined", "/", r#"" 12 ""#, "NaN"),
+    ("undefined", "/", r#""abc""#, "NaN"),
+    ("undefined", "/", "[2]", "NaN"),
+    ("undefined", "/", "{}", "NaN"),
+    ("null", "/", "undefined", "NaN"),
+    ("null", "/", "null", "NaN"),
+    ("null", "/", "true", "0"),
+    ("null", "/", "-0", "NaN"),
+    ("null", "/", "1.5", "0"),
+    ("null", "/", "-7", "-0"),
+    ("null", "/", "NaN", "NaN"),
+    ("null", "/", "Infinity", "0"),
+    ("null", "/", "4294967301", "0"),
+    ("null", "/", r#"" 12 ""#, "0"),
+    ("null", "/", r#""abc""#, "NaN"),
+    ("null", "/", "[2]", "0"),
+    ("null", "/", "{}", "NaN"),
+    ("true", "/", "undefined", "NaN"),
+    ("true", "/", "null", "Infinity"),
+    ("true", "/", "true", "1"),
+    ("true", "/", "-0", "-Infinity"),
+    ("true", "/", "1.5", "0.6666666666666666"),
+    ("true", "/", "-7", "-0.14285714285714285"),
+    ("true", "/", "NaN", "NaN"),
+    ("true", "/", "Infinity", "0"),
+    ("true", "/", "4294967301", "2.3283064338281909e-10"),
+    ("true", "/", r#"" 12 ""#, "0.08333333333333333"),
+    ("true", "/", r#""abc""#, "NaN"),
+    ("true", "/", "[2]", "0.5"),
+    ("true", "/", "{}", "NaN"),
+    ("-0", "/", "undefined", "NaN"),
+    ("-0", "/", "null", "NaN"),
+    ("-0", "/", "true", "-0"),
+    ("-0", "/", "-0", "NaN"),
+    ("-0", "/", "1.5", "-0"),
+    ("-0", "/", "-7", "0"),
+    ("-0", "/", "NaN", "NaN"),
+    ("-0", "/", "Infinity", "-0"),
+    ("-0", "/", "4294967301", "-0"),
+    ("-0", "/", r#"" 12 ""#, "-0"),
+    ("-0", "/", r#""abc""#, "NaN"),
+    ("-0", "/", "[2]", "-0"),
+    ("-0", "/", "{}", "NaN"),
+    ("1.5", "/", "undefined", "NaN"),
+    ("1.5", "/", "null", "Infinity"),
+    ("1.5", "/", "true", "1.5"),
+    ("1.5", "/", "-0", "-Infinity"),
+    ("1.5", "/", "1.5", "1"),
+    ("1.5", "/", "-7", "-0.21428571428571427"),
+    ("1.5", "/", "NaN", "NaN"),
+    ("1.5", "/", "Infinity", "0"),
+    ("1.5", "/", "4294967301", "3.4924596507422863e-10"),
+    ("1.5", "/", r#"" 12 ""#, "0.125"),
+    ("1.5", "/", r#""abc""#, "NaN"),
+    ("1.5", "/", "[2]", "0.75"),
+    ("1.5", "/", "{}", "NaN"),
+    ("-7", "/", "undefined", "NaN"),
+    ("-7", "/", "null", "-Infinity"),
+    ("-7", "/", "true", "-7"),
+    ("-7", "/", "-0", "Infinity"),
+    ("-7", "/", "1.5", "-4.666666666666667"),
+    ("-7", "/", "-7", "1"),
+    ("-7", "/", "NaN", "NaN"),
+    ("-7", "/", "Infinity", "-0"),
+    ("-7", "/", "4294967301", "-1.6298145036797336e-9"),
+    ("-7", "/", r#"" 12 ""#, "-0.5833333333333334"),
+    ("-7", "/", r#""abc""#, "NaN"),
+    ("-7", "/", "[2]", "-3.5"),
+    ("-7", "/", "{}", "NaN"),
+    ("NaN", "/", "undefined", "NaN"),
+    ("NaN", "/", "null", "NaN"),
+    ("NaN", "/", "true", "NaN"),
+    ("NaN", "/", "-0", "NaN"),
+    ("NaN", "/", "1.5", "NaN"),
+    ("NaN", "/", "-7", "NaN"),
+    ("NaN", "/", "NaN", "NaN"),
+    ("NaN", "/", "Infinity", "NaN"),
+    ("NaN", "/", "4294967301", "NaN"),
+    ("NaN", "/", r#"" 12 ""#, "NaN"),
+    ("NaN", "/", r#""abc""#, "NaN"),
+    ("NaN", "/", "[2]", "NaN"),
+    ("NaN", "/", "{}", "NaN"),
+    ("Infinity", "/", "undefined", "NaN"),
+    ("Infinity", "/", "null", "Infinity"),
+    ("Infinity", "/", "true", "Infinity"),
+    ("Infinity", "/", "-0", "-Infinity"),
+    ("Infinity", "/", "1.5", "Infinity"),
+    ("Infinity", "/", "-7", "-Infinity"),
+    ("Infinity", "/", "NaN", "NaN"),
+    ("Infinity", "/", "Infinity", "NaN"),
+    ("Infinity", "/", "4294967301", "Infinity"),
+    ("Infinity", "/", r#"" 12 ""#, "Infinity"),
+    ("Infinity", "/", r#""abc""#, "NaN"),
+    ("Infinity", "/", "[2]", "Infinity"),
+    ("Infinity", "/", "{}", "NaN"),
+    ("4294967301", "/", "undefined", "NaN"),
+    ("4294967301", "/", "null", "Infinity"),
+    ("4294967301", "/", "true", "4294967301"),
+    ("4294967301", "/", "-0", "-Infinity"),
+    ("4294967301", "/", "1.5", "2863311534"),
+    ("4294967301", "/", "-7", "-613566757.2857143"),
+    ("4294967301", "/", "NaN", "NaN"),
+    ("4294967301", "/", "Infinity", "0"),
+    ("4294967301", "/", "4294967301", "1"),
+    ("4294967301", "/", r#"" 12 ""#, "357913941.75"),
+    ("4294967301", "/", r#""abc""#, "NaN"),
+    ("4294967301", "/", "[2]", "2147483650.5"),
+    ("4294967301", "/", "{}", "NaN"),
+    (r#"" 12 ""#, "/", "undefined", "NaN"),
+    (r#"" 12 ""#, "/", "null", "Infinity"),
+    (r#"" 12 ""#, "/", "true", "12"),
+    (r#"" 12 ""#, "/", "-0", "-Infinity"),
+    (r#"" 12 ""#, "/", "1.5", "8"),
+    (r#"" 12 ""#, "/", "-7", "-1.7142857142857142"),
+    (r#"" 12 ""#, "/", "NaN", "NaN"),
+    (r#"" 12 ""#, "/", "Infinity", "0"),
+    (r#"" 12 ""#, "/", "4294967301", "2.793967720593829e-9"),
+    (r#"" 12 ""#, "/", r#"" 12 ""#, "1"),
+    (r#"" 12 ""#, "/", r#""abc""#, "NaN"),
+    (r#"" 12 ""#, "/", "[2]", "6"),
+    (r#"" 12 ""#, "/", "{}", "NaN"),
+    (r#""abc""#, "/", "undefined", "NaN"),
+    (r#""abc""#, "/", "null", "NaN"),
+    (r#""abc""#, "/", "true", "NaN"),
+    (r#""abc""#, "/", "-0", "NaN"),
+    (r#""abc""#, "/", "1.5", "NaN"),
+    (r#""abc""#, "/", "-7", "NaN"),
+    (r#""abc""#, "/", "NaN", "NaN"),
+    (r#""abc""#, "/", "Infinity", "NaN"),
+    (r#""abc""#, "/", "4294967301", "NaN"),
+    (r#""abc""#, "/", r#"" 12 ""#, "NaN"),
+    (r#""abc""#, "/", r#""abc""#, "NaN"),
+    (r#""abc""#, "/", "[2]", "NaN"),
+    (r#""abc""#, "/", "{}", "NaN"),
+    ("[2]", "/", "undefined", "NaN"),
+    ("[2]", "/", "null", "Infinity"),
+    ("[2]", "/", "true", "2"),
+    ("[2]", "/", "-0", "-Infinity"),
+    ("[2]", "/", "1.5", "1.3333333333333333"),
+    ("[2]", "/", "-7", "-0.2857142857142857"),
+    ("[2]", "/", "NaN", "NaN"),
+    ("[2]", "/", "Infinity", "0"),
+    ("[2]", "/", "4294967301", "4.6566128676563817e-10"),
+    ("[2]", "/", r#"" 12 ""#, "0.16666666666666666"),
+    ("[2]", "/", r#""abc""#, "NaN"),
+    ("[2]", "/", "[2]", "1"),
+    ("[2]", "/", "{}", "NaN"),
+    ("{}", "/", "undefined", "NaN"),
+    ("{}", "/", "null", "NaN"),
+    ("{}", "/", "true", "NaN"),
+    ("{}", "/", "-0", "NaN"),
+    ("{}", "/", "1.5", "NaN"),
+    ("{}", "/", "-7", "NaN"),
+    ("{}", "/", "NaN", "NaN"),
+    ("{}", "/", "Infinity", "NaN"),
+    ("{}", "/", "4294967301", "NaN"),
+    ("{}", "/", r#"" 12 ""#, "NaN"),
+    ("{}", "/", r#""abc""#, "NaN"),
+    ("{}", "/", "[2]", "NaN"),
+    ("{}", "/", "{}", "NaN"),
+    ("undefined", "%", "undefined", "NaN"),
+    ("undefined", "%", "null", "NaN"),
+    ("undefined", "%", "true", "NaN"),
+    ("undefined", "%", "-0", "NaN"),
+    ("undefined", "%", "1.5", "NaN"),
+    ("undefined", "%", "-7", "NaN"),
+    ("undefined", "%", "NaN", "NaN"),
+    ("undefined", "%", "Infinity", "NaN"),
+    ("undefined", "%", "4294967301", "NaN"),
+    ("undefined", "%", r#"" 12 ""#, "NaN"),
+    ("undefined", "%", r#""abc""#, "NaN"),
+    ("undefined", "%", "[2]", "NaN"),
+    ("undefined", "%", "{}", "NaN"),
+    ("null", "%", "undefined", "NaN"),
+    ("null", "%", "null", "NaN"),
+    ("null", "%", "true", "0"),
+    ("null", "%", "-0", "NaN"),
+    ("null", "%", "1.5", "0"),
+    ("null", "%", "-7", "0"),
+    ("null", "%", "NaN", "NaN"),
+    ("null", "%", "Infinity", "0"),
+    ("null", "%", "4294967301", "0"),
+    ("null", "%", r#"" 12 ""#, "0"),
+    ("null", "%", r#""abc""#, "NaN"),
+    ("null", "%", "[2]", "0"),
+    ("null", "%", "{}", "NaN"),
+    ("true", "%", "undefined", "NaN"),
+    ("true", "%", "null", "NaN"),
+    ("true", "%", "true", "0"),
+    ("true", "%", "-0", "NaN"),
+    ("true", "%", "1.5", "1"),
+    ("true", "%", "-7", "1"),
+    ("true", "%", "NaN", "NaN"),
+    ("true", "%", "Infinity", "1"),
+    ("true", "%", "4294967301", "1"),
+    ("true", "%", r#"" 12 ""#, "1"),
+    ("true", "%", r#""abc""#, "NaN"),
+    ("true", "%", "[2]", "1"),
+    ("true", "%", "{}", "NaN"),
+    ("-0", "%", "undefined", "NaN"),
+    ("-0", "%", "null", "NaN"),
+    ("-0", "%", "true", "-0"),
+    ("-0", "%", "-0", "NaN"),
+    ("-0", "%", "1.5", "-0"),
+    ("-0", "%", "-7", "-0"),
+    ("-0", "%", "NaN", "NaN"),
+    ("-0", "%", "Infinity", "-0"),
+    ("-0", "%", "4294967301", "-0"),
+    ("-0", "%", r#"" 12 ""#, "-0"),
+    ("-0", "%", r#""abc""#, "NaN"),
+    ("-0", "%", "[2]", "-0"),
+    ("-0", "%", "{}", "NaN"),
+    ("1.5", "%", "undefined", "NaN"),
+    ("1.5", "%", "null", "NaN"),
+    ("1.5", "%", "true", "0.5"),
+    ("1.5", "%", "-0", "NaN"),
+    ("1.5", "%", "1.5", "0"),
+    ("1.5", "%", "-7", "1.5"),
+    ("1.5", "%", "NaN", "NaN"),
+    ("1.5", "%", "Infinity", "1.5"),
+    ("1.5", "%", "4294967301", "1.5"),
+    ("1.5", "%", r#"" 12 ""#, "1.5"),
+    ("1.5", "%", r#""abc""#, "NaN"),
+    ("1.5", "%", "[2]", "1.5"),
+    ("1.5", "%", "{}", "NaN"),
+    ("-7", "%", "undefined", "NaN"),
+    ("-7", "%", "null", "NaN"),
+    ("-7", "%", "true", "-0"),
+    ("-7", "%", "-0", "NaN"),
+    ("-7", "%", "1.5", "-1"),
+    ("-7", "%", "-7", "-0"),
+    ("-7", "%", "NaN", "NaN"),
+    ("-7", "%", "Infinity", "-7"),
+    ("-7", "%", "4294967301", "-7"),
+    ("-7", "%", r#"" 12 ""#, "-7"),
+    ("-7", "%", r#""abc""#, "NaN"),
+    ("-7", "%", "[2]", "-1"),
+    ("-7", "%", "{}", "NaN"),
+    ("NaN", "%", "undefined", "NaN"),
+    ("NaN", "%", "null", "NaN"),
+    ("NaN", "%", "true", "NaN"),
+    ("NaN", "%", "-0", "NaN"),
+    ("NaN", "%", "1.5", "NaN"),
+    ("NaN", "%", "-7", "NaN"),
+    ("NaN", "%", "NaN", "NaN"),
+    ("NaN", "%", "Infinity", "NaN"),
+    ("NaN", "%", "4294967301", "NaN"),
+    ("NaN", "%", r#"" 12 ""#, "NaN"),
+    ("NaN", "%", r#""abc""#, "NaN"),
+    ("NaN", "%", "[2]", "NaN"),
+    ("NaN", "%", "{}", "NaN"),
+    ("Infinity", "%", "undefined", "NaN"),
+    ("Infinity", "%", "null", "NaN"),
+    ("Infinity", "%", "true", "NaN"),
+    ("Infinity", "%", "-0", "NaN"),
+    ("Infinity", "%", "1.5", "NaN"),
+    ("Infinity", "%", "-7", "NaN"),
+    ("Infinity", "%", "NaN", "NaN"),
+    ("Infinity", "%", "Infinity", "NaN"),
+    ("Infinity", "%", "4294967301", "NaN"),
+    ("Infinity", "%", r#"" 12 ""#, "NaN"),
+    ("Infinity", "%", r#""abc""#, "NaN"),
+    ("Infinity", "%", "[2]", "NaN"),
+    ("Infinity", "%", "{}", "NaN"),
+    ("4294967301", "%", "undefined", "NaN"),
+    ("4294967301", "%", "null", "NaN"),
+    ("4294967301", "%", "true", "0"),
+    ("4294967301", "%", "-0", "NaN"),
+    ("4294967301", "%", "1.5", "0"),
+    ("4294967301", "%", "-7", "2"),
+    ("4294967301", "%", "NaN", "NaN"),
+    ("4294967301", "%", "Infinity", "4294967301"),
+    ("4294967301", "%", "4294967301", "0"),
+    ("4294967301", "%", r#"" 12 ""#, "9"),
+    ("4294967301", "%", r#""abc""#, "NaN"),
+    ("4294967301", "%", "[2]", "1"),
+    ("4294967301", "%", "{}", "NaN"),
+    (r#"" 12 ""#, "%", "undefined", "NaN"),
+    (r#"" 12 ""#, "%", "null", "NaN"),
+    (r#"" 12 ""#, "%", "true", "0"),
+    (r#"" 12 ""#, "%", "-0", "NaN"),
+    (r#"" 12 ""#, "%", "1.5", "0"),
+    (r#"" 12 ""#, "%", "-7", "5"),
+    (r#"" 12 ""#, "%", "NaN", "NaN"),
+    (r#"" 12 ""#, "%", "Infinity", "12"),
+    (r#"" 12 ""#, "%", "4294967301", "12"),
+    (r#"" 12 ""#, "%", r#"" 12 ""#, "0"),
+    (r#"" 12 ""#, "%", r#""abc""#, "NaN"),
+    (r#"" 12 ""#, "%", "[2]", "0"),
+    (r#"" 12 ""#, "%", "{}", "NaN"),
+    (r#""abc""#, "%", "undefined", "NaN"),
+    (r#""abc""#, "%", "null", "NaN"),
+    (r#""abc""#, "%", "true", "NaN"),
+    (r#""abc""#, "%", "-0", "NaN"),
+    (r#""abc""#, "%", "1.5", "NaN"),
+    (r#""abc""#, "%", "-7", "NaN"),
+    (r#""abc""#, "%", "NaN", "NaN"),
+    (r#""abc""#, "%", "Infinity", "NaN"),
+    (r#""abc""#, "%", "4294967301", "NaN"),
+    (r#""abc""#, "%", r#"" 12 ""#, "NaN"),
+    (r#""abc""#, "%", r#""abc""#, "NaN"),
+    (r#""abc""#, "%", "[2]", "NaN"),
+    (r#""abc""#, "%", "{}", "NaN"),
+    ("[2]", "%", "undefined", "NaN"),
+    ("[2]", "%", "null", "NaN"),
+    ("[2]", "%", "true", "0"),
+    ("[2]", "%", "-0", "NaN"),
+    ("[2]", "%", "1.5", "0.5"),
+    ("[2]", "%", "-7", "2"),
+    ("[2]", "%", "NaN", "NaN"),
+    ("[2]", "%", "Infinity", "2"),
+    ("[2]", "%", "4294967301", "2"),
+    ("[2]", "%", r#"" 12 ""#, "2"),
+    ("[2]", "%", r#""abc""#, "NaN"),
+    ("[2]", "%", "[2]", "0"),
+    ("[2]", "%", "{}", "NaN"),
+    ("{}", "%", "undefined", "NaN"),
+    ("{}", "%", "null", "NaN"),
+    ("{}", "%", "true", "NaN"),
+    ("{}", "%", "-0", "NaN"),
+    ("{}", "%", "1.5", "NaN"),
+    ("{}", "%", "-7", "NaN"),
+    ("{}", "%", "NaN", "NaN"),
+    ("{}", "%", "Infinity", "NaN"),
+    ("{}", "%", "4294967301", "NaN"),
+    ("{}", "%", r#"" 12 ""#, "NaN"),
+    ("{}", "%", r#""abc""#, "NaN"),
+    ("{}", "%", "[2]", "NaN"),
+    ("{}", "%", "{}", "NaN"),
+    ("undefined", "&", "undefined", "0"),
+    ("undefined", "&", "null", "0"),
+    ("undefined", "&", "true", "0"),
+    ("undefined", "&", "-0", "0"),
+    ("undefined", "&", "1.5", "0"),
+    ("undefined", "&", "-7", "0"),
+    ("undefined", "&", "NaN", "0"),
+    ("undefined", "&", "Infinity", "0"),
+    ("undefined", "&", "4294967301", "0"),
+    ("undefined", "&", r#"" 12 ""#, "0"),
+    ("undefined", "&", r#""abc""#, "0"),
+    ("undefined", "&", "[2]", "0"),
+    ("undefined", "&", "{}", "0"),
+    ("null", "&", "undefined", "0"),
+    ("null", "&", "null", "0"),
+    ("null", "&", "true", "0"),
+    ("null", "&", "-0", "0"),
+    ("null", "&", "1.5", "0"),
+    ("null", "&", "-7", "0"),
+    ("null", "&", "NaN", "0"),
+    ("null", "&", "Infinity", "0"),
+    ("null", "&", "4294967301", "0"),
+    ("null", "&", r#"" 12 ""#, "0"),
+    ("null", "&", r#""abc""#, "0"),
+    ("null", "&", "[2]", "0"),
+    ("null", "&", "{}", "0"),
+    ("true", "&", "undefined", "0"),
+    ("true", "&", "null", "0"),
+    ("true", "&", "true", "1"),
+    ("true", "&", "-0", "0"),
+    ("true", "&", "1.5", "1"),
+    ("true", "&", "-7", "1"),
+    ("true", "&", "NaN", "0"),
+    ("true", "&", "Infinity", "0"),
+    ("true", "&", "4294967301", "1"),
+    ("true", "&", r#"" 12 ""#, "0"),
+    ("true", "&", r#""abc""#, "0"),
+    ("true", "&", "[2]", "0"),
+    ("true", "&", "{}", "0"),
+    ("-0", "&", "undefined", "0"),
+    ("-0", "&", "null", "0"),
+    ("-0", "&", "true", "0"),
+    ("-0", "&", "-0", "0"),
+    ("-0", "&", "1.5", "0"),
+    ("-0", "&", "-7", "0"),
+    ("-0", "&", "NaN", "0"),
+    ("-0", "&", "Infinity", "0"),
+    ("-0", "&", "4294967301", "0"),
+    ("-0", "&", r#"" 12 ""#, "0"),
+    ("-0", "&", r#""abc""#, "0"),
+    ("-0", "&", "[2]", "0"),
+    ("-0", "&", "{}", "0"),
+    ("1.5", "&", "undefined", "0"),
+    ("1.5", "&", "null", "0"),
+    ("1.5", "&", "true", "1"),
+    ("1.5", "&", "-0", "0"),
+    ("1.5", "&", "1.5", "1"),
+    ("1.5", "&", "-7", "1"),
+    ("1.5", "&", "NaN", "0"),
+    ("1.5", "&", "Infinity", "0"),
+    ("1.5", "&", "4294967301", "1"),
+    ("1.5", "&", r#"" 12 ""#, "0"),
+    ("1.5", "&", r#""abc""#, "0"),
+    ("1.5", "&", "[2]", "0"),
+    ("1.5", "&", "{}", "0"),
+    ("-7", "&", "undefined", "0"),
+    ("-7", "&", "null", "0"),
+    ("-7", "&", "true", "1"),
+    ("-7", "&", "-0", "0"),
+    ("-7", "&", "1.5", "1"),
+    ("-7", "&", "-7", "-7"),
+    ("-7", "&", "NaN", "0"),
+    ("-7", "&", "Infinity", "0"),
+    ("-7", "&", "4294967301", "1"),
+    ("-7", "&", r#"" 12 ""#, "8"),
+    ("-7", "&", r#""abc""#, "0"),
+    ("-7", "&", "[2]", "0"),
+    ("-7", "&", "{}", "0"),
+    ("NaN", "&", "undefined", "0"),
+    ("NaN", "&", "null", "0"),
+    ("NaN", "&", "true", "0"),
+    ("NaN", "&", "-0", "0"),
+    ("NaN", "&", "1.5", "0"),
+    ("NaN", "&", "-7", "0"),
+    ("NaN", "&", "NaN", "0"),
+    ("NaN", "&", "Infinity", "0"),
+    ("NaN", "&", "4294967301", "0"),
+    ("NaN", "&", r#"" 12 ""#, "0"),
+    ("NaN", "&", r#""abc""#, "0"),
+    ("NaN", "&", "[2]", "0"),
+    ("NaN", "&", "{}", "0"),
+    ("Infinity", "&", "undefined", "0"),
+    ("Infinity", "&", "null", "0"),
+    ("Infinity", "&", "true", "0"),
+    ("Infinity", "&", "-0", "0"),
+    ("Infinity", "&", "1.5", "0"),
+    ("Infinity", "&", "-7", "0"),
+    ("Infinity", "&", "NaN", "0"),
+    ("Infinity", "&", "Infinity", "0"),
+    ("Infinity", "&", "4294967301", "0"),
+    ("Infinity", "&", r#"" 12 ""#, "0"),
+    ("Infinity", "&", r#""abc""#, "0"),
+    ("Infinity", "&", "[2]", "0"),
+    ("Infinity", "&", "{}", "0"),
+    ("4294967301", "&", "undefined", "0"),
+    ("4294967301", "&", "null", "0"),
+    ("4294967301", "&", "true", "1"),
+    ("4294967301", "&", "-0", "0"),
+    ("4294967301", "&", "1.5", "1"),
+    ("4294967301", "&", "-7", "1"),
+    ("4294967301", "&", "NaN", "0"),
+    ("4294967301", "&", "Infinity", "0"),
+    ("4294967301", "&", "4294967301", "5"),
+    ("4294967301", "&", r#"" 12 ""#, "4"),
+    ("4294967301", "&", r#""abc""#, "0"),
+    ("4294967301", "&", "[2]", "0"),
+    ("4294967301", "&", "{}", "0"),
+    (r#"" 12 ""#, "&", "undefined", "0"),
+    (r#"" 12 ""#, "&", "null", "0"),
+    (r#"" 12 ""#, "&", "true", "0"),
+    (r#"" 12 ""#, "&", "-0", "0"),
+    (r#"" 12 ""#, "&", "1.5", "0"),
+    (r#"" 12 ""#, "&", "-7", "8"),
+    (r#"" 12 ""#, "&", "NaN", "0"),
+    (r#"" 12 ""#, "&", "Infinity", "0"),
+    (r#"" 12 ""#, "&", "4294967301", "4"),
+    (r#"" 12 ""#, "&", r#"" 12 ""#, "12"),
+    (r#"" 12 ""#, "&", r#""abc""#, "0"),
+    (r#"" 12 ""#, "&", "[2]", "0"),
+    (r#"" 12 ""#, "&", "{}", "0"),
+    (r#""abc""#, "&", "undefined", "0"),
+    (r#""abc""#, "&", "null", "0"),
+    (r#""abc""#, "&", "true", "0"),
+    (r#""abc""#, "&", "-0", "0"),
+    (r#""abc""#, "&", "1.5", "0"),
+    (r#""abc""#, "&", "-7", "0"),
+    (r#""abc""#, "&", "NaN", "0"),
+    (r#""abc""#, "&", "Infinity", "0"),
+    (r#""abc""#, "&", "4294967301", "0"),
+    (r#""abc""#, "&", r#"" 12 ""#, "0"),
+    (r#""abc""#, "&", r#""abc""#, "0"),
+    (r#""abc""#, "&", "[2]", "0"),
+    (r#""abc""#, "&", "{}", "0"),
+    ("[2]", "&", "undefined", "0"),
+    ("[2]", "&", "null", "0"),
+    ("[2]", "&", "true", "0"),
+    ("[2]", "&", "-0", "0"),
+    ("[2]", "&", "1.5", "0"),
+    ("[2]", "&", "-7", "0"),
+    ("[2]", "&", "NaN", "0"),
+    ("[2]", "&", "Infinity", "0"),
+    ("[2]", "&", "4294967301", "0"),
+    ("[2]", "&", r#"" 12 ""#, "0"),
+    ("[2]", "&", r#""abc""#, "0"),
+    ("[2]", "&", "[2]", "2"),
+    ("[2]", "&", "{}", "0"),
+    ("{}", "&", "undefined", "0"),
+    ("{}", "&", "null", "0"),
+    ("{}", "&", "true", "0"),
+    ("{}", "&", "-0", "0"),
+    ("{}", "&", "1.5", "0"),
+    ("{}", "&", "-7", "0"),
+    ("{}", "&", "NaN", "0"),
+    ("{}", "&", "Infinity", "0"),
+    ("{}", "&", "4294967301", "0"),
+    ("{}", "&", r#"" 12 ""#, "0"),
+    ("{}", "&", r#""abc""#, "0"),
+    ("{}", "&", "[2]", "0"),
+    ("{}", "&", "{}", "0"),
+    ("undefined", "|", "undefined", "0"),
+    ("undefined", "|", "null", "0"),
+    ("undefined", "|", "true", "1"),
+    ("undefined", "|", "-0", "0"),
+    ("undefined", "|", "1.5", "1"),
+    ("undefined", "|", "-7", "-7"),
+    ("undefined", "|", "NaN", "0"),
+    ("undefined", "|", "Infinity", "0"),
+    ("undefined", "|", "4294967301", "5"),
+    ("undefined", "|", r#"" 12 ""#, "12"),
+    ("undefined", "|", r#""abc""#, "0"),
+    ("undefined", "|", "[2]", "2"),
+    ("undefined", "|", "{}", "0"),
+    ("null", "|", "undefined", "0"),
+    ("null", "|", "null", "0"),
+    ("null", "|", "true", "1"),
+    ("null", "|", "-0", "0"),
+    ("null", "|", "1.5", "1"),
+    ("null", "|", "-7", "-7"),
+    ("null", "|", "NaN", "0"),
+    ("null", "|", "Infinity", "0"),
+    ("null", "|", "4294967301", "5"),
+    ("null", "|", r#"" 12 ""#, "12"),
+    ("null", "|", r#""abc""#, "0"),
+    ("null", "|", "[2]", "2"),
+    ("null", "|", "{}", "0"),
+    ("true", "|", "undefined", "1"),
+    ("true", "|", "null", "1"),
+    ("true", "|", "true", "1"),
+    ("true", "|", "-0", "1"),
+    ("true", "|", "1.5", "1"),
+    ("true", "|", "-7", "-7"),
+    ("true", "|", "NaN", "1"),
+    ("true", "|", "Infinity", "1"),
+    ("true", "|", "4294967301", "5"),
+    ("true", "|", r#"" 12 ""#, "13"),
+    ("true", "|", r#""abc""#, "1"),
+    ("true", "|", "[2]", "3"),
+    ("true", "|", "{}", "1"),
+    ("-0", "|", "undefined", "0"),
+    ("-0", "|", "null", "0"),
+    ("-0", "|", "true", "1"),
+    ("-0", "|", "-0", "0"),
+    ("-0", "|", "1.5", "1"),
+    ("-0", "|", "-7", "-7"),
+    ("-0", "|", "NaN", "0"),
+    ("-0", "|", "Infinity", "0"),
+    ("-0", "|", "4294967301", "5"),
+    ("-0", "|", r#"" 12 ""#, "12"),
+    ("-0", "|", r#""abc""#, "0"),
+    ("-0", "|", "[2]", "2"),
+    ("-0", "|", "{}", "0"),
+    ("1.5", "|", "undefined", "1"),
+    ("1.5", "|", "null", "1"),
+    ("1.5", "|", "true", "1"),
+    ("1.5", "|", "-0", "1"),
+    ("1.5", "|", "1.5", "1"),
+    ("1.5", "|", "-7", "-7"),
+    ("1.5", "|", "NaN", "1"),
+    ("1.5", "|", "Infinity", "1"),
+    ("1.5", "|", "4294967301", "5"),
+    ("1.5", "|", r#"" 12 ""#, "13"),
+    ("1.5", "|", r#""abc""#, "1"),
+    ("1.5", "|", "[2]", "3"),
+    ("1.5", "|", "{}", "1"),
+    ("-7", "|", "undefined", "-7"),
+    ("-7", "|", "null", "-7"),
+    ("-7", "|", "true", "-7"),
+    ("-7", "|", "-0", "-7"),
+    ("-7", "|", "1.5", "-7"),
+    ("-7", "|", "-7", "-7"),
+    ("-7", "|", "NaN", "-7"),
+    ("-7", "|", "Infinity", "-7"),
+    ("-7", "|", "4294967301", "-3"),
+    ("-7", "|", r#"" 12 ""#, "-3"),
+    ("-7", "|", r#""abc""#, "-7"),
+    ("-7", "|", "[2]", "-5"),
+    ("-7", "|", "{}", "-7"),
+    ("NaN", "|", "undefined", "0"),
+    ("NaN", "|", "null", "0"),
+    ("NaN", "|", "true", "1"),
+    ("NaN", "|", "-0", "0"),
+    ("NaN", "|", "1.5", "1"),
+    ("NaN", "|", "-7", "-7"),
+    ("NaN", "|", "NaN", "0"),
+    ("NaN", "|", "Infinity", "0"),
+    ("NaN", "|", "4294967301", "5"),
+    ("NaN", "|", r#"" 12 ""#, "12"),
+    ("NaN", "|", r#""abc""#, "0"),
+    ("NaN", "|", "[2]", "2"),
+    ("NaN", "|", "{}", "0"),
+    ("Infinity", "|", "undefined", "0"),
+    ("Infinity", "|", "null", "0"),
+    ("Infinity", "|", "true", "1"),
+    ("Infinity", "|", "-0", "0"),
+    ("Infinity", "|", "1.5", "1"),
+    ("Infinity", "|", "-7", "-7"),
+    ("Infinity", "|", "NaN", "0"),
+    ("Infinity", "|", "Infinity", "0"),
+    ("Infinity", "|", "4294967301", "5"),
+    ("Infinity", "|", r#"" 12 ""#, "12"),
+    ("Infinity", "|", r#""abc""#, "0"),
+    ("Infinity", "|", "[2]", "2"),
+    ("Infinity", "|", "{}", "0"),
+    ("4294967301", "|", "undefined", "5"),
+    ("4294967301", "|", "null", "5"),
+    ("4294967301", "|", "true", "5"),
+    ("4294967301", "|", "-0", "5"),
+    ("4294967301", "|", "1.5", "5"),
+    ("4294967301", "|", "-7", "-3"),
+    ("4294967301", "|", "NaN", "5"),
+    ("4294967301", "|", "Infinity", "5"),
+    ("4294967301", "|", "4294967301", "5"),
+    ("4294967301", "|", r#"" 12 ""#, "13"),
+    ("4294967301", "|", r#""abc""#, "5"),
+    ("4294967301", "|", "[2]", "7"),
+    ("4294967301", "|", "{}", "5"),
+    (r#"" 12 ""#, "|", "undefined", "12"),
+    (r#"" 12 ""#, "|", "null", "12"),
+    (r#"" 12 ""#, "|", "true", "13"),
+    (r#"" 12 ""#, "|", "-0", "12"),
+    (r#"" 12 ""#, "|", "1.5", "13"),
+    (r#"" 12 ""#, "|", "-7", "-3"),
+    (r#"" 12 ""#, "|", "NaN", "12"),
+    (r#"" 12 ""#, "|", "Infinity", "12"),
+    (r#"" 12 ""#, "|", "4294967301", "13"),
+    (r#"" 12 ""#, "|", r#"" 12 ""#, "12"),
+    (r#"" 12 ""#, "|", r#""abc""#, "12"),
+    (r#"" 12 ""#, "|", "[2]", "14"),
+    (r#"" 12 ""#, "|", "{}", "12"),
+    (r#""abc""#, "|", "undefined", "0"),
+    (r#""abc""#, "|", "null", "0"),
+    (r#""abc""#, "|", "true", "1"),
+    (r#""abc""#, "|", "-0", "0"),
+    (r#""abc""#, "|", "1.5", "1"),
+    (r#""abc""#, "|", "-7", "-7"),
+    (r#""abc""#, "|", "NaN", "0"),
+    (r#""abc""#, "|", "Infinity", "0"),
+    (r#""abc""#, "|", "4294967301", "5"),
+    (r#""abc""#, "|", r#"" 12 ""#, "12"),
+    (r#""abc""#, "|", r#""abc""#, "0"),
+    (r#""abc""#, "|", "[2]", "2"),
+    (r#""abc""#, "|", "{}", "0"),
+    ("[2]", "|", "undefined", "2"),
+    ("[2]", "|", "null", "2"),
+    ("[2]", "|", "true", "3"),
+    ("[2]", "|", "-0", "2"),
+    ("[2]", "|", "1.5", "3"),
+    ("[2]", "|", "-7", "-5"),
+    ("[2]", "|", "NaN", "2"),
+    ("[2]", "|", "Infinity", "2"),
+    ("[2]", "|", "4294967301", "7"),
+    ("[2]", "|", r#"" 12 ""#, "14"),
+    ("[2]", "|", r#""abc""#, "2"),
+    ("[2]", "|", "[2]", "2"),
+    ("[2]", "|", "{}", "2"),
+    ("{}", "|", "undefined", "0"),
+    ("{}", "|", "null", "0"),
+    ("{}", "|", "true", "1"),
+    ("{}", "|", "-0", "0"),
+    ("{}", "|", "1.5", "1"),
+    ("{}", "|", "-7", "-7"),
+    ("{}", "|", "NaN", "0"),
+    ("{}", "|", "Infinity", "0"),
+    ("{}", "|", "4294967301", "5"),
+    ("{}", "|", r#"" 12 ""#, "12"),
+    ("{}", "|", r#""abc""#, "0"),
+    ("{}", "|", "[2]", "2"),
+    ("{}", "|", "{}", "0"),
+    ("undefined", "^", "undefined", "0"),
+    ("undefined", "^", "null", "0"),
+    ("undefined", "^", "true", "1"),
+    ("undefined", "^", "-0", "0"),
+    ("undefined", "^", "1.5", "1"),
+    ("undefined", "^", "-7", "-7"),
+    ("undefined", "^", "NaN", "0"),
+    ("undefined", "^", "Infinity", "0"),
+    ("undefined", "^", "4294967301", "5"),
+    ("undefined", "^", r#"" 12 ""#, "12"),
+    ("undefined", "^", r#""abc""#, "0"),
+    ("undefined", "^", "[2]", "2"),
+    ("undefined", "^", "{}", "0"),
+    ("null", "^", "undefined", "0"),
+    ("null", "^", "null", "0"),
+    ("null", "^", "true", "1"),
+    ("null", "^", "-0", "0"),
+    ("null", "^", "1.5", "1"),
+    ("null", "^", "-7", "-7"),
+    ("null", "^", "NaN", "0"),
+    ("null", "^", "Infinity", "0"),
+    ("null", "^", "4294967301", "5"),
+    ("null", "^", r#"" 12 ""#, "12"),
+    ("null", "^", r#""abc""#, "0"),
+    ("null", "^", "[2]", "2"),
+    ("null", "^", "{}", "0"),
+    ("true", "^", "undefined", "1"),
+    ("true", "^", "null", "1"),
+    ("true", "^", "true", "0"),
+    ("true", "^", "-0", "1"),
+    ("true", "^", "1.5", "0"),
+    ("true", "^", "-7", "-8"),
+    ("true", "^", "NaN", "1"),
+    ("true", "^", "Infinity", "1"),
+    ("true", "^", "4294967301", "4"),
+    ("true", "^", r#"" 12 ""#, "13"),
+    ("true", "^", r#""abc""#, "1"),
+    ("true", "^", "[2]", "3"),
+    ("true", "^", "{}", "1"),
+    ("-0", "^", "undefined", "0"),
+    ("-0", "^", "null", "0"),
+    ("-0", "^", "true", "1"),
+    ("-0", "^", "-0", "0"),
+    ("-0", "^", "1.5", "1"),
+    ("-0", "^", "-7", "-7"),
+    ("-0", "^", "NaN", "0"),
+    ("-0", "^", "Infinity", "0"),
+    ("-0", "^", "4294967301", "5"),
+    ("-0", "^", r#"" 12 ""#, "12"),
+    ("-0", "^", r#""abc""#, "0"),
+    ("-0", "^", "[2]", "2"),
+    ("-0", "^", "{}", "0"),
+    ("1.5", "^", "undefined", "1"),
+    ("1.5", "^", "null", "1"),
+    ("1.5", "^", "true", "0"),
+    ("1.5", "^", "-0", "1"),
+    ("1.5", "^", "1.5", "0"),
+    ("1.5", "^", "-7", "-8"),
+    ("1.5", "^", "NaN", "1"),
+    ("1.5", "^", "Infinity", "1"),
+    ("1.5", "^", "4294967301", "4"),
+    ("1.5", "^", r#"" 12 ""#, "13"),
+    ("1.5", "^", r#""abc""#, "1"),
+    ("1.5", "^", "[2]", "3"),
+    ("1.5", "^", "{}", "1"),
+    ("-7", "^", "undefined", "-7"),
+    ("-7", "^", "null", "-7"),
+    ("-7", "^", "true", "-8"),
+    ("-7", "^", "-0", "-7"),
+    ("-7", "^", "1.5", "-8"),
+    ("-7", "^", "-7", "0"),
+    ("-7", "^", "NaN", "-7"),
+    ("-7", "^", "Infinity", "-7"),
+    ("-7", "^", "4294967301", "-4"),
+    ("-7", "^", r#"" 12 ""#, "-11"),
+    ("-7", "^", r#""abc""#, "-7"),
+    ("-7", "^", "[2]", "-5"),
+    ("-7", "^", "{}", "-7"),
+    ("NaN", "^", "undefined", "0"),
+    ("NaN", "^", "null", "0"),
+    ("NaN", "^", "true", "1"),
+    ("NaN", "^", "-0", "0"),
+    ("NaN", "^", "1.5", "1"),
+    ("NaN", "^", "-7", "-7"),
+    ("NaN", "^", "NaN", "0"),
+    ("NaN", "^", "Infinity", "0"),
+    ("NaN", "^", "4294967301", "5"),
+    ("NaN", "^", r#"" 12 ""#, "12"),
+    ("NaN", "^", r#""abc""#, "0"),
+    ("NaN", "^", "[2]", "2"),
+    ("NaN", "^", "{}", "0"),
+    ("Infinity", "^", "undefined", "0"),
+    ("Infinity", "^", "null", "0"),
+    ("Infinity", "^", "true", "1"),
+    ("Infinity", "^", "-0", "0"),
+    ("Infinity", "^", "1.5", "1"),
+    ("Infinity", "^", "-7", "-7"),
+    ("Infinity", "^", "NaN", "0"),
+    ("Infinity", "^", "Infinity", "0"),
+    ("Infinity", "^", "4294967301", "5"),
+    ("Infinity", "^", r#"" 12 ""#, "12"),
+    ("Infinity", "^", r#""abc""#, "0"),
+    ("Infinity", "^", "[2]", "2"),
+    ("Infinity", "^", "{}", "0"),
+    ("4294967301", "^", "undefined", "5"),
+    ("4294967301", "^", "null", "5"),
+    ("4294967301", "^", "true", "4"),
+    ("4294967301", "^", "-0", "5"),
+    ("4294967301", "^", "1.5", "4"),
+    ("4294967301", "^", "-7", "-4"),
+    ("4294967301", "^", "NaN", "5"),
+    ("4294967301", "^", "Infinity", "5"),
+    ("4294967301", "^", "4294967301", "0"),
+    ("4294967301", "^", r#"" 12 ""#, "9"),
+    ("4294967301", "^", r#""abc""#, "5"),
+    ("4294967301", "^", "[2]", "7"),
+    ("4294967301", "^", "{}", "5"),
+    (r#"" 12 ""#, "^", "undefined", "12"),
+    (r#"" 12 ""#, "^", "null", "12"),
+    (r#"" 12 ""#, "^", "true", "13"),
+    (r#"" 12 ""#, "^", "-0", "12"),
+    (r#"" 12 ""#, "^", "1.5", "13"),
+    (r#"" 12 ""#, "^", "-7", "-11"),
+    (r#"" 12 ""#, "^", "NaN", "12"),
+    (r#"" 12 ""#, "^", "Infinity", "12"),
+    (r#"" 12 ""#, "^", "4294967301", "9"),
+    (r#"" 12 ""#, "^", r#"" 12 ""#, "0"),
+    (r#"" 12 ""#, "^", r#""abc""#, "12"),
+    (r#"" 12 ""#, "^", "[2]", "14"),
+    (r#"" 12 ""#, "^", "{}", "12"),
+    (r#""abc""#, "^", "undefined", "0"),
+    (r#""abc""#, "^", "null", "0"),
+    (r#""abc""#, "^", "true", "1"),
+    (r#""abc""#, "^", "-0", "0"),
+    (r#""abc""#, "^", "1.5", "1"),
+    (r#""abc""#, "^", "-7", "-7"),
+    (r#""abc""#, "^", "NaN", "0"),
+    (r#""abc""#, "^", "Infinity", "0"),
+    (r#""abc""#, "^", "4294967301", "5"),
+    (r#""abc""#, "^", r#"" 12 ""#, "12"),
+    (r#""abc""#, "^", r#""abc""#, "0"),
+    (r#""abc""#, "^", "[2]", "2"),
+    (r#""abc""#, "^", "{}", "0"),
+    ("[2]", "^", "undefined", "2"),
+    ("[2]", "^", "null", "2"),
+    ("[2]", "^", "true", "3"),
+    ("[2]", "^", "-0", "2"),
+    ("[2]", "^", "1.5", "3"),
+    ("[2]", "^", "-7", "-5"),
+    ("[2]", "^", "NaN", "2"),
+    ("[2]", "^", "Infinity", "2"),
+    ("[2]", "^", "4294967301", "7"),
+    ("[2]", "^", r#"" 12 ""#, "14"),
+    ("[2]", "^", r#""abc""#, "2"),
+    ("[2]", "^", "[2]", "0"),
+    ("[2]", "^", "{}", "2"),
+    ("{}", "^", "undefined", "0"),
+    ("{}", "^", "null", "0"),
+    ("{}", "^", "true", "1"),
+    ("{}", "^", "-0", "0"),
+    ("{}", "^", "1.5", "1"),
+    ("{}", "^", "-7", "-7"),
+    ("{}", "^", "NaN", "0"),
+    ("{}", "^", "Infinity", "0"),
+    ("{}", "^", "4294967301", "5"),
+    ("{}", "^", r#"" 12 ""#, "12"),
+    ("{}", "^", r#""abc""#, "0"),
+    ("{}", "^", "[2]", "2"),
+    ("{}", "^", "{}", "0"),
+    ("undefined", "<<", "undefined", "0"),
+    ("undefined", "<<", "null", "0"),
+    ("undefined", "<<", "true", "0"),
+    ("undefined", "<<", "-0", "0"),
+    ("undefined", "<<", "1.5", "0"),
+    ("undefined", "<<", "-7", "0"),
+    ("undefined", "<<", "NaN", "0"),
+    ("undefined", "<<", "Infinity", "0"),
+    ("undefined", "<<", "4294967301", "0"),
+    ("undefined", "<<", r#"" 12 ""#, "0"),
+    ("undefined", "<<", r#""abc""#, "0"),
+    ("undefined", "<<", "[2]", "0"),
+    ("undefined", "<<", "{}", "0"),
+    ("null", "<<", "undefined", "0"),
+    ("null", "<<", "null", "0"),
+    ("null", "<<", "true", "0"),
+    ("null", "<<", "-0", "0"),
+    ("null", "<<", "1.5", "0"),
+    ("null", "<<", "-7", "0"),
+    ("null", "<<", "NaN", "0"),
+    ("null", "<<", "Infinity", "0"),
+    ("null", "<<", "4294967301", "0"),
+    ("null", "<<", r#"" 12 ""#, "0"),
+    ("null", "<<", r#""abc""#, "0"),
+    ("null", "<<", "[2]", "0"),
+    ("null", "<<", "{}", "0"),
+    ("true", "<<", "undefined", "1"),
+    ("true", "<<", "null", "1"),
+    ("true", "<<", "true", "2"),
+    ("true", "<<", "-0", "1"),
+    ("true", "<<", "1.5", "2"),
+    ("true", "<<", "-7", "33554432"),
+    ("true", "<<", "NaN", "1"),
+    ("true", "<<", "Infinity", "1"),
+    ("true", "<<", "4294967301", "32"),
+    ("true", "<<", r#"" 12 ""#, "4096"),
+    ("true", "<<", r#""abc""#, "1"),
+    ("true", "<<", "[2]", "4"),
+    ("true", "<<", "{}", "1"),
+    ("-0", "<<", "undefined", "0"),
+    ("-0", "<<", "null", "0"),
+    ("-0", "<<", "true", "0"),
+    ("-0", "<<", "-0", "0"),
+    ("-0", "<<", "1.5", "0"),
+    ("-0", "<<", "-7", "0"),
+    ("-0", "<<", "NaN", "0"),
+    ("-0", "<<", "Infinity", "0"),
+    ("-0", "<<", "4294967301", "0"),
+    ("-0", "<<", r#"" 12 ""#, "0"),
+    ("-0", "<<", r#""abc""#, "0"),
+    ("-0", "<<", "[2]", "0"),
+    ("-0", "<<", "{}", "0"),
+    ("1.5", "<<", "undefined", "1"),
+    ("1.5", "<<", "null", "1"),
+    ("1.5", "<<", "true", "2"),
+    ("1.5", "<<", "-0", "1"),
+    ("1.5", "<<", "1.5", "2"),
+    ("1.5", "<<", "-7", "33554432"),
+    ("1.5", "<<", "NaN", "1"),
+    ("1.5", "<<", "Infinity", "1"),
+    ("1.5", "<<", "4294967301", "32"),
+    ("1.5", "<<", r#"" 12 ""#, "4096"),
+    ("1.5", "<<", r#""abc""#, "1"),
+    ("1.5", "<<", "[2]", "4"),
+    ("1.5", "<<", "{}", "1"),
+    ("-7", "<<", "undefined", "-7"),
+    ("-7", "<<", "null", "-7"),
+    ("-7", "<<", "true", "-14"),
+    ("-7", "<<", "-0", "-7"),
+    ("-7", "<<", "1.5", "-14"),
+    ("-7", "<<", "-7", "-234881024"),
+    ("-7", "<<", "NaN", "-7"),
+    ("-7", "<<", "Infinity", "-7"),
+    ("-7", "<<", "4294967301", "-224"),
+    ("-7", "<<", r#"" 12 ""#, "-28672"),
+    ("-7", "<<", r#""abc""#, "-7"),
+    ("-7", "<<", "[2]", "-28"),
+    ("-7", "<<", "{}", "-7"),
+    ("NaN", "<<", "undefined", "0"),
+    ("NaN", "<<", "null", "0"),
+    ("NaN", "<<", "true", "0"),
+    ("NaN", "<<", "-0", "0"),
+    ("NaN", "<<", "1.5", "0"),
+    ("NaN", "<<", "-7", "0"),
+    ("NaN", "<<", "NaN", "0"),
+    ("NaN", "<<", "Infinity", "0"),
+    ("NaN", "<<", "4294967301", "0"),
+    ("NaN", "<<", r#"" 12 ""#, "0"),
+    ("NaN", "<<", r#""abc""#, "0"),
+    ("NaN", "<<", "[2]", "0"),
+    ("NaN", "<<", "{}", "0"),
+    ("Infinity", "<<", "undefined", "0"),
+    ("Infinity", "<<", "null", "0"),
+    ("Infinity", "<<", "true", "0"),
+    ("Infinity", "<<", "-0", "0"),
+    ("Infinity", "<<", "1.5", "0"),
+    ("Infinity", "<<", "-7", "0"),
+    ("Infinity", "<<", "NaN", "0"),
+    ("Infinity", "<<", "Infinity", "0"),
+    ("Infinity", "<<", "4294967301", "0"),
+    ("Infinity", "<<", r#"" 12 ""#, "0"),
+    ("Infinity", "<<", r#""abc""#, "0"),
+    ("Infinity", "<<", "[2]", "0"),
+    ("Infinity", "<<", "{}", "0"),
+    ("4294967301", "<<", "undefined", "5"),
+    ("4294967301", "<<", "null", "5"),
+    ("4294967301", "<<", "true", "10"),
+    ("4294967301", "<<", "-0", "5"),
+    ("4294967301", "<<", "1.5", "10"),
+    ("4294967301", "<<", "-7", "167772160"),
+    ("4294967301", "<<", "NaN", "5"),
+    ("4294967301", "<<", "Infinity", "5"),
+    ("4294967301", "<<", "4294967301", "160"),
+    ("4294967301", "<<", r#"" 12 ""#, "20480"),
+    ("4294967301", "<<", r#""abc""#, "5"),
+    ("4294967301", "<<", "[2]", "20"),
+    ("4294967301", "<<", "{}", "5"),
+    (r#"" 12 ""#, "<<", "undefined", "12"),
+    (r#"" 12 ""#, "<<", "null", "12"),
+    (r#"" 12 ""#, "<<", "true", "24"),
+    (r#"" 12 ""#, "<<", "-0", "12"),
+    (r#"" 12 ""#, "<<", "1.5", "24"),
+    (r#"" 12 ""#, "<<", "-7", "402653184"),
+    (r#"" 12 ""#, "<<", "NaN", "12"),
+    (r#"" 12 ""#, "<<", "Infinity", "12"),
+    (r#"" 12 ""#, "<<", "4294967301", "384"),
+    (r#"" 12 ""#, "<<", r#"" 12 ""#, "49152"),
+    (r#"" 12 ""#, "<<", r#""abc""#, "12"),
+    (r#"" 12 ""#, "<<", "[2]", "48"),
+    (r#"" 12 ""#, "<<", "{}", "12"),
+    (r#""abc""#, "<<", "undefined", "0"),
+    (r#""abc""#, "<<", "null", "0"),
+    (r#""abc""#, "<<", "true", "0"),
+    (r#""abc""#, "<<", "-0", "0"),
+    (r#""abc""#, "<<", "1.5", "0"),
+    (r#""abc""#, "<<", "-7", "0"),
+    (r#""abc""#, "<<", "NaN", "0"),
+    (r#""abc""#, "<<", "Infinity", "0"),
+    (r#""abc""#, "<<", "4294967301", "0"),
+    (r#""abc""#, "<<", r#"" 12 ""#, "0"),
+    (r#""abc""#, "<<", r#""abc""#, "0"),
+    (r#""abc""#, "<<", "[2]", "0"),
+    (r#""abc""#, "<<", "{}", "0"),
+    ("[2]", "<<", "undefined", "2"),
+    ("[2]", "<<", "null", "2"),
+    ("[2]", "<<", "true", "4"),
+    ("[2]", "<<", "-0", "2"),
+    ("[2]", "<<", "1.5", "4"),
+    ("[2]", "<<", "-7", "67108864"),
+    ("[2]", "<<", "NaN", "2"),
+    ("[2]", "<<", "Infinity", "2"),
+    ("[2]", "<<", "4294967301", "64"),
+    ("[2]", "<<", r#"" 12 ""#, "8192"),
+    ("[2]", "<<", r#""abc""#, "2"),
+    ("[2]", "<<", "[2]", "8"),
+    ("[2]", "<<", "{}", "2"),
+    ("{}", "<<", "undefined", "0"),
+    ("{}", "<<", "null", "0"),
+    ("{}", "<<", "true", "0"),
+    ("{}", "<<", "-0", "0"),
+    ("{}", "<<", "1.5", "0"),
+    ("{}", "<<", "-7", "0"),
+    ("{}", "<<", "NaN", "0"),
+    ("{}", "<<", "Infinity", "0"),
+    ("{}", "<<", "4294967301", "0"),
+    ("{}", "<<", r#"" 12 ""#, "0"),
+    ("{}", "<<", r#""abc""#, "0"),
+    ("{}", "<<", "[2]", "0"),
+    ("{}", "<<", "{}", "0"),
+    ("undefined", ">>", "undefined", "0"),
+    ("undefined", ">>", "null", "0"),
+    ("undefined", ">>", "true", "0"),
+    ("undefined", ">>", "-0", "0"),
+    ("undefined", ">>", "1.5", "0"),
+    ("undefined", ">>", "-7", "0"),
+    ("undefined", ">>", "NaN", "0"),
+    ("undefined", ">>", "Infinity", "0"),
+    ("undefined", ">>", "4294967301", "0"),
+    ("undefined", ">>", r#"" 12 ""#, "0"),
+    ("undefined", ">>", r#""abc""#, "0"),
+    ("undefined", ">>", "[2]", "0"),
+    ("undefined", ">>", "{}", "0"),
+    ("null", ">>", "undefined", "0"),
+    ("null", ">>", "null", "0"),
+    ("null", ">>", "true", "0"),
+    ("null", ">>", "-0", "0"),
+    ("null", ">>", "1.5", "0"),
+    ("null", ">>", "-7", "0"),
+    ("null", ">>", "NaN", "0"),
+    ("null", ">>", "Infinity", "0"),
+    ("null", ">>", "4294967301", "0"),
+    ("null", ">>", r#"" 12 ""#, "0"),
+    ("null", ">>", r#""abc""#, "0"),
+    ("null", ">>", "[2]", "0"),
+    ("null", ">>", "{}", "0"),
+    ("true", ">>", "undefined", "1"),
+    ("true", ">>", "null", "1"),
+    ("true", ">>", "true", "0"),
+    ("true", ">>", "-0", "1"),
+    ("true", ">>", "1.5", "0"),
+    ("true", ">>", "-7", "0"),
+    ("true", ">>", "NaN", "1"),
+    ("true", ">>", "Infinity", "1"),
+    ("true", ">>", "4294967301", "0"),
+    ("true", ">>", r#"" 12 ""#, "0"),
+    ("true", ">>", r#""abc""#, "1"),
+    ("true", ">>", "[2]", "0"),
+    ("true", ">>", "{}", "1"),
+    ("-0", ">>", "undefined", "0"),
+    ("-0", ">>", "null", "0"),
+    ("-0", ">>", "true", "0"),
+    ("-0", ">>", "-0", "0"),
+    ("-0", ">>", "1.5", "0"),
+    ("-0", ">>", "-7", "0"),
+    ("-0", ">>", "NaN", "0"),
+    ("-0", ">>", "Infinity", "0"),
+    ("-0", ">>", "4294967301", "0"),
+    ("-0", ">>", r#"" 12 ""#, "0"),
+    ("-0", ">>", r#""abc""#, "0"),
+    ("-0", ">>", "[2]", "0"),
+    ("-0", ">>", "{}", "0"),
+    ("1.5", ">>", "undefined", "1"),
+    ("1.5", ">>", "null", "1"),
+    ("1.5", ">>", "true", "0"),
+    ("1.5", ">>", "-0", "1"),
+    ("1.5", ">>", "1.5", "0"),
+    ("1.5", ">>", "-7", "0"),
+    ("1.5", ">>", "NaN", "1"),
+    ("1.5", ">>", "Infinity", "1"),
+    ("1.5", ">>", "4294967301", "0"),
+    ("1.5", ">>", r#"" 12 ""#, "0"),
+    ("1.5", ">>", r#""abc""#, "1"),
+    ("1.5", ">>", "[2]", "0"),
+    ("1.5", ">>", "{}", "1"),
+    ("-7", ">>", "undefined", "-7"),
+    ("-7", ">>", "null", "-7"),
+    ("-7", ">>", "true", "-4"),
+    ("-7", ">>", "-0", "-7"),
+    ("-7", ">>", "1.5", "-4"),
+    ("-7", ">>", "-7", "-1"),
+    ("-7", ">>", "NaN", "-7"),
+    ("-7", ">>", "Infinity", "-7"),
+    ("-7", ">>", "4294967301", "-1"),
+    ("-7", ">>", r#"" 12 ""#, "-1"),
+    ("-7", ">>", r#""abc""#, "-7"),
+    ("-7", ">>", "[2]", "-2"),
+    ("-7", ">>", "{}", "-7"),
+    ("NaN", ">>", "undefined", "0"),
+    ("NaN", ">>", "null", "0"),
+    ("NaN", ">>", "true", "0"),
+    ("NaN", ">>", "-0", "0"),
+    ("NaN", ">>", "1.5", "0"),
+    ("NaN", ">>", "-7", "0"),
+    ("NaN", ">>", "NaN", "0"),
+    ("NaN", ">>", "Infinity", "0"),
+    ("NaN", ">>", "4294967301", "0"),
+    ("NaN", ">>", r#"" 12 ""#, "0"),
+    ("NaN", ">>", r#""abc""#, "0"),
+    ("NaN", ">>", "[2]", "0"),
+    ("NaN", ">>", "{}", "0"),
+    ("Infinity", ">>", "undefined", "0"),
+    ("Infinity", ">>", "null", "0"),
+    ("Infinity", ">>", "true", "0"),
+    ("Infinity", ">>", "-0", "0"),
+    ("Infinity", ">>", "1.5", "0"),
+    ("Infinity", ">>", "-7", "0"),
+    ("Infinity", ">>", "NaN", "0"),
+    ("Infinity", ">>", "Infinity", "0"),
+    ("Infinity", ">>", "4294967301", "0"),
+    ("Infinity", ">>", r#"" 12 ""#, "0"),
+    ("Infinity", ">>", r#""abc""#, "0"),
+    ("Infinity", ">>", "[2]", "0"),
+    ("Infinity", ">>", "{}", "0"),
+    ("4294967301", ">>", "undefined", "5"),
+    ("4294967301", ">>", "null", "5"),
+    ("4294967301", ">>", "true", "2"),
+    ("4294967301", ">>", "-0", "5"),
+    ("4294967301", ">>", "1.5", "2"),
+    ("4294967301", ">>", "-7", "0"),
+    ("4294967301", ">>", "NaN", "5"),
+    ("4294967301", ">>", "Infinity", "5"),
+    ("4294967301", ">>", "4294967301", "0"),
+    ("4294967301", ">>", r#"" 12 ""#, "0"),
+    ("4294967301", ">>", r#""abc""#, "5"),
+    ("4294967301", ">>", "[2]", "1"),
+    ("4294967301", ">>", "{}", "5"),
+    (r#"" 12 ""#, ">>", "undefined", "12"),
+    (r#"" 12 ""#, ">>", "null", "12"),
+    (r#"" 12 ""#, ">>", "true", "6"),
+    (r#"" 12 ""#, ">>", "-0", "12"),
+    (r#"" 12 ""#, ">>", "1.5", "6"),
+    (r#"" 12 ""#, ">>", "-7", "0"),
+    (r#"" 12 ""#, ">>", "NaN", "12"),
+    (r#"" 12 ""#, ">>", "Infinity", "12"),
+    (r#"" 12 ""#, ">>", "4294967301", "0"),
+    (r#"" 12 ""#, ">>", r#"" 12 ""#, "0"),
+    (r#"" 12 ""#, ">>", r#""abc""#, "12"),
+    (r#"" 12 ""#, ">>", "[2]", "3"),
+    (r#"" 12 ""#, ">>", "{}", "12"),
+    (r#""abc""#, ">>", "undefined", "0"),
+    (r#""abc""#, ">>", "null", "0"),
+    (r#""abc""#, ">>", "true", "0"),
+    (r#""abc""#, ">>", "-0", "0"),
+    (r#""abc""#, ">>", "1.5", "0"),
+    (r#""abc""#, ">>", "-7", "0"),
+    (r#""abc""#, ">>", "NaN", "0"),
+    (r#""abc""#, ">>", "Infinity", "0"),
+    (r#""abc""#, ">>", "4294967301", "0"),
+    (r#""abc""#, ">>", r#"" 12 ""#, "0"),
+    (r#""abc""#, ">>", r#""abc""#, "0"),
+    (r#""abc""#, ">>", "[2]", "0"),
+    (r#""abc""#, ">>", "{}", "0"),
+    ("[2]", ">>", "undefined", "2"),
+    ("[2]", ">>", "null", "2"),
+    ("[2]", ">>", "true", "1"),
+    ("[2]", ">>", "-0", "2"),
+    ("[2]", ">>", "1.5", "1"),
+    ("[2]", ">>", "-7", "0"),
+    ("[2]", ">>", "NaN", "2"),
+    ("[2]", ">>", "Infinity", "2"),
+    ("[2]", ">>", "4294967301", "0"),
+    ("[2]", ">>", r#"" 12 ""#, "0"),
+    ("[2]", ">>", r#""abc""#, "2"),
+    ("[2]", ">>", "[2]", "0"),
+    ("[2]", ">>", "{}", "2"),
+    ("{}", ">>", "undefined", "0"),
+    ("{}", ">>", "null", "0"),
+    ("{}", ">>", "true", "0"),
+    ("{}", ">>", "-0", "0"),
+    ("{}", ">>", "1.5", "0"),
+    ("{}", ">>", "-7", "0"),
+    ("{}", ">>", "NaN", "0"),
+    ("{}", ">>", "Infinity", "0"),
+    ("{}", ">>", "4294967301", "0"),
+    ("{}", ">>", r#"" 12 ""#, "0"),
+    ("{}", ">>", r#""abc""#, "0"),
+    ("{}", ">>", "[2]", "0"),
+    ("{}", ">>", "{}", "0"),
+    ("undefined", ">>>", "undefined", "0"),
+    ("undefined", ">>>", "null", "0"),
+    ("undefined", ">>>", "true", "0"),
+    ("undefined", ">>>", "-0", "0"),
+    ("undefined", ">>>", "1.5", "0"),
+    ("undefined", ">>>", "-7", "0"),
+    ("undefined", ">>>", "NaN", "0"),
+    ("undefined", ">>>", "Infinity", "0"),
+    ("undefined", ">>>", "4294967301", "0"),
+    ("undefined", ">>>", r#"" 12 ""#, "0"),
+    ("undefined", ">>>", r#""abc""#, "0"),
+    ("undefined", ">>>", "[2]", "0"),
+    ("undefined", ">>>", "{}", "0"),
+    ("null", ">>>", "undefined", "0"),
+    ("null", ">>>", "null", "0"),
+    ("null", ">>>", "true", "0"),
+    ("null", ">>>", "-0", "0"),
+    ("null", ">>>", "1.5", "0"),
+    ("null", ">>>", "-7", "0"),
+    ("null", ">>>", "NaN", "0"),
+    ("null", ">>>", "Infinity", "0"),
+    ("null", ">>>", "4294967301", "0"),
+    ("null", ">>>", r#"" 12 ""#, "0"),
+    ("null", ">>>", r#""abc""#, "0"),
+    ("null", ">>>", "[2]", "0"),
+    ("null", ">>>", "{}", "0"),
+    ("true", ">>>", "undefined", "1"),
+    ("true", ">>>", "null", "1"),
+    ("true", ">>>", "true", "0"),
+    ("true", ">>>", "-0", "1"),
+    ("true", ">>>", "1.5", "0"),
+    ("true", ">>>", "-7", "0"),
+    ("true", ">>>", "NaN", "1"),
+    ("true", ">>>", "Infinity", "1"),
+    ("true", ">>>", "4294967301", "0"),
+    ("true", ">>>", r#"" 12 ""#, "0"),
+    ("true", ">>>", r#""abc""#, "1"),
+    ("true", ">>>", "[2]", "0"),
+    ("true", ">>>", "{}", "1"),
+    ("-0", ">>>", "undefined", "0"),
+    ("-0", ">>>", "null", "0"),
+    ("-0", ">>>", "true", "0"),
+    ("-0", ">>>", "-0", "0"),
+    ("-0", ">>>", "1.5", "0"),
+    ("-0", ">>>", "-7", "0"),
+    ("-0", ">>>", "NaN", "0"),
+    ("-0", ">>>", "Infinity", "0"),
+    ("-0", ">>>", "4294967301", "0"),
+    ("-0", ">>>", r#"" 12 ""#, "0"),
+    ("-0", ">>>", r#""abc""#, "0"),
+    ("-0", ">>>", "[2]", "0"),
+    ("-0", ">>>", "{}", "0"),
+    ("1.5", ">>>", "undefined", "1"),
+    ("1.5", ">>>", "null", "1"),
+    ("1.5", ">>>", "true", "0"),
+    ("1.5", ">>>", "-0", "1"),
+    ("1.5", ">>>", "1.5", "0"),
+    ("1.5", ">>>", "-7", "0"),
+    ("1.5", ">>>", "NaN", "1"),
+    ("1.5", ">>>", "Infinity", "1"),
+    ("1.5", ">>>", "4294967301", "0"),
+    ("1.5", ">>>", r#"" 12 ""#, "0"),
+    ("1.5", ">>>", r#""abc""#, "1"),
+    ("1.5", ">>>", "[2]", "0"),
+    ("1.5", ">>>", "{}", "1"),
+    ("-7", ">>>", "undefined", "4294967289"),
+    ("-7", ">>>", "null", "4294967289"),
+    ("-7", ">>>", "true", "2147483644"),
+    ("-7", ">>>", "-0", "4294967289"),
+    ("-7", ">>>", "1.5", "2147483644"),
+    ("-7", ">>>", "-7", "127"),
+    ("-7", ">>>", "NaN", "4294967289"),
+    ("-7", ">>>", "Infinity", "4294967289"),
+    ("-7", ">>>", "4294967301", "134217727"),
+    ("-7", ">>>", r#"" 12 ""#, "1048575"),
+    ("-7", ">>>", r#""abc""#, "4294967289"),
+    ("-7", ">>>", "[2]", "1073741822"),
+    ("-7", ">>>", "{}", "4294967289"),
+    ("NaN", ">>>", "undefined", "0"),
+    ("NaN", ">>>", "null", "0"),
+    ("NaN", ">>>", "true", "0"),
+    ("NaN", ">>>", "-0", "0"),
+    ("NaN", ">>>", "1.5", "0"),
+    ("NaN", ">>>", "-7", "0"),
+    ("NaN", ">>>", "NaN", "0"),
+    ("NaN", ">>>", "Infinity", "0"),
+    ("NaN", ">>>", "4294967301", "0"),
+    ("NaN", ">>>", r#"" 12 ""#, "0"),
+    ("NaN", ">>>", r#""abc""#, "0"),
+    ("NaN", ">>>", "[2]", "0"),
+    ("NaN", ">>>", "{}", "0"),
+    ("Infinity", ">>>", "undefined", "0"),
+    ("Infinity", ">>>", "null", "0"),
+    ("Infinity", ">>>", "true", "0"),
+    ("Infinity", ">>>", "-0", "0"),
+    ("Infinity", ">>>", "1.5", "0"),
+    ("Infinity", ">>>", "-7", "0"),
+    ("Infinity", ">>>", "NaN", "0"),
+    ("Infinity", ">>>", "Infinity", "0"),
+    ("Infinity", ">>>", "4294967301", "0"),
+    ("Infinity", ">>>", r#"" 12 ""#, "0"),
+    ("Infinity", ">>>", r#""abc""#, "0"),
+    ("Infinity", ">>>", "[2]", "0"),
+    ("Infinity", ">>>", "{}", "0"),
+    ("4294967301", ">>>", "undefined", "5"),
+    ("4294967301", ">>>", "null", "5"),
+    ("4294967301", ">>>", "true", "2"),
+    ("4294967301", ">>>", "-0", "5"),
+    ("4294967301", ">>>", "1.5", "2"),
+    ("4294967301", ">>>", "-7", "0"),
+    ("4294967301", ">>>", "NaN", "5"),
+    ("4294967301", ">>>", "Infinity", "5"),
+    ("4294967301", ">>>", "4294967301", "0"),
+    ("4294967301", ">>>", r#"" 12 ""#, "0"),
+    ("4294967301", ">>>", r#""abc""#, "5"),
+    ("4294967301", ">>>", "[2]", "1"),
+    ("4294967301", ">>>", "{}", "5"),
+    (r#"" 12 ""#, ">>>", "undefined", "12"),
+    (r#"" 12 ""#, ">>>", "null", "12"),
+    (r#"" 12 ""#, ">>>", "true", "6"),
+    (r#"" 12 ""#, ">>>", "-0", "12"),
+    (r#"" 12 ""#, ">>>", "1.5", "6"),
+    (r#"" 12 ""#, ">>>", "-7", "0"),
+    (r#"" 12 ""#, ">>>", "NaN", "12"),
+    (r#"" 12 ""#, ">>>", "Infinity", "12"),
+    (r#"" 12 ""#, ">>>", "4294967301", "0"),
+    (r#"" 12 ""#, ">>>", r#"" 12 ""#, "0"),
+    (r#"" 12 ""#, ">>>", r#""abc""#, "12"),
+    (r#"" 12 ""#, ">>>", "[2]", "3"),
+    (r#"" 12 ""#, ">>>", "{}", "12"),
+    (r#""abc""#, ">>>", "undefined", "0"),
+    (r#""abc""#, ">>>", "null", "0"),
+    (r#""abc""#, ">>>", "true", "0"),
+    (r#""abc""#, ">>>", "-0", "0"),
+    (r#""abc""#, ">>>", "1.5", "0"),
+    (r#""abc""#, ">>>", "-7", "0"),
+    (r#""abc""#, ">>>", "NaN", "0"),
+    (r#""abc""#, ">>>", "Infinity", "0"),
+    (r#""abc""#, ">>>", "4294967301", "0"),
+    (r#""abc""#, ">>>", r#"" 12 ""#, "0"),
+    (r#""abc""#, ">>>", r#""abc""#, "0"),
+    (r#""abc""#, ">>>", "[2]", "0"),
+    (r#""abc""#, ">>>", "{}", "0"),
+    ("[2]", ">>>", "undefined", "2"),
+    ("[2]", ">>>", "null", "2"),
+    ("[2]", ">>>", "true", "1"),
+    ("[2]", ">>>", "-0", "2"),
+    ("[2]", ">>>", "1.5", "1"),
+    ("[2]", ">>>", "-7", "0"),
+    ("[2]", ">>>", "NaN", "2"),
+    ("[2]", ">>>", "Infinity", "2"),
+    ("[2]", ">>>", "4294967301", "0"),
+    ("[2]", ">>>", r#"" 12 ""#, "0"),
+    ("[2]", ">>>", r#""abc""#, "2"),
+    ("[2]", ">>>", "[2]", "0"),
+    ("[2]", ">>>", "{}", "2"),
+    ("{}", ">>>", "undefined", "0"),
+    ("{}", ">>>", "null", "0"),
+    ("{}", ">>>", "true", "0"),
+    ("{}", ">>>", "-0", "0"),
+    ("{}", ">>>", "1.5", "0"),
+    ("{}", ">>>", "-7", "0"),
+    ("{}", ">>>", "NaN", "0"),
+    ("{}", ">>>", "Infinity", "0"),
+    ("{}", ">>>", "4294967301", "0"),
+    ("{}", ">>>", r#"" 12 ""#, "0"),
+    ("{}", ">>>", r#""abc""#, "0"),
+    ("{}", ">>>", "[2]", "0"),
+    ("{}", ">>>", "{}", "0"),
+    ("undefined", "==", "undefined", "true"),
+    ("undefined", "==", "null", "true"),
+    ("undefined", "==", "true", "false"),
+    ("undefined", "==", "-0", "false"),
+    ("undefined", "==", "1.5", "false"),
+    ("undefined", "==", "-7", "false"),
+    ("undefined", "==", "NaN", "false"),
+    ("undefined", "==", "Infinity", "false"),
+    ("undefined", "==", "4294967301", "false"),
+    ("undefined", "==", r#"" 12 ""#, "false"),
+    ("undefined", "==", r#""abc""#, "false"),
+    ("undefined", "==", "[2]", "false"),
+    ("undefined", "==", "{}", "false"),
+    ("null", "==", "undefined", "true"),
+    ("null", "==", "null", "true"),
+    ("null", "==", "true", "false"),
+    ("null", "==", "-0", "false"),
+    ("null", "==", "1.5", "false"),
+    ("null", "==", "-7", "false"),
+    ("null", "==", "NaN", "false"),
+    ("null", "==", "Infinity", "false"),
+    ("null", "==", "4294967301", "false"),
+    ("null", "==", r#"" 12 ""#, "false"),
+    ("null", "==", r#""abc""#, "false"),
+    ("null", "==", "[2]", "false"),
+    ("null", "==", "{}", "false"),
+    ("true", "==", "undefined", "false"),
+    ("true", "==", "null", "false"),
+    ("true", "==", "true", "true"),
+    ("true", "==", "-0", "false"),
+    ("true", "==", "1.5", "false"),
+    ("true", "==", "-7", "false"),
+    ("true", "==", "NaN", "false"),
+    ("true", "==", "Infinity", "false"),
+    ("true", "==", "4294967301", "false"),
+    ("true", "==", r#"" 12 ""#, "false"),
+    ("true", "==", r#""abc""#, "false"),
+    ("true", "==", "[2]", "false"),
+    ("true", "==", "{}", "false"),
+    ("-0", "==", "undefined", "false"),
+    ("-0", "==", "null", "false"),
+    ("-0", "==", "true", "false"),
+    ("-0", "==", "-0", "true"),
+    ("-0", "==", "1.5", "false"),
+    ("-0", "==", "-7", "false"),
+    ("-0", "==", "NaN", "false"),
+    ("-0", "==", "Infinity", "false"),
+    ("-0", "==", "4294967301", "false"),
+    ("-0", "==", r#"" 12 ""#, "false"),
+    ("-0", "==", r#""abc""#, "false"),
+    ("-0", "==", "[2]", "false"),
+    ("-0", "==", "{}", "false"),
+    ("1.5", "==", "undefined", "false"),
+    ("1.5", "==", "null", "false"),
+    ("1.5", "==", "true", "false"),
+    ("1.5", "==", "-0", "false"),
+    ("1.5", "==", "1.5", "true"),
+    ("1.5", "==", "-7", "false"),
+    ("1.5", "==", "NaN", "false"),
+    ("1.5", "==", "Infinity", "false"),
+    ("1.5", "==", "4294967301", "false"),
+    ("1.5", "==", r#"" 12 ""#, "false"),
+    ("1.5", "==", r#""abc""#, "false"),
+    ("1.5", "==", "[2]", "false"),
+    ("1.5", "==", "{}", "false"),
+    ("-7", "==", "undefined", "false"),
+    ("-7", "==", "null", "false"),
+    ("-7", "==", "true", "false"),
+    ("-7", "==", "-0", "false"),
+    ("-7", "==", "1.5", "false"),
+    ("-7", "==", "-7", "true"),
+    ("-7", "==", "NaN", "false"),
+    ("-7", "==", "Infinity", "false"),
+    ("-7", "==", "4294967301", "false"),
+    ("-7", "==", r#"" 12 ""#, "false"),
+    ("-7", "==", r#""abc""#, "false"),
+    ("-7", "==", "[2]", "false"),
+    ("-7", "==", "{}", "false"),
+    ("NaN", "==", "undefined", "false"),
+    ("NaN", "==", "null", "false"),
+    ("NaN", "==", "true", "false"),
+    ("NaN", "==", "-0", "false"),
+    ("NaN", "==", "1.5", "false"),
+    ("NaN", "==", "-7", "false"),
+    ("NaN", "==", "NaN", "false"),
+    ("NaN", "==", "Infinity", "false"),
+    ("NaN", "==", "4294967301", "false"),
+    ("NaN", "==", r#"" 12 ""#, "false"),
+    ("NaN", "==", r#""abc""#, "false"),
+    ("NaN", "==", "[2]", "false"),
+    ("NaN", "==", "{}", "false"),
+    ("Infinity", "==", "undefined", "false"),
+    ("Infinity", "==", "null", "false"),
+    ("Infinity", "==", "true", "false"),
+    ("Infinity", "==", "-0", "false"),
+    ("Infinity", "==", "1.5", "false"),
+    ("Infinity", "==", "-7", "false"),
+    ("Infinity", "==", "NaN", "false"),
+    ("Infinity", "==", "Infinity", "true"),
+    ("Infinity", "==", "4294967301", "false"),
+    ("Infinity", "==", r#"" 12 ""#, "false"),
+    ("Infinity", "==", r#""abc""#, "false"),
+    ("Infinity", "==", "[2]", "false"),
+    ("Infinity", "==", "{}", "false"),
+    ("4294967301", "==", "undefined", "false"),
+    ("4294967301", "==", "null", "false"),
+    ("4294967301", "==", "true", "false"),
+    ("4294967301", "==", "-0", "false"),
+    ("4294967301", "==", "1.5", "false"),
+    ("4294967301", "==", "-7", "false"),
+    ("4294967301", "==", "NaN", "false"),
+    ("4294967301", "==", "Infinity", "false"),
+    ("4294967301", "==", "4294967301", "true"),
+    ("4294967301", "==", r#"" 12 ""#, "false"),
+    ("4294967301", "==", r#""abc""#, "false"),
+    ("4294967301", "==", "[2]", "false"),
+    ("4294967301", "==", "{}", "false"),
+    (r#"" 12 ""#, "==", "undefined", "false"),
+    (r#"" 12 ""#, "==", "null", "false"),
+    (r#"" 12 ""#, "==", "true", "false"),
+    (r#"" 12 ""#, "==", "-0", "false"),
+    (r#"" 12 ""#, "==", "1.5", "false"),
+    (r#"" 12 ""#, "==", "-7", "false"),
+    (r#"" 12 ""#, "==", "NaN", "false"),
+    (r#"" 12 ""#, "==", "Infinity", "false"),
+    (r#"" 12 ""#, "==", "4294967301", "false"),
+    (r#"" 12 ""#, "==", r#"" 12 ""#, "true"),
+    (r#"" 12 ""#, "==", r#""abc""#, "false"),
+    (r#"" 12 ""#, "==", "[2]", "false"),
+    (r#"" 12 ""#, "==", "{}", "false"),
+    (r#""abc""#, "==", "undefined", "false"),
+    (r#""abc""#, "==", "null", "false"),
+    (r#""abc""#, "==", "true", "false"),
+    (r#""abc""#, "==", "-0", "false"),
+    (r#""abc""#, "==", "1.5", "false"),
+    (r#""abc""#, "==", "-7", "false"),
+    (r#""abc""#, "==", "NaN", "false"),
+    (r#""abc""#, "==", "Infinity", "false"),
+    (r#""abc""#, "==", "4294967301", "false"),
+    (r#""abc""#, "==", r#"" 12 ""#, "false"),
+    (r#""abc""#, "==", r#""abc""#, "true"),
+    (r#""abc""#, "==", "[2]", "false"),
+    (r#""abc""#, "==", "{}", "false"),
+    ("[2]", "==", "undefined", "false"),
+    ("[2]", "==", "null", "false"),
+    ("[2]", "==", "true", "false"),
+    ("[2]", "==", "-0", "false"),
+    ("[2]", "==", "1.5", "false"),
+    ("[2]", "==", "-7", "false"),
+    ("[2]", "==", "NaN", "false"),
+    ("[2]", "==", "Infinity", "false"),
+    ("[2]", "==", "4294967301", "false"),
+    ("[2]", "==", r#"" 12 ""#, "false"),
+    ("[2]", "==", r#""abc""#, "false"),
+    ("[2]", "==", "[2]", "false"),
+    ("[2]", "==", "{}", "false"),
+    ("{}", "==", "undefined", "false"),
+    ("{}", "==", "null", "false"),
+    ("{}", "==", "true", "false"),
+    ("{}", "==", "-0", "false"),
+    ("{}", "==", "1.5", "false"),
+    ("{}", "==", "-7", "false"),
+    ("{}", "==", "NaN", "false"),
+    ("{}", "==", "Infinity", "false"),
+    ("{}", "==", "4294967301", "false"),
+    ("{}", "==", r#"" 12 ""#, "false"),
+    ("{}", "==", r#""abc""#, "false"),
+    ("{}", "==", "[2]", "false"),
+    ("{}", "==", "{}", "false"),
+    ("undefined", "===", "undefined", "true"),
+    ("undefined", "===", "null", "false"),
+    ("undefined", "===", "true", "false"),
+    ("undefined", "===", "-0", "false"),
+    ("undefined", "===", "1.5", "false"),
+    ("undefined", "===", "-7", "false"),
+    ("undefined", "===", "NaN", "false"),
+    ("undefined", "===", "Infinity", "false"),
+    ("undefined", "===", "4294967301", "false"),
+    ("undefined", "===", r#"" 12 ""#, "false"),
+    ("undefined", "===", r#""abc""#, "false"),
+    ("undefined", "===", "[2]", "false"),
+    ("undefined", "===", "{}", "false"),
+    ("null", "===", "undefined", "false"),
+    ("null", "===", "null", "true"),
+    ("null", "===", "true", "false"),
+    ("null", "===", "-0", "false"),
+    ("null", "===", "1.5", "false"),
+    ("null", "===", "-7", "false"),
+    ("null", "===", "NaN", "false"),
+    ("null", "===", "Infinity", "false"),
+    ("null", "===", "4294967301", "false"),
+    ("null", "===", r#"" 12 ""#, "false"),
+    ("null", "===", r#""abc""#, "false"),
+    ("null", "===", "[2]", "false"),
+    ("null", "===", "{}", "false"),
+    ("true", "===", "undefined", "false"),
+    ("true", "===", "null", "false"),
+    ("true", "===", "true", "true"),
+    ("true", "===", "-0", "false"),
+    ("true", "===", "1.5", "false"),
+    ("true", "===", "-7", "false"),
+    ("true", "===", "NaN", "false"),
+    ("true", "===", "Infinity", "false"),
+    ("true", "===", "4294967301", "false"),
+    ("true", "===", r#"" 12 ""#, "false"),
+    ("true", "===", r#""abc""#, "false"),
+    ("true", "===", "[2]", "false"),
+    ("true", "===", "{}", "false"),
+    ("-0", "===", "undefined", "false"),
+    ("-0", "===", "null", "false"),
+    ("-0", "===", "true", "false"),
+    ("-0", "===", "-0", "true"),
+    ("-0", "===", "1.5", "false"),
+    ("-0", "===", "-7", "false"),
+    ("-0", "===", "NaN", "false"),
+    ("-0", "===", "Infinity", "false"),
+    ("-0", "===", "4294967301", "false"),
+    ("-0", "===", r#"" 12 ""#, "false"),
+    ("-0", "===", r#""abc""#, "false"),
+    ("-0", "===", "[2]", "false"),
+    ("-0", "===", "{}", "false"),
+    ("1.5", "===", "undefined", "false"),
+    ("1.5", "===", "null", "false"),
+    ("1.5", "===", "true", "false"),
+    ("1.5", "===", "-0", "false"),
+    ("1.5", "===", "1.5", "true"),
+    ("1.5", "===", "-7", "false"),
+    ("1.5", "===", "NaN", "false"),
+    ("1.5", "===", "Infinity", "false"),
+    ("1.5", "===", "4294967301", "false"),
+    ("1.5", "===", r#"" 12 ""#, "false"),
+    ("1.5", "===", r#""abc""#, "false"),
+    ("1.5", "===", "[2]", "false"),
+    ("1.5", "===", "{}", "false"),
+    ("-7", "===", "undefined", "false"),
+    ("-7", "===", "null", "false"),
+    ("-7", "===", "true", "false"),
+    ("-7", "===", "-0", "false"),
+    ("-7", "===", "1.5", "false"),
+    ("-7", "===", "-7", "true"),
+    ("-7", "===", "NaN", "false"),
+    ("-7", "===", "Infinity", "false"),
+    ("-7", "===", "4294967301", "false"),
+    ("-7", "===", r#"" 12 ""#, "false"),
+    ("-7", "===", r#""abc""#, "false"),
+    ("-7", "===", "[2]", "false"),
+    ("-7", "===", "{}", "false"),
+    ("NaN", "===", "undefined", "false"),
+    ("NaN", "===", "null", "false"),
+    ("NaN", "===", "true", "false"),
+    ("NaN", "===", "-0", "false"),
+    ("NaN", "===", "1.5", "false"),
+    ("NaN", "===", "-7", "false"),
+    ("NaN", "===", "NaN", "false"),
+    ("NaN", "===", "Infinity", "false"),
+    ("NaN", "===", "4294967301", "false"),
+    ("NaN", "===", r#"" 12 ""#, "false"),
+    ("NaN", "===", r#""abc""#, "false"),
+    ("NaN", "===", "[2]", "false"),
+    ("NaN", "===", "{}", "false"),
+    ("Infinity", "===", "undefined", "false"),
+    ("Infinity", "===", "null", "false"),
+    ("Infinity", "===", "true", "false"),
+    ("Infinity", "===", "-0", "false"),
+    ("Infinity", "===", "1.5", "false"),
+    ("Infinity", "===", "-7", "false"),
+    ("Infinity", "===", "NaN", "false"),
+    ("Infinity", "===", "Infinity", "true"),
+    ("Infinity", "===", "4294967301", "false"),
+    ("Infinity", "===", r#"" 12 ""#, "false"),
+    ("Infinity", "===", r#""abc""#, "false"),
+    ("Infinity", "===", "[2]", "false"),
+    ("Infinity", "===", "{}", "false"),
+    ("4294967301", "===", "undefined", "false"),
+    ("4294967301", "===", "null", "false"),
+    ("4294967301", "===", "true", "false"),
+    ("4294967301", "===", "-0", "false"),
+    ("4294967301", "===", "1.5", "false"),
+    ("4294967301", "===", "-7", "false"),
+    ("4294967301", "===", "NaN", "false"),
+    ("4294967301", "===", "Infinity", "false"),
+    ("4294967301", "===", "4294967301", "true"),
+    ("4294967301", "===", r#"" 12 ""#, "false"),
+    ("4294967301", "===", r#""abc""#, "false"),
+    ("4294967301", "===", "[2]", "false"),
+    ("4294967301", "===", "{}", "false"),
+    (r#"" 12 ""#, "===", "undefined", "false"),
+    (r#"" 12 ""#, "===", "null", "false"),
+    (r#"" 12 ""#, "===", "true", "false"),
+    (r#"" 12 ""#, "===", "-0", "false"),
+    (r#"" 12 ""#, "===", "1.5", "false"),
+    (r#"" 12 ""#, "===", "-7", "false"),
+    (r#"" 12 ""#, "===", "NaN", "false"),
+    (r#"" 12 ""#, "===", "Infinity", "false"),
+    (r#"" 12 ""#, "===", "4294967301", "false"),
+    (r#"" 12 ""#, "===", r#"" 12 ""#, "true"),
+    (r#"" 12 ""#, "===", r#""abc""#, "false"),
+    (r#"" 12 ""#, "===", "[2]", "false"),
+    (r#"" 12 ""#, "===", "{}", "false"),
+    (r#""abc""#, "===", "undefined", "false"),
+    (r#""abc""#, "===", "null", "false"),
+    (r#""abc""#, "===", "true", "false"),
+    (r#""abc""#, "===", "-0", "false"),
+    (r#""abc""#, "===", "1.5", "false"),
+    (r#""abc""#, "===", "-7", "false"),
+    (r#""abc""#, "===", "NaN", "false"),
+    (r#""abc""#, "===", "Infinity", "false"),
+    (r#""abc""#, "===", "4294967301", "false"),
+    (r#""abc""#, "===", r#"" 12 ""#, "false"),
+    (r#""abc""#, "===", r#""abc""#, "true"),
+    (r#""abc""#, "===", "[2]", "false"),
+    (r#""abc""#, "===", "{}", "false"),
+    ("[2]", "===", "undefined", "false"),
+    ("[2]", "===", "null", "false"),
+    ("[2]", "===", "true", "false"),
+    ("[2]", "===", "-0", "false"),
+    ("[2]", "===", "1.5", "false"),
+    ("[2]", "===", "-7", "false"),
+    ("[2]", "===", "NaN", "false"),
+    ("[2]", "===", "Infinity", "false"),
+    ("[2]", "===", "4294967301", "false"),
+    ("[2]", "===", r#"" 12 ""#, "false"),
+    ("[2]", "===", r#""abc""#, "false"),
+    ("[2]", "===", "[2]", "false"),
+    ("[2]", "===", "{}", "false"),
+    ("{}", "===", "undefined", "false"),
+    ("{}", "===", "null", "false"),
+    ("{}", "===", "true", "false"),
+    ("{}", "===", "-0", "false"),
+    ("{}", "===", "1.5", "false"),
+    ("{}", "===", "-7", "false"),
+    ("{}", "===", "NaN", "false"),
+    ("{}", "===", "Infinity", "false"),
+    ("{}", "===", "4294967301", "false"),
+    ("{}", "===", r#"" 12 ""#, "false"),
+    ("{}", "===", r#""abc""#, "false"),
+    ("{}", "===", "[2]", "false"),
+    ("{}", "===", "{}", "false"),
+    ("undefined", "<", "undefined", "undefined"),
+    ("undefined", "<", "null", "undefined"),
+    ("undefined", "<", "true", "undefined"),
+    ("undefined", "<", "-0", "undefined"),
+    ("undefined", "<", "1.5", "undefined"),
+    ("undefined", "<", "-7", "undefined"),
+    ("undefined", "<", "NaN", "undefined"),
+    ("undefined", "<", "Infinity", "undefined"),
+    ("undefined", "<", "4294967301", "undefined"),
+    ("undefined", "<", r#"" 12 ""#, "undefined"),
+    ("undefined", "<", r#""abc""#, "undefined"),
+    ("undefined", "<", "[2]", "undefined"),
+    ("undefined", "<", "{}", "undefined"),
+    ("null", "<", "undefined", "undefined"),
+    ("null", "<", "null", "false"),
+    ("null", "<", "true", "true"),
+    ("null", "<", "-0", "false"),
+    ("null", "<", "1.5", "true"),
+    ("null", "<", "-7", "false"),
+    ("null", "<", "NaN", "undefined"),
+    ("null", "<", "Infinity", "true"),
+    ("null", "<", "4294967301", "true"),
+    ("null", "<", r#"" 12 ""#, "true"),
+    ("null", "<", r#""abc""#, "undefined"),
+    ("null", "<", "[2]", "true"),
+    ("null", "<", "{}", "undefined"),
+    ("true", "<", "undefined", "undefined"),
+    ("true", "<", "null", "false"),
+    ("true", "<", "true", "false"),
+    ("true", "<", "-0", "false"),
+    ("true", "<", "1.5", "true"),
+    ("true", "<", "-7", "false"),
+    ("true", "<", "NaN", "undefined"),
+    ("true", "<", "Infinity", "true"),
+    ("true", "<", "4294967301", "true"),
+    ("true", "<", r#"" 12 ""#, "true"),
+    ("true", "<", r#""abc""#, "undefined"),
+    ("true", "<", "[2]", "true"),
+    ("true", "<", "{}", "undefined"),
+    ("-0", "<", "undefined", "undefined"),
+    ("-0", "<", "null", "false"),
+    ("-0", "<", "true", "true"),
+    ("-0", "<", "-0", "false"),
+    ("-0", "<", "1.5", "true"),
+    ("-0", "<", "-7", "false"),
+    ("-0", "<", "NaN", "undefined"),
+    ("-0", "<", "Infinity", "true"),
+    ("-0", "<", "4294967301", "true"),
+    ("-0", "<", r#"" 12 ""#, "true"),
+    ("-0", "<", r#""abc""#, "undefined"),
+    ("-0", "<", "[2]", "true"),
+    ("-0", "<", "{}", "undefined"),
+    ("1.5", "<", "undefined", "undefined"),
+    ("1.5", "<", "null", "false"),
+    ("1.5", "<", "true", "false"),
+    ("1.5", "<", "-0", "false"),
+    ("1.5", "<", "1.5", "false"),
+    ("1.5", "<", "-7", "false"),
+    ("1.5", "<", "NaN", "undefined"),
+    ("1.5", "<", "Infinity", "true"),
+    ("1.5", "<", "4294967301", "true"),
+    ("1.5", "<", r#"" 12 ""#, "true"),
+    ("1.5", "<", r#""abc""#, "undefined"),
+    ("1.5", "<", "[2]", "true"),
+    ("1.5", "<", "{}", "undefined"),
+    ("-7", "<", "undefined", "undefined"),
+    ("-7", "<", "null", "true"),
+    ("-7", "<", "true", "true"),
+    ("-7", "<", "-0", "true"),
+    ("-7", "<", "1.5", "true"),
+    ("-7", "<", "-7", "false"),
+    ("-7", "<", "NaN", "undefined"),
+    ("-7", "<", "Infinity", "true"),
+    ("-7", "<", "4294967301", "true"),
+    ("-7", "<", r#"" 12 ""#, "true"),
+    ("-7", "<", r#""abc""#, "undefined"),
+    ("-7", "<", "[2]", "true"),
+    ("-7", "<", "{}", "undefined"),
+    ("NaN", "<", "undefined", "undefined"),
+    ("NaN", "<", "null", "undefined"),
+    ("NaN", "<", "true", "undefined"),
+    ("NaN", "<", "-0", "undefined"),
+    ("NaN", "<", "1.5", "undefined"),
+    ("NaN", "<", "-7", "undefined"),
+    ("NaN", "<", "NaN", "undefined"),
+    ("NaN", "<", "Infinity", "undefined"),
+    ("NaN", "<", "4294967301", "undefined"),
+    ("NaN", "<", r#"" 12 ""#, "undefined"),
+    ("NaN", "<", r#""abc""#, "undefined"),
+    ("NaN", "<", "[2]", "undefined"),
+    ("NaN", "<", "{}", "undefined"),
+    ("Infinity", "<", "undefined", "undefined"),
+    ("Infinity", "<", "null", "false"),
+    ("Infinity", "<", "true", "false"),
+    ("Infinity", "<", "-0", "false"),
+    ("Infinity", "<", "1.5", "false"),
+    ("Infinity", "<", "-7", "false"),
+    ("Infinity", "<", "NaN", "undefined"),
+    ("Infinity", "<", "Infinity", "false"),
+    ("Infinity", "<", "4294967301", "false"),
+    ("Infinity", "<", r#"" 12 ""#, "false"),
+    ("Infinity", "<", r#""abc""#, "undefined"),
+    ("Infinity", "<", "[2]", "false"),
+    ("Infinity", "<", "{}", "undefined"),
+    ("4294967301", "<", "undefined", "undefined"),
+    ("4294967301", "<", "null", "false"),
+    ("4294967301", "<", "true", "false"),
+    ("4294967301", "<", "-0", "false"),
+    ("4294967301", "<", "1.5", "false"),
+    ("4294967301", "<", "-7", "false"),
+    ("4294967301", "<", "NaN", "undefined"),
+    ("4294967301", "<", "Infinity", "true"),
+    ("4294967301", "<", "4294967301", "false"),
+    ("4294967301", "<", r#"" 12 ""#, "false"),
+    ("4294967301", "<", r#""abc""#, "undefined"),
+    ("4294967301", "<", "[2]", "false"),
+    ("4294967301", "<", "{}", "undefined"),
+    (r#"" 12 ""#, "<", "undefined", "undefined"),
+    (r#"" 12 ""#, "<", "null", "false"),
+    (r#"" 12 ""#, "<", "true", "false"),
+    (r#"" 12 ""#, "<", "-0", "false"),
+    (r#"" 12 ""#, "<", "1.5", "false"),
+    (r#"" 12 ""#, "<", "-7", "false"),
+    (r#"" 12 ""#, "<", "NaN", "undefined"),
+    (r#"" 12 ""#, "<", "Infinity", "true"),
+    (r#"" 12 ""#, "<", "4294967301", "true"),
+    (r#"" 12 ""#, "<", r#"" 12 ""#, "false"),
+    (r#"" 12 ""#, "<", r#""abc""#, "true"),
+    (r#"" 12 ""#, "<", "[2]", "true"),
+    (r#"" 12 ""#, "<", "{}", "true"),
+    (r#""abc""#, "<", "undefined", "undefined"),
+    (r#""abc""#, "<", "null", "undefined"),
+    (r#""abc""#, "<", "true", "undefined"),
+    (r#""abc""#, "<", "-0", "undefined"),
+    (r#""abc""#, "<", "1.5", "undefined"),
+    (r#""abc""#, "<", "-7", "undefined"),
+    (r#""abc""#, "<", "NaN", "undefined"),
+    (r#""abc""#, "<", "Infinity", "undefined"),
+    (r#""abc""#, "<", "4294967301", "undefined"),
+    (r#""abc""#, "<", r#"" 12 ""#, "false"),
+    (r#""abc""#, "<", r#""abc""#, "false"),
+    (r#""abc""#, "<", "[2]", "false"),
+    (r#""abc""#, "<", "{}", "false"),
+    ("[2]", "<", "undefined", "undefined"),
+    ("[2]", "<", "null", "false"),
+    ("[2]", "<", "true", "false"),
+    ("[2]", "<", "-0", "false"),
+    ("[2]", "<", "1.5", "false"),
+    ("[2]", "<", "-7", "false"),
+    ("[2]", "<", "NaN", "undefined"),
+    ("[2]", "<", "Infinity", "true"),
+    ("[2]", "<", "4294967301", "true"),
+    ("[2]", "<", r#"" 12 ""#, "false"),
+    ("[2]", "<", r#""abc""#, "true"),
+    ("[2]", "<", "[2]", "false"),
+    ("[2]", "<", "{}", "true"),
+    ("{}", "<", "undefined", "undefined"),
+    ("{}", "<", "null", "undefined"),
+    ("{}", "<", "true", "undefined"),
+    ("{}", "<", "-0", "undefined"),
+    ("{}", "<", "1.5", "undefined"),
+    ("{}", "<", "-7", "undefined"),
+    ("{}", "<", "NaN", "undefined"),
+    ("{}", "<", "Infinity", "undefined"),
+    ("{}", "<", "4294967301", "undefined"),
+    ("{}", "<", r#"" 12 ""#, "false"),
+    ("{}", "<", r#""abc""#, "true"),
+    ("{}", "<", "[2]", "false"),
+    ("{}", "<", "{}", "false"),
+    ("", "-", "undefined", "NaN"),
+    ("", "-", "null", "-0"),
+    ("", "-", "true", "-1"),
+    ("", "-", "-0", "0"),
+    ("", "-", "1.5", "-1.5"),
+    ("", "-", "-7", "7"),
+    ("", "-", "NaN", "NaN"),
+    ("", "-", "Infinity", "-Infinity"),
+    ("", "-", "4294967301", "-4294967301"),
+    ("", "-", r#"" 12 ""#, "-12"),
+    ("", "-", r#""abc""#, "NaN"),
+    ("", "-", "[2]", "-2"),
+    ("", "-", "{}", "NaN"),
+    ("", "~", "undefined", "-1"),
+    ("", "~", "null", "-1"),
+    ("", "~", "true", "-2"),
+    ("", "~", "-0", "-1"),
+    ("", "~", "1.5", "-2"),
+    ("", "~", "-7", "6"),
+    ("", "~", "NaN", "-1"),
+    ("", "~", "Infinity", "-1"),
+    ("", "~", "4294967301", "-6"),
+    ("", "~", r#"" 12 ""#, "-13"),
+    ("", "~", r#""abc""#, "-1"),
+    ("", "~", "[2]", "-3"),
+    ("", "~", "{}", "-1"),
+    ("", "!", "undefined", "true"),
+    ("", "!", "null", "true"),
+    ("", "!", "true", "false"),
+    ("", "!", "-0", "true"),
+    ("", "!", "1.5", "false"),
+    ("", "!", "-7", "false"),
+    ("", "!", "NaN", "true"),
+    ("", "!", "Infinity", "false"),
+    ("", "!", "4294967301", "false"),
+    ("", "!", r#"" 12 ""#, "false"),
+    ("", "!", r#""abc""#, "false"),
+    ("", "!", "[2]", "false"),
+    ("", "!", "{}", "false"),
+    ("", "typeof", "undefined", r#""undefined""#),
+    ("", "typeof", "null", r#""object""#),
+    ("", "typeof", "true", r#""boolean""#),
+    ("", "typeof", "-0", r#""number""#),
+    ("", "typeof", "1.5", r#""number""#),
+    ("", "typeof", "-7", r#""number""#),
+    ("", "typeof", "NaN", r#""number""#),
+    ("", "typeof", "Infinity", r#""number""#),
+    ("", "typeof", "4294967301", r#""number""#),
+    ("", "typeof", r#"" 12 ""#, r#""string""#),
+    ("", "typeof", r#""abc""#, r#""string""#),
+    ("", "typeof", "[2]", r#""object""#),
+    ("", "typeof", "{}", r#""object""#),
+];
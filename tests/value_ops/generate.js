@@ -0,0 +1,52 @@
+// Regenerates the cases of the value_ops test by evaluating every
+// expression in V8:
+// `node tests/value_ops/generate.js > tests/value_ops/cases.rs`
+
+const operands = [
+  "undefined", "null", "true", "-0", "1.5", "-7", "NaN", "Infinity",
+  "4294967301", '" 12 "', '"abc"', "[2]", "{}",
+];
+const binary = ["+", "-", "*", "/", "%", "&", "|", "^", "<<", ">>", ">>>", "==", "===", "<"];
+const unary = ["-", "~", "!", "typeof"];
+
+function repr(value) {
+  if (typeof value === "number") {
+    return Object.is(value, -0) ? "-0" : String(value);
+  }
+  return typeof value === "string" ? JSON.stringify(value) : String(value);
+}
+
+function literal(source) {
+  return source.includes('"') ? `r#"${source}"#` : `"${source}"`;
+}
+
+const cases = [];
+for (const op of binary) {
+  for (const a of operands) {
+    for (const b of operands) {
+      let result;
+      if (op === "<") {
+        // `undefined` where neither `<` nor `>=` holds
+        const lt = eval(`(${a}) < (${b})`);
+        const ge = eval(`(${a}) >= (${b})`);
+        result = lt || ge ? String(lt) : "undefined";
+      } else {
+        result = repr(eval(`(${a}) ${op} (${b})`));
+      }
+      cases.push(`    (${literal(a)}, "${op}", ${literal(b)}, ${literal(result)}),`);
+    }
+  }
+}
+for (const op of unary) {
+  for (const a of operands) {
+    const result = repr(eval(`${op} (${a})`));
+    cases.push(`    ("", "${op}", ${literal(a)}, ${literal(result)}),`);
+  }
+}
+
+console.log("// Generated by generate.js, do not edit.");
+console.log("");
+console.log("/// `(left, operator, right, result)`, `left` is empty for unary operators.");
+console.log("pub const CASES: &[(&str, &str, &str, &str)] = &[");
+console.log(cases.join("\n"));
+console.log("];");
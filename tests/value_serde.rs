@@ -0,0 +1,109 @@
+//! serde round trips through `Value`, run with `--features serde`.
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use gdv8::{from_value, to_value, Value};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Player {
+    name: String,
+    score: f64,
+    nickname: Option<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+    Line(f64, f64),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type")]
+enum Event {
+    Spawn { x: f64 },
+    Despawn,
+}
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    from_value(to_value(value).unwrap()).unwrap()
+}
+
+#[test]
+fn struct_round_trip() {
+    let player = Player {
+        name: "ann".to_string(),
+        score: 1.5,
+        nickname: None,
+        tags: vec!["a".to_string(), "b".to_string()],
+    };
+    assert_eq!(round_trip(&player), player);
+}
+
+#[test]
+fn option_round_trip() {
+    assert_eq!(round_trip(&Some(2.0)), Some(2.0));
+    assert_eq!(round_trip(&None::<f64>), None);
+    assert_eq!(round_trip(&Some(Some(1))), Some(Some(1)));
+    assert!(matches!(to_value(&None::<f64>).unwrap(), Value::Null));
+}
+
+#[test]
+fn enum_round_trip() {
+    for shape in [
+        Shape::Point,
+        Shape::Circle(2.0),
+        Shape::Rect {
+            width: 1.0,
+            height: 2.0,
+        },
+        Shape::Line(1.0, 2.0),
+    ] {
+        assert_eq!(round_trip(&shape), shape);
+    }
+
+    assert_eq!(
+        round_trip(&Event::Spawn { x: 1.0 }),
+        Event::Spawn { x: 1.0 }
+    );
+    assert_eq!(round_trip(&Event::Despawn), Event::Despawn);
+}
+
+#[test]
+fn map_round_trip() {
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), vec![1, 2]);
+    map.insert("b".to_string(), vec![]);
+    assert_eq!(round_trip(&map), map);
+}
+
+#[test]
+fn bytes_round_trip() {
+    let bytes = ByteBuf::from(vec![0, 1, 255]);
+    assert_eq!(round_trip(&bytes), bytes);
+}
+
+#[test]
+fn negative_zero_round_trip() {
+    assert!(round_trip(&-0.0f64).is_sign_negative());
+    assert!(from_value::<f64>(Value::Number(-0.0))
+        .unwrap()
+        .is_sign_negative());
+
+    match to_value(&Value::Number(-0.0)).unwrap() {
+        Value::Number(v) => assert!(v == 0.0 && v.is_sign_negative()),
+        v => panic!("expected a number, got {v:?}"),
+    }
+}
+
+#[test]
+fn error_path() {
+    let value = to_value(&BTreeMap::from([("name", 1)])).unwrap();
+    let error = from_value::<Player>(value).unwrap_err();
+    assert!(error.to_string().starts_with("name: "), "{error}");
+}